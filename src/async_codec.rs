@@ -0,0 +1,228 @@
+use crate::{Decoder, Encoder};
+use core::future::Future;
+
+/// Async version of [`Encoder`].
+///
+/// Every synchronous encoder can be used as an `AsyncEncoder` by wrapping it in [`AsyncCodec`]
+/// (or [`SpawnBlockingCodec`] with the **`tokio` feature** enabled).
+///
+/// The returned future is `Send` so it can be used with multi-threaded executors, e.g. in
+/// `tokio::spawn`. Implementations can still use `async fn`.
+///
+/// Only available with the **`async` feature** enabled.
+pub trait AsyncEncoder<T>: 'static {
+    type Error;
+    type Encoded;
+
+    fn encode(val: &T) -> impl Future<Output = Result<Self::Encoded, Self::Error>> + Send;
+}
+
+/// Async version of [`Decoder`].
+///
+/// Every synchronous decoder can be used as an `AsyncDecoder` by wrapping it in [`AsyncCodec`]
+/// (or [`SpawnBlockingCodec`] with the **`tokio` feature** enabled).
+///
+/// The returned future is `Send` so it can be used with multi-threaded executors, e.g. in
+/// `tokio::spawn`. Implementations can still use `async fn`.
+///
+/// Only available with the **`async` feature** enabled.
+pub trait AsyncDecoder<T>: 'static {
+    type Error;
+    type Encoded: ?Sized;
+
+    fn decode(val: &Self::Encoded) -> impl Future<Output = Result<T, Self::Error>> + Send;
+}
+
+/// Wraps a synchronous codec and makes it an [`AsyncEncoder`] and [`AsyncDecoder`] by running it
+/// inline in the calling task.
+///
+/// Only available with the **`async` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{AsyncCodec, AsyncEncoder, AsyncDecoder};
+/// # use codee::string::FromToStringCodec;
+/// #
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let encoded = AsyncCodec::<FromToStringCodec>::encode(&42).await.unwrap();
+/// let decoded: i32 = AsyncCodec::<FromToStringCodec>::decode(&encoded).await.unwrap();
+///
+/// assert_eq!(decoded, 42);
+/// # }
+/// ```
+pub struct AsyncCodec<C>(C);
+
+impl<T, E> AsyncEncoder<T> for AsyncCodec<E>
+where
+    T: Sync,
+    E: Encoder<T>,
+{
+    type Error = E::Error;
+    type Encoded = E::Encoded;
+
+    async fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        E::encode(val)
+    }
+}
+
+impl<T, D> AsyncDecoder<T> for AsyncCodec<D>
+where
+    D: Decoder<T>,
+    D::Encoded: Sync,
+{
+    type Error = D::Error;
+    type Encoded = D::Encoded;
+
+    async fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        D::decode(val)
+    }
+}
+
+#[cfg(feature = "tokio")]
+mod spawn_blocking {
+    use super::{AsyncDecoder, AsyncEncoder};
+    use crate::{Decoder, Encoder};
+    use std::borrow::Borrow;
+    use thiserror::Error;
+
+    /// Wraps a synchronous codec and makes it an [`AsyncEncoder`] and [`AsyncDecoder`] by
+    /// running it on tokio's blocking thread pool with [`tokio::task::spawn_blocking`].
+    ///
+    /// This keeps expensive encoding or decoding from blocking the async runtime. Since the
+    /// work is moved to another thread, the value to encode is cloned and the input to decode is
+    /// copied into an owned buffer first.
+    ///
+    /// Only available with the **`tokio` feature** enabled.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use codee::{SpawnBlockingCodec, AsyncEncoder, AsyncDecoder};
+    /// # use codee::string::FromToStringCodec;
+    /// #
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let encoded = SpawnBlockingCodec::<FromToStringCodec>::encode(&42).await.unwrap();
+    /// let decoded: i32 = SpawnBlockingCodec::<FromToStringCodec>::decode(&encoded).await.unwrap();
+    ///
+    /// assert_eq!(decoded, 42);
+    /// # }
+    /// ```
+    pub struct SpawnBlockingCodec<C>(C);
+
+    #[derive(Error, Debug)]
+    pub enum SpawnBlockingError<Err> {
+        #[error("{0}")]
+        Codec(Err),
+        #[error("blocking task failed: {0}")]
+        Join(#[from] tokio::task::JoinError),
+    }
+
+    impl<T, E> AsyncEncoder<T> for SpawnBlockingCodec<E>
+    where
+        T: Clone + Send + Sync + 'static,
+        E: Encoder<T>,
+        E::Error: Send,
+        E::Encoded: Send,
+    {
+        type Error = SpawnBlockingError<E::Error>;
+        type Encoded = E::Encoded;
+
+        async fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+            let val = val.clone();
+
+            tokio::task::spawn_blocking(move || E::encode(&val))
+                .await?
+                .map_err(SpawnBlockingError::Codec)
+        }
+    }
+
+    impl<T, D> AsyncDecoder<T> for SpawnBlockingCodec<D>
+    where
+        T: Send + 'static,
+        D: Decoder<T>,
+        D::Error: Send,
+        D::Encoded: ToOwned + Sync,
+        <D::Encoded as ToOwned>::Owned: Send + 'static,
+    {
+        type Error = SpawnBlockingError<D::Error>;
+        type Encoded = D::Encoded;
+
+        async fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+            let val = val.to_owned();
+
+            tokio::task::spawn_blocking(move || D::decode(val.borrow()))
+                .await?
+                .map_err(SpawnBlockingError::Codec)
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+pub use spawn_blocking::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::string::FromToStringCodec;
+
+    #[tokio::test]
+    async fn test_async_codec() {
+        let enc = AsyncCodec::<FromToStringCodec>::encode(&42).await.unwrap();
+        assert_eq!(enc, "42");
+
+        let dec: i32 = AsyncCodec::<FromToStringCodec>::decode(&enc).await.unwrap();
+        assert_eq!(dec, 42);
+    }
+
+    #[tokio::test]
+    async fn test_async_codec_spawn() {
+        async fn round_trip<C>(val: i32) -> i32
+        where
+            C: AsyncEncoder<i32, Encoded = String> + AsyncDecoder<i32, Encoded = str>,
+        {
+            let Ok(enc) = C::encode(&val).await else {
+                panic!("failed to encode");
+            };
+            let Ok(dec) = C::decode(&enc).await else {
+                panic!("failed to decode");
+            };
+            dec
+        }
+
+        // the futures are `Send` so they can be spawned on a multi-threaded runtime
+        let dec = tokio::spawn(round_trip::<AsyncCodec<FromToStringCodec>>(42))
+            .await
+            .unwrap();
+        assert_eq!(dec, 42);
+    }
+
+    #[cfg(all(feature = "tokio", feature = "json_serde"))]
+    #[tokio::test]
+    async fn test_spawn_blocking_codec() {
+        use crate::string::JsonSerdeCodec;
+
+        #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Test {
+            s: String,
+            i: i32,
+        }
+        let t = Test {
+            s: String::from("party time 🎉"),
+            i: 42,
+        };
+
+        let enc = SpawnBlockingCodec::<JsonSerdeCodec>::encode(&t)
+            .await
+            .unwrap();
+        let dec: Test = SpawnBlockingCodec::<JsonSerdeCodec>::decode(&enc)
+            .await
+            .unwrap();
+        assert_eq!(dec, t);
+
+        let dec: Result<Test, _> = SpawnBlockingCodec::<JsonSerdeCodec>::decode("{").await;
+        assert!(matches!(dec, Err(SpawnBlockingError::Codec(_))));
+    }
+}
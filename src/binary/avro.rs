@@ -0,0 +1,151 @@
+use crate::{Decoder, Encoder};
+use apache_avro::{from_avro_datum, from_value, to_avro_datum, to_value, Schema};
+use serde::{de::DeserializeOwned, Serialize};
+use thiserror::Error;
+
+/// Implement this to provide the Avro schema that [`AvroCodec`] uses to encode and decode `Self`.
+///
+/// The schema is returned as a `'static` reference so it only has to be parsed once.
+///
+/// Only available with the **`avro` feature** enabled.
+pub trait WithAvroSchema {
+    fn avro_schema() -> &'static Schema;
+}
+
+/// A binary codec that relies on [`apache_avro`] to encode data as a single Avro datum without
+/// any header or schema. This is the format that is usually used for Kafka payloads.
+///
+/// The schema is provided by implementing [`WithAvroSchema`] for `T`.
+///
+/// Only available with the **`avro` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::{AvroCodec, WithAvroSchema};
+/// # use apache_avro::Schema;
+/// # use serde::{Deserialize, Serialize};
+/// # use std::sync::OnceLock;
+/// #
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct User {
+///     name: String,
+///     age: i32,
+/// }
+///
+/// impl WithAvroSchema for User {
+///     fn avro_schema() -> &'static Schema {
+///         static SCHEMA: OnceLock<Schema> = OnceLock::new();
+///         SCHEMA.get_or_init(|| {
+///             Schema::parse_str(r#"{
+///                 "type": "record",
+///                 "name": "User",
+///                 "fields": [
+///                     {"name": "name", "type": "string"},
+///                     {"name": "age", "type": "int"}
+///                 ]
+///             }"#).unwrap()
+///         })
+///     }
+/// }
+///
+/// let user = User { name: "Ferris".to_owned(), age: 9 };
+///
+/// let encoded = AvroCodec::encode(&user).unwrap();
+/// let decoded: User = AvroCodec::decode(&encoded).unwrap();
+///
+/// assert_eq!(decoded, user);
+/// ```
+pub struct AvroCodec;
+
+#[derive(Error, Debug)]
+pub enum AvroCodecError {
+    #[error("avro error: {0}")]
+    Avro(#[from] Box<apache_avro::Error>),
+    #[error("{0} trailing bytes after the datum")]
+    TrailingBytes(usize),
+}
+
+impl From<apache_avro::Error> for AvroCodecError {
+    fn from(err: apache_avro::Error) -> Self {
+        Self::Avro(Box::new(err))
+    }
+}
+
+impl<T: Serialize + WithAvroSchema> Encoder<T> for AvroCodec {
+    type Error = AvroCodecError;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        Ok(to_avro_datum(T::avro_schema(), to_value(val)?)?)
+    }
+}
+
+impl<T: DeserializeOwned + WithAvroSchema> Decoder<T> for AvroCodec {
+    type Error = AvroCodecError;
+    type Encoded = [u8];
+
+    fn decode(mut val: &Self::Encoded) -> Result<T, Self::Error> {
+        let value = from_avro_datum(T::avro_schema(), &mut val, None)?;
+
+        if !val.is_empty() {
+            return Err(AvroCodecError::TrailingBytes(val.len()));
+        }
+
+        Ok(from_value(&value)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use std::sync::OnceLock;
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    struct Test {
+        s: String,
+        i: i32,
+        list: Vec<i64>,
+    }
+
+    impl WithAvroSchema for Test {
+        fn avro_schema() -> &'static Schema {
+            static SCHEMA: OnceLock<Schema> = OnceLock::new();
+            SCHEMA.get_or_init(|| {
+                Schema::parse_str(
+                    r#"{
+                        "type": "record",
+                        "name": "Test",
+                        "fields": [
+                            {"name": "s", "type": "string"},
+                            {"name": "i", "type": "int"},
+                            {"name": "list", "type": {"type": "array", "items": "long"}}
+                        ]
+                    }"#,
+                )
+                .unwrap()
+            })
+        }
+    }
+
+    #[test]
+    fn test_avro_codec() {
+        let t = Test {
+            s: String::from("party time 🎉"),
+            i: 42,
+            list: vec![1, -2, 1 << 40],
+        };
+        let mut enc = AvroCodec::encode(&t).unwrap();
+        let dec: Test = AvroCodec::decode(&enc).unwrap();
+        assert_eq!(dec, t);
+
+        enc.push(0);
+        let dec: Result<Test, _> = AvroCodec::decode(&enc);
+        assert!(matches!(dec, Err(AvroCodecError::TrailingBytes(1))));
+
+        let dec: Result<Test, _> = AvroCodec::decode(&enc[..3]);
+        assert!(matches!(dec, Err(AvroCodecError::Avro(_))));
+    }
+}
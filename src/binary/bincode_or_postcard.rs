@@ -0,0 +1,150 @@
+use crate::binary::{BincodeDecodeError, BincodeSerdeCodec, PostcardSerdeCodec};
+use crate::{Decoder, DecoderWithRemainder, Encoder};
+use thiserror::Error;
+
+/// A codec for migrating stored data from bincode to postcard.
+///
+/// Encoding always writes postcard prefixed with the format tag
+/// [`POSTCARD_TAG`](Self::POSTCARD_TAG). Decoding dispatches on the format tag and reads both
+/// postcard and bincode (tagged with [`BINCODE_TAG`](Self::BINCODE_TAG)).
+///
+/// Legacy bincode data that was written without any tag is supported as well: if the first byte
+/// is not a known tag the whole input is decoded as untagged bincode. Since untagged bincode can
+/// start with a byte that looks like a tag, the whole input is also tried as untagged bincode if
+/// decoding the tagged data fails. This fallback only succeeds if the bincode data spans the
+/// whole input so that corrupt tagged data is reported as an error instead of being decoded
+/// into a wrong value.
+///
+/// Only available with the **`bincode_serde` and `postcard_serde` features** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::{BincodeOrPostcardCodec, BincodeSerdeCodec};
+/// #
+/// let legacy = BincodeSerdeCodec::encode(&(42_u32, "hello".to_owned())).unwrap();
+/// let decoded: (u32, String) = BincodeOrPostcardCodec::decode(&legacy).unwrap();
+/// assert_eq!(decoded, (42, "hello".to_owned()));
+///
+/// let encoded = BincodeOrPostcardCodec::encode(&decoded).unwrap();
+/// assert_eq!(encoded[0], BincodeOrPostcardCodec::POSTCARD_TAG);
+/// ```
+pub struct BincodeOrPostcardCodec;
+
+impl BincodeOrPostcardCodec {
+    /// Format tag for data that is encoded with bincode
+    pub const BINCODE_TAG: u8 = 0xB1;
+    /// Format tag for data that is encoded with postcard
+    pub const POSTCARD_TAG: u8 = 0xC1;
+}
+
+#[derive(Error, Debug)]
+pub enum BincodeOrPostcardDecodeError {
+    #[error("failed to decode postcard: {0}")]
+    Postcard(postcard::Error),
+    #[error("failed to decode bincode: {0}")]
+    Bincode(BincodeDecodeError),
+}
+
+impl<T: serde::Serialize> Encoder<T> for BincodeOrPostcardCodec {
+    type Error = postcard::Error;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        postcard::to_extend(val, vec![Self::POSTCARD_TAG])
+    }
+}
+
+impl<T: serde::de::DeserializeOwned> Decoder<T> for BincodeOrPostcardCodec {
+    type Error = BincodeOrPostcardDecodeError;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        let tagged = match val.split_first() {
+            Some((&Self::POSTCARD_TAG, rest)) => {
+                PostcardSerdeCodec::decode(rest).map_err(BincodeOrPostcardDecodeError::Postcard)
+            }
+            Some((&Self::BINCODE_TAG, rest)) => {
+                BincodeSerdeCodec::decode(rest).map_err(BincodeOrPostcardDecodeError::Bincode)
+            }
+            _ => {
+                return BincodeSerdeCodec::decode(val)
+                    .map_err(BincodeOrPostcardDecodeError::Bincode)
+            }
+        };
+
+        tagged.or_else(|err| match BincodeSerdeCodec::decode_with_remainder(val) {
+            Ok((value, [])) => Ok(value),
+            _ => Err(err),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Test {
+        s: String,
+        i: i32,
+    }
+
+    fn test_value() -> Test {
+        Test {
+            s: String::from("party time 🎉"),
+            i: 42,
+        }
+    }
+
+    #[test]
+    fn test_tagged_postcard() {
+        let t = test_value();
+        let enc = BincodeOrPostcardCodec::encode(&t).unwrap();
+        assert_eq!(enc[0], BincodeOrPostcardCodec::POSTCARD_TAG);
+        assert_eq!(enc[1..], PostcardSerdeCodec::encode(&t).unwrap());
+
+        let dec: Test = BincodeOrPostcardCodec::decode(&enc).unwrap();
+        assert_eq!(dec, t);
+    }
+
+    #[test]
+    fn test_tagged_bincode() {
+        let t = test_value();
+        let mut enc = vec![BincodeOrPostcardCodec::BINCODE_TAG];
+        enc.extend(BincodeSerdeCodec::encode(&t).unwrap());
+
+        let dec: Test = BincodeOrPostcardCodec::decode(&enc).unwrap();
+        assert_eq!(dec, t);
+    }
+
+    #[test]
+    fn test_legacy_untagged_bincode() {
+        let t = test_value();
+        let enc = BincodeSerdeCodec::encode(&t).unwrap();
+
+        let dec: Test = BincodeOrPostcardCodec::decode(&enc).unwrap();
+        assert_eq!(dec, t);
+    }
+
+    #[test]
+    fn test_legacy_untagged_bincode_starting_with_tag() {
+        let enc = BincodeSerdeCodec::encode(&BincodeOrPostcardCodec::POSTCARD_TAG).unwrap();
+
+        let dec: u8 = BincodeOrPostcardCodec::decode(&enc).unwrap();
+        assert_eq!(dec, BincodeOrPostcardCodec::POSTCARD_TAG);
+    }
+
+    #[test]
+    fn test_corrupt_tagged_postcard() {
+        // unterminated varint that would be a valid bincode `u32` followed by a trailing byte
+        let enc = [BincodeOrPostcardCodec::POSTCARD_TAG, 0x80, 0x80, 0x80, 0x80];
+
+        let dec: Result<u32, _> = BincodeOrPostcardCodec::decode(&enc);
+        assert!(matches!(
+            dec,
+            Err(BincodeOrPostcardDecodeError::Postcard(_))
+        ));
+    }
+}
@@ -1,25 +1,133 @@
-use crate::{Decoder, Encoder};
+#[cfg(feature = "smallvec")]
+use crate::SmallVecEncoder;
+use crate::{Decoder, DecoderWithRemainder, Encoder, EncoderInto};
+#[cfg(feature = "std")]
+use crate::{StreamDecoder, StreamEncoder};
+use bincode::ErrorKind;
+use thiserror::Error;
 
 /// A codec that relies on `bincode` adn `serde` to encode data in the bincode format.
 ///
 /// This is only available with the **`bincode_serde` feature** enabled.
 pub struct BincodeSerdeCodec;
 
-impl<T: serde::Serialize> Encoder<T> for BincodeSerdeCodec {
-    type Error = bincode::Error;
+/// Errors that can occur when encoding with [`BincodeSerdeCodec`].
+#[derive(Error, Debug)]
+pub enum BincodeEncodeError {
+    #[error("bincode can only encode sequences and maps with a known length")]
+    SequenceMustHaveLength,
+    #[error("size limit reached")]
+    SizeLimit,
+    #[error("failed to write: {0}")]
+    Io(std::io::Error),
+    #[error("failed to serialize: {0}")]
+    Serialize(String),
+}
+
+impl From<bincode::Error> for BincodeEncodeError {
+    fn from(err: bincode::Error) -> Self {
+        match *err {
+            ErrorKind::SequenceMustHaveLength => Self::SequenceMustHaveLength,
+            ErrorKind::SizeLimit => Self::SizeLimit,
+            ErrorKind::Io(err) => Self::Io(err),
+            ErrorKind::Custom(msg) => Self::Serialize(msg),
+            kind => Self::Serialize(kind.to_string()),
+        }
+    }
+}
+
+/// Errors that can occur when decoding with [`BincodeSerdeCodec`].
+#[derive(Error, Debug)]
+pub enum BincodeDecodeError {
+    #[error("unexpected end of input")]
+    UnexpectedEnd,
+    #[error("invalid UTF-8 in string: {0}")]
+    InvalidUtf8(core::str::Utf8Error),
+    #[error("invalid bool encoding {0}")]
+    InvalidBool(u8),
+    #[error("invalid char encoding")]
+    InvalidChar,
+    #[error("invalid enum tag {0}")]
+    InvalidTag(usize),
+    #[error("size limit reached")]
+    SizeLimit,
+    #[error("failed to read: {0}")]
+    Io(std::io::Error),
+    #[error("failed to deserialize: {0}")]
+    Deserialize(String),
+}
+
+impl From<bincode::Error> for BincodeDecodeError {
+    fn from(err: bincode::Error) -> Self {
+        match *err {
+            ErrorKind::Io(err) if err.kind() == std::io::ErrorKind::UnexpectedEof => {
+                Self::UnexpectedEnd
+            }
+            ErrorKind::Io(err) => Self::Io(err),
+            ErrorKind::InvalidUtf8Encoding(err) => Self::InvalidUtf8(err),
+            ErrorKind::InvalidBoolEncoding(byte) => Self::InvalidBool(byte),
+            ErrorKind::InvalidCharEncoding => Self::InvalidChar,
+            ErrorKind::InvalidTagEncoding(tag) => Self::InvalidTag(tag),
+            ErrorKind::SizeLimit => Self::SizeLimit,
+            ErrorKind::Custom(msg) => Self::Deserialize(msg),
+            kind => Self::Deserialize(kind.to_string()),
+        }
+    }
+}
+
+impl<T: serde::Serialize + ?Sized> Encoder<T> for BincodeSerdeCodec {
+    type Error = BincodeEncodeError;
     type Encoded = Vec<u8>;
 
     fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
-        bincode::serialize(val)
+        Ok(bincode::serialize(val)?)
+    }
+}
+
+impl<T: serde::Serialize + ?Sized> EncoderInto<T> for BincodeSerdeCodec {
+    fn encode_into(val: &T, buf: &mut Self::Encoded) -> Result<(), Self::Error> {
+        Ok(bincode::serialize_into(buf, val)?)
+    }
+}
+
+#[cfg(feature = "smallvec")]
+impl<T: serde::Serialize + ?Sized> SmallVecEncoder<T> for BincodeSerdeCodec {
+    fn encode_smallvec<const N: usize>(
+        val: &T,
+    ) -> Result<smallvec::SmallVec<[u8; N]>, Self::Error> {
+        let mut buf = smallvec::SmallVec::new();
+        bincode::serialize_into(&mut buf, val)?;
+        Ok(buf)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: serde::Serialize + ?Sized> StreamEncoder<T> for BincodeSerdeCodec {
+    fn encode_to_writer<W: std::io::Write>(val: &T, writer: &mut W) -> Result<(), Self::Error> {
+        Ok(bincode::serialize_into(writer, val)?)
     }
 }
 
 impl<T: serde::de::DeserializeOwned> Decoder<T> for BincodeSerdeCodec {
-    type Error = bincode::Error;
+    type Error = BincodeDecodeError;
     type Encoded = [u8];
 
     fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
-        bincode::deserialize(val)
+        Ok(bincode::deserialize(val)?)
+    }
+}
+
+impl<T: serde::de::DeserializeOwned> DecoderWithRemainder<T> for BincodeSerdeCodec {
+    fn decode_with_remainder(mut val: &[u8]) -> Result<(T, &[u8]), Self::Error> {
+        let value = bincode::deserialize_from(&mut val)?;
+        Ok((value, val))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: serde::de::DeserializeOwned> StreamDecoder<T> for BincodeSerdeCodec {
+    fn decode_from_reader<R: std::io::Read>(reader: &mut R) -> Result<T, Self::Error> {
+        Ok(bincode::deserialize_from(reader)?)
     }
 }
 
@@ -42,4 +150,118 @@ mod tests {
         let dec: Test = BincodeSerdeCodec::decode(&enc).unwrap();
         assert_eq!(dec, t);
     }
+
+    #[test]
+    fn test_bincode_codec_slice() {
+        let items = vec![(1_u32, String::from("party time 🎉")), (2, String::new())];
+        let slice: &[(u32, String)] = &items;
+
+        let enc = BincodeSerdeCodec::encode(slice).unwrap();
+        assert_eq!(enc, BincodeSerdeCodec::encode(&items).unwrap());
+
+        let dec: Vec<(u32, String)> = BincodeSerdeCodec::decode(&enc).unwrap();
+        assert_eq!(dec, items);
+
+        let mut buf = Vec::new();
+        BincodeSerdeCodec::encode_into(slice, &mut buf).unwrap();
+        assert_eq!(buf, enc);
+    }
+
+    #[test]
+    fn test_bincode_codec_errors() {
+        let enc = BincodeSerdeCodec::encode(&(42_u32, String::from("party time 🎉"))).unwrap();
+        let dec: Result<(u32, String), _> = BincodeSerdeCodec::decode(&enc[..enc.len() - 1]);
+        assert!(matches!(dec, Err(BincodeDecodeError::UnexpectedEnd)));
+
+        let dec: Result<bool, _> = BincodeSerdeCodec::decode(&[2]);
+        assert!(matches!(dec, Err(BincodeDecodeError::InvalidBool(2))));
+
+        struct UnknownLength;
+
+        impl serde::Serialize for UnknownLength {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_seq((0..3).filter(|i| i % 2 == 0))
+            }
+        }
+
+        let enc = BincodeSerdeCodec::encode(&UnknownLength);
+        assert!(matches!(
+            enc,
+            Err(BincodeEncodeError::SequenceMustHaveLength)
+        ));
+    }
+
+    #[test]
+    fn test_bincode_codec_encode_into() {
+        let mut buf = Vec::with_capacity(64);
+        let ptr = buf.as_ptr();
+
+        for i in 0..1000_u32 {
+            buf.clear();
+            BincodeSerdeCodec::encode_into(&(i, "party time 🎉"), &mut buf).unwrap();
+
+            let dec: (u32, String) = BincodeSerdeCodec::decode(&buf).unwrap();
+            assert_eq!(dec, (i, "party time 🎉".to_owned()));
+        }
+
+        assert_eq!(buf.capacity(), 64);
+        assert_eq!(buf.as_ptr(), ptr);
+    }
+
+    #[test]
+    fn test_bincode_codec_decode_with_remainder() {
+        let first = (42_u32, String::from("party time 🎉"));
+        let second = vec![1.5_f64, -2.0];
+
+        let mut buf = BincodeSerdeCodec::encode(&first).unwrap();
+        let second_enc = BincodeSerdeCodec::encode(&second).unwrap();
+        buf.extend_from_slice(&second_enc);
+
+        let (dec, rest): ((u32, String), _) =
+            BincodeSerdeCodec::decode_with_remainder(&buf).unwrap();
+        assert_eq!(dec, first);
+        assert_eq!(rest, second_enc.as_slice());
+
+        let (dec, rest): (Vec<f64>, _) = BincodeSerdeCodec::decode_with_remainder(rest).unwrap();
+        assert_eq!(dec, second);
+        assert!(rest.is_empty());
+    }
+
+    #[cfg(feature = "smallvec")]
+    #[test]
+    fn test_bincode_codec_encode_smallvec() {
+        let enc = BincodeSerdeCodec::encode_smallvec::<16>(&(42_u32, true)).unwrap();
+        assert!(!enc.spilled());
+        assert_eq!(
+            enc.as_slice(),
+            BincodeSerdeCodec::encode(&(42_u32, true)).unwrap()
+        );
+
+        let t = (42_u32, String::from("party time 🎉"));
+        let enc = BincodeSerdeCodec::encode_smallvec::<16>(&t).unwrap();
+        assert!(enc.spilled());
+
+        let dec: (u32, String) = BincodeSerdeCodec::decode(enc.as_slice()).unwrap();
+        assert_eq!(dec, t);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_bincode_codec_encode_to_writer() {
+        let t = (42_u32, String::from("party time 🎉"));
+
+        let mut writer: Vec<u8> = Vec::new();
+        BincodeSerdeCodec::encode_to_writer(&t, &mut writer).unwrap();
+        assert_eq!(writer, BincodeSerdeCodec::encode(&t).unwrap());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_bincode_codec_decode_from_reader() {
+        let t = (42_u32, String::from("party time 🎉"));
+
+        let mut reader = std::io::Cursor::new(BincodeSerdeCodec::encode(&t).unwrap());
+        let dec: (u32, String) = BincodeSerdeCodec::decode_from_reader(&mut reader).unwrap();
+        assert_eq!(dec, t);
+    }
 }
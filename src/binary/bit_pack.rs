@@ -0,0 +1,305 @@
+use crate::{Decoder, Encoder};
+use alloc::vec::Vec;
+use core::convert::Infallible;
+use thiserror::Error;
+
+/// A value that is stored in a fixed number of bits by [`BitPackCodec`].
+///
+/// This is implemented for `bool`. Implement it for small enums to pack them together with flags.
+pub trait BitField: Sized {
+    /// Number of bits the value occupies. At most 64.
+    const BITS: u32;
+
+    /// Returns the bits of the value. Only the lowest [`Self::BITS`] bits may be set.
+    fn to_bits(&self) -> u64;
+
+    /// Creates the value from its bits or returns `None` if they don't represent a valid value.
+    fn from_bits(bits: u64) -> Option<Self>;
+}
+
+impl BitField for bool {
+    const BITS: u32 = 1;
+
+    fn to_bits(&self) -> u64 {
+        *self as u64
+    }
+
+    fn from_bits(bits: u64) -> Option<Self> {
+        Some(bits != 0)
+    }
+}
+
+/// Implement this for a struct to encode it with [`BitPackCodec`]. The fields have to be
+/// read in the same order they are written.
+pub trait BitPacked: Sized {
+    /// Write the fields of `self` to `writer`.
+    fn pack(&self, writer: &mut BitWriter);
+
+    /// Read the fields from `reader`.
+    fn unpack(reader: &mut BitReader<'_>) -> Result<Self, BitPackDecodeError>;
+}
+
+/// A binary codec that packs the fields of a struct into as few bytes as possible by giving every
+/// field only the number of bits it needs.
+///
+/// The fields are defined by implementing [`BitPacked`]. Bools take one bit and small enums can
+/// implement [`BitField`] to be packed with their own bit width. The bits are filled in the order
+/// the fields are written, starting with the least significant bit of the first byte.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::{BitField, BitPackCodec, BitPackDecodeError, BitPacked, BitReader, BitWriter};
+/// #
+/// #[derive(Debug, PartialEq)]
+/// struct Permissions {
+///     read: bool,
+///     write: bool,
+///     execute: bool,
+///     max_depth: u8,
+/// }
+///
+/// impl BitPacked for Permissions {
+///     fn pack(&self, writer: &mut BitWriter) {
+///         writer
+///             .write(&self.read)
+///             .write(&self.write)
+///             .write(&self.execute)
+///             .write_bits(self.max_depth as u64, 5);
+///     }
+///
+///     fn unpack(reader: &mut BitReader<'_>) -> Result<Self, BitPackDecodeError> {
+///         Ok(Self {
+///             read: reader.read()?,
+///             write: reader.read()?,
+///             execute: reader.read()?,
+///             max_depth: reader.read_bits(5)? as u8,
+///         })
+///     }
+/// }
+///
+/// let permissions = Permissions { read: true, write: false, execute: true, max_depth: 20 };
+///
+/// let encoded = BitPackCodec::encode(&permissions).unwrap();
+/// // max_depth, execute, write, read from the most to the least significant bit
+/// assert_eq!(encoded, vec![0b10100101]);
+///
+/// let decoded: Permissions = BitPackCodec::decode(&encoded).unwrap();
+/// assert_eq!(decoded, permissions);
+/// ```
+pub struct BitPackCodec;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum BitPackDecodeError {
+    #[error("unexpected end of input")]
+    UnexpectedEnd,
+    #[error("bits {0:#b} don't represent a valid value")]
+    InvalidValue(u64),
+    #[error("{0} trailing bytes after the packed fields")]
+    TrailingBytes(usize),
+}
+
+/// Writes values of arbitrary bit widths into a byte buffer. Passed to [`BitPacked::pack`].
+#[derive(Default)]
+pub struct BitWriter {
+    buf: Vec<u8>,
+    len: usize,
+}
+
+impl BitWriter {
+    /// Appends the bits of `field`.
+    pub fn write<F: BitField>(&mut self, field: &F) -> &mut Self {
+        self.write_bits(field.to_bits(), F::BITS)
+    }
+
+    /// Appends the lowest `width` bits of `value`. `width` must be at most 64 and `value` must
+    /// fit into it.
+    pub fn write_bits(&mut self, value: u64, width: u32) -> &mut Self {
+        debug_assert!(width <= 64, "bit width {width} is larger than 64");
+        debug_assert!(
+            width == 64 || value >> width == 0,
+            "value {value} doesn't fit into {width} bits"
+        );
+
+        for i in 0..width {
+            if self.len.is_multiple_of(8) {
+                self.buf.push(0);
+            }
+            if (value >> i) & 1 == 1 {
+                *self.buf.last_mut().unwrap() |= 1 << (self.len % 8);
+            }
+            self.len += 1;
+        }
+
+        self
+    }
+}
+
+/// Reads values of arbitrary bit widths from a byte buffer. Passed to [`BitPacked::unpack`].
+pub struct BitReader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl BitReader<'_> {
+    /// Reads the next `F::BITS` bits and converts them with [`BitField::from_bits`].
+    pub fn read<F: BitField>(&mut self) -> Result<F, BitPackDecodeError> {
+        let bits = self.read_bits(F::BITS)?;
+        F::from_bits(bits).ok_or(BitPackDecodeError::InvalidValue(bits))
+    }
+
+    /// Reads the next `width` bits. `width` must be at most 64.
+    pub fn read_bits(&mut self, width: u32) -> Result<u64, BitPackDecodeError> {
+        debug_assert!(width <= 64, "bit width {width} is larger than 64");
+
+        if self.pos + width as usize > self.buf.len() * 8 {
+            return Err(BitPackDecodeError::UnexpectedEnd);
+        }
+
+        let mut value = 0;
+        for i in 0..width {
+            let bit = (self.buf[self.pos / 8] >> (self.pos % 8)) & 1;
+            value |= (bit as u64) << i;
+            self.pos += 1;
+        }
+
+        Ok(value)
+    }
+}
+
+impl<T: BitPacked> Encoder<T> for BitPackCodec {
+    type Error = Infallible;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        let mut writer = BitWriter::default();
+        val.pack(&mut writer);
+
+        Ok(writer.buf)
+    }
+}
+
+impl<T: BitPacked> Decoder<T> for BitPackCodec {
+    type Error = BitPackDecodeError;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        let mut reader = BitReader { buf: val, pos: 0 };
+        let value = T::unpack(&mut reader)?;
+
+        let trailing = val.len() - reader.pos.div_ceil(8);
+        if trailing > 0 {
+            return Err(BitPackDecodeError::TrailingBytes(trailing));
+        }
+
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    enum Level {
+        Low,
+        Medium,
+        High,
+    }
+
+    impl BitField for Level {
+        const BITS: u32 = 2;
+
+        fn to_bits(&self) -> u64 {
+            *self as u64
+        }
+
+        fn from_bits(bits: u64) -> Option<Self> {
+            match bits {
+                0 => Some(Level::Low),
+                1 => Some(Level::Medium),
+                2 => Some(Level::High),
+                _ => None,
+            }
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Flags {
+        a: bool,
+        b: bool,
+        c: bool,
+        level: Level,
+    }
+
+    impl BitPacked for Flags {
+        fn pack(&self, writer: &mut BitWriter) {
+            writer
+                .write(&self.a)
+                .write(&self.b)
+                .write(&self.c)
+                .write(&self.level);
+        }
+
+        fn unpack(reader: &mut BitReader<'_>) -> Result<Self, BitPackDecodeError> {
+            Ok(Self {
+                a: reader.read()?,
+                b: reader.read()?,
+                c: reader.read()?,
+                level: reader.read()?,
+            })
+        }
+    }
+
+    #[test]
+    fn test_bit_pack_codec() {
+        let flags = Flags {
+            a: true,
+            b: false,
+            c: true,
+            level: Level::High,
+        };
+
+        let enc = BitPackCodec::encode(&flags).unwrap();
+        // level, c, b, a from the most to the least significant bit
+        assert_eq!(enc, vec![0b10101]);
+        assert_eq!(BitPackCodec::decode(&enc), Ok(flags));
+    }
+
+    #[test]
+    fn test_bit_pack_codec_invalid() {
+        assert_eq!(
+            <BitPackCodec as Decoder<Flags>>::decode(&[0b11000]),
+            Err(BitPackDecodeError::InvalidValue(0b11))
+        );
+        assert_eq!(
+            <BitPackCodec as Decoder<Flags>>::decode(&[]),
+            Err(BitPackDecodeError::UnexpectedEnd)
+        );
+        assert_eq!(
+            <BitPackCodec as Decoder<Flags>>::decode(&[0, 0]),
+            Err(BitPackDecodeError::TrailingBytes(1))
+        );
+    }
+
+    #[test]
+    fn test_bit_writer_across_bytes() {
+        let mut writer = BitWriter::default();
+        writer
+            .write_bits(0b101, 3)
+            .write_bits(u64::MAX, 64)
+            .write(&false);
+        assert_eq!(writer.len, 68);
+
+        let mut reader = BitReader {
+            buf: &writer.buf,
+            pos: 0,
+        };
+        assert_eq!(reader.read_bits(3), Ok(0b101));
+        assert_eq!(reader.read_bits(64), Ok(u64::MAX));
+        assert_eq!(reader.read::<bool>(), Ok(false));
+        assert_eq!(reader.read_bits(4), Ok(0));
+        assert_eq!(reader.read_bits(1), Err(BitPackDecodeError::UnexpectedEnd));
+    }
+}
@@ -0,0 +1,180 @@
+use crate::binary::Compressor;
+use crate::{Decoder, Encoder};
+use std::io::{Read, Write};
+use thiserror::Error;
+
+/// [`Compressor`] that uses [brotli](https://github.com/google/brotli). See [`BrotliCodec`] for
+/// the meaning of `QUALITY` and `LGWIN`.
+///
+/// Only available with the **`brotli` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::{Brotli, FromToBytesCodec, MaybeCompressed};
+/// #
+/// type Codec = MaybeCompressed<FromToBytesCodec, Brotli, 64>;
+///
+/// let long = "hello".repeat(100);
+/// let encoded = Codec::encode(&long).unwrap();
+/// assert_eq!(encoded[0], 1);
+/// assert!(encoded.len() < long.len());
+/// let decoded: String = Codec::decode(&encoded).unwrap();
+/// assert_eq!(decoded, long);
+/// ```
+pub struct Brotli<const QUALITY: u32 = 5, const LGWIN: u32 = 22>;
+
+const BUFFER_SIZE: usize = 4096;
+
+impl<const QUALITY: u32, const LGWIN: u32> Compressor for Brotli<QUALITY, LGWIN> {
+    type Error = std::io::Error;
+
+    fn compress(val: &[u8]) -> Result<Vec<u8>, Self::Error> {
+        const {
+            assert!(QUALITY <= 11, "brotli quality has to be between 0 and 11");
+            assert!(
+                LGWIN >= 10 && LGWIN <= 24,
+                "brotli window size has to be between 10 and 24"
+            );
+        }
+
+        let mut writer = brotli::CompressorWriter::new(Vec::new(), BUFFER_SIZE, QUALITY, LGWIN);
+        writer.write_all(val)?;
+        writer.flush()?;
+
+        Ok(writer.into_inner())
+    }
+
+    fn decompress(val: &[u8]) -> Result<Vec<u8>, Self::Error> {
+        let mut buf = Vec::new();
+        brotli::Decompressor::new(val, BUFFER_SIZE).read_to_end(&mut buf)?;
+
+        Ok(buf)
+    }
+}
+
+/// Wraps a binary codec and compresses its output with [brotli](https://github.com/google/brotli).
+/// To only compress large outputs use [`MaybeCompressed`](crate::binary::MaybeCompressed) with
+/// [`Brotli`] instead.
+///
+/// `QUALITY` ranges from 0 (fastest) to 11 (smallest output) and defaults to 5. `LGWIN` is the
+/// base 2 logarithm of the sliding window size and ranges from 10 to 24. It defaults to 22.
+/// Both are checked at compile time.
+///
+/// Brotli usually compresses text noticeably better than gzip.
+///
+/// Only available with the **`brotli` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::{BrotliCodec, FromToBytesCodec};
+/// #
+/// let original_value = "hello world ".repeat(100);
+///
+/// let encoded = BrotliCodec::<FromToBytesCodec>::encode(&original_value).unwrap();
+/// assert!(encoded.len() < original_value.len());
+///
+/// let decoded: String = BrotliCodec::<FromToBytesCodec>::decode(&encoded).unwrap();
+/// assert_eq!(decoded, original_value);
+///
+/// // maximum compression with a smaller window
+/// let encoded = BrotliCodec::<FromToBytesCodec, 11, 16>::encode(&original_value).unwrap();
+/// ```
+pub struct BrotliCodec<C, const QUALITY: u32 = 5, const LGWIN: u32 = 22>(C);
+
+#[derive(Error, Debug)]
+pub enum BrotliEncodeError<Err> {
+    #[error("failed to encode: {0}")]
+    Encoder(Err),
+    #[error("failed to compress brotli: {0}")]
+    Compress(std::io::Error),
+}
+
+#[derive(Error, Debug)]
+pub enum BrotliDecodeError<Err> {
+    #[error("failed to decompress brotli: {0}")]
+    Decompress(std::io::Error),
+    #[error("failed to decode: {0}")]
+    Decoder(Err),
+}
+
+impl<T, E, const QUALITY: u32, const LGWIN: u32> Encoder<T> for BrotliCodec<E, QUALITY, LGWIN>
+where
+    E: Encoder<T, Encoded = Vec<u8>>,
+{
+    type Error = BrotliEncodeError<E::Error>;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        let payload = E::encode(val).map_err(BrotliEncodeError::Encoder)?;
+
+        Brotli::<QUALITY, LGWIN>::compress(&payload).map_err(BrotliEncodeError::Compress)
+    }
+}
+
+impl<T, D, const QUALITY: u32, const LGWIN: u32> Decoder<T> for BrotliCodec<D, QUALITY, LGWIN>
+where
+    D: Decoder<T, Encoded = [u8]>,
+{
+    type Error = BrotliDecodeError<D::Error>;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        let payload =
+            Brotli::<QUALITY, LGWIN>::decompress(val).map_err(BrotliDecodeError::Decompress)?;
+
+        D::decode(&payload).map_err(BrotliDecodeError::Decoder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary::{FromToBytesCodec, MaybeCompressed};
+
+    #[test]
+    fn test_brotli_codec() {
+        let s = "party time 🎉 ".repeat(100);
+
+        let enc = BrotliCodec::<FromToBytesCodec>::encode(&s).unwrap();
+        assert!(enc.len() < s.len() / 10);
+
+        let dec: String = BrotliCodec::<FromToBytesCodec>::decode(&enc).unwrap();
+        assert_eq!(dec, s);
+
+        let enc = BrotliCodec::<FromToBytesCodec, 0, 10>::encode(&s).unwrap();
+        let dec: String = BrotliCodec::<FromToBytesCodec, 0, 10>::decode(&enc).unwrap();
+        assert_eq!(dec, s);
+    }
+
+    #[test]
+    fn test_brotli_maybe_compressed() {
+        type Codec = MaybeCompressed<FromToBytesCodec, Brotli<11>, 64>;
+
+        let short = String::from("party time 🎉");
+        let enc = Codec::encode(&short).unwrap();
+        assert_eq!(enc[0], 0);
+        let dec: String = Codec::decode(&enc).unwrap();
+        assert_eq!(dec, short);
+
+        let long = short.repeat(20);
+        let enc = Codec::encode(&long).unwrap();
+        assert_eq!(enc[0], 1);
+        assert_eq!(&enc[1..], Brotli::<11>::compress(long.as_bytes()).unwrap());
+        let dec: String = Codec::decode(&enc).unwrap();
+        assert_eq!(dec, long);
+    }
+
+    #[test]
+    fn test_brotli_codec_errors() {
+        let dec: Result<String, _> = BrotliCodec::<FromToBytesCodec>::decode(b"not brotli");
+        assert!(matches!(dec, Err(BrotliDecodeError::Decompress(_))));
+
+        let enc = BrotliCodec::<FromToBytesCodec>::encode(&0xfffe_u16).unwrap();
+        let dec: Result<String, _> = BrotliCodec::<FromToBytesCodec>::decode(&enc);
+        assert!(matches!(dec, Err(BrotliDecodeError::Decoder(_))));
+    }
+}
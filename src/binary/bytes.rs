@@ -0,0 +1,94 @@
+use crate::{Decoder, Encoder};
+use alloc::vec::Vec;
+use bytes::Bytes;
+
+/// Wraps a binary codec and makes its encoded output a [`bytes::Bytes`] instead of a `Vec<u8>`.
+///
+/// The conversion from `Vec<u8>` to `Bytes` doesn't copy or reallocate so the encoded output can
+/// be handed to frameworks like `axum` or `tokio-util` for free. Decoding still takes a `&[u8]`.
+///
+/// Only available with the **`bytes` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::{BytesCodec, MsgpackSerdeCodec};
+/// # use bytes::Bytes;
+/// #
+/// let encoded: Bytes = BytesCodec::<MsgpackSerdeCodec>::encode(&vec![1, 2, 3]).unwrap();
+/// let decoded: Vec<i32> = BytesCodec::<MsgpackSerdeCodec>::decode(&encoded).unwrap();
+///
+/// assert_eq!(decoded, vec![1, 2, 3]);
+/// ```
+pub struct BytesCodec<C>(C);
+
+impl<T, E> Encoder<T> for BytesCodec<E>
+where
+    E: Encoder<T, Encoded = Vec<u8>>,
+{
+    type Error = E::Error;
+    type Encoded = Bytes;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        E::encode(val).map(Bytes::from)
+    }
+
+    fn size_hint(val: &T) -> Option<usize> {
+        E::size_hint(val)
+    }
+}
+
+impl<T, D> Decoder<T> for BytesCodec<D>
+where
+    D: Decoder<T, Encoded = [u8]>,
+{
+    type Error = D::Error;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        D::decode(val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary::FromToBytesCodec;
+
+    #[test]
+    fn test_bytes_codec() {
+        let enc: Bytes = BytesCodec::<FromToBytesCodec>::encode(&42_u32).unwrap();
+        assert_eq!(enc, Bytes::from_static(&[0, 0, 0, 42]));
+        assert_eq!(BytesCodec::<FromToBytesCodec>::size_hint(&42_u32), Some(4));
+
+        let dec: u32 = BytesCodec::<FromToBytesCodec>::decode(&enc).unwrap();
+        assert_eq!(dec, 42);
+    }
+
+    #[cfg(all(feature = "bincode_serde", feature = "msgpack_serde"))]
+    #[test]
+    fn test_bytes_serde_codecs() {
+        use crate::binary::{BincodeSerdeCodec, MsgpackSerdeCodec};
+
+        #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Test {
+            s: String,
+            i: i32,
+        }
+        let t = Test {
+            s: String::from("party time 🎉"),
+            i: 42,
+        };
+
+        let enc: Bytes = BytesCodec::<BincodeSerdeCodec>::encode(&t).unwrap();
+        assert_eq!(enc, BincodeSerdeCodec::encode(&t).unwrap());
+        let dec: Test = BytesCodec::<BincodeSerdeCodec>::decode(&enc).unwrap();
+        assert_eq!(dec, t);
+
+        let enc: Bytes = BytesCodec::<MsgpackSerdeCodec>::encode(&t).unwrap();
+        assert_eq!(enc, MsgpackSerdeCodec::encode(&t).unwrap());
+        let dec: Test = BytesCodec::<MsgpackSerdeCodec>::decode(&enc).unwrap();
+        assert_eq!(dec, t);
+    }
+}
@@ -0,0 +1,181 @@
+use crate::{Decoder, Encoder};
+use capnp::message::{Builder, HeapAllocator, Reader, ReaderOptions, ReaderSegments};
+use capnp::serialize;
+
+/// Implement this to make a type encodable with the Cap'n Proto codecs [`CapnpCodec`] and
+/// [`CapnpPackedCodec`](crate::binary::CapnpPackedCodec).
+///
+/// Cap'n Proto doesn't use serde but message builders and readers that are usually generated by
+/// `capnpc` from a schema. This trait maps `Self` to and from such a message.
+///
+/// Only available with the **`capnp` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::binary::CapnpMessage;
+/// # use capnp::message::{Builder, HeapAllocator, Reader, ReaderSegments};
+/// #
+/// struct Samples(Vec<u16>);
+///
+/// impl CapnpMessage for Samples {
+///     fn build_message(&self, message: &mut Builder<HeapAllocator>) -> capnp::Result<()> {
+///         // With a generated schema this would be `message.init_root::<samples::Builder>()`
+///         let mut list = message.initn_root::<capnp::primitive_list::Builder<u16>>(self.0.len() as u32);
+///         for (i, sample) in self.0.iter().enumerate() {
+///             list.set(i as u32, *sample);
+///         }
+///         Ok(())
+///     }
+///
+///     fn read_message<S: ReaderSegments>(message: &Reader<S>) -> capnp::Result<Self> {
+///         let list = message.get_root::<capnp::primitive_list::Reader<u16>>()?;
+///         Ok(Samples(list.iter().collect()))
+///     }
+/// }
+/// ```
+pub trait CapnpMessage: Sized {
+    /// Writes `self` into the root of the empty `message`.
+    fn build_message(&self, message: &mut Builder<HeapAllocator>) -> capnp::Result<()>;
+
+    /// Reads the value from the root of `message`.
+    fn read_message<S: ReaderSegments>(message: &Reader<S>) -> capnp::Result<Self>;
+}
+
+/// Builds the message for `val` with a fresh builder.
+pub(crate) fn build<T: CapnpMessage>(val: &T) -> capnp::Result<Builder<HeapAllocator>> {
+    let mut message = Builder::new_default();
+    val.build_message(&mut message)?;
+    Ok(message)
+}
+
+/// A binary codec that encodes types implementing [`CapnpMessage`] using the standard
+/// Cap'n Proto encoding.
+///
+/// The standard encoding is a segment table followed by the message words. It can be read
+/// without any decoding step which is ideal for zero-copy IPC. Use
+/// [`CapnpPackedCodec`](crate::binary::CapnpPackedCodec) if size matters more.
+///
+/// Only available with the **`capnp` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::{CapnpMessage, CapnpCodec};
+/// # use capnp::message::{Builder, HeapAllocator, Reader, ReaderSegments};
+/// #
+/// #[derive(Debug, PartialEq)]
+/// struct Samples(Vec<u16>);
+///
+/// impl CapnpMessage for Samples {
+///     // ...
+/// #     fn build_message(&self, message: &mut Builder<HeapAllocator>) -> capnp::Result<()> {
+/// #         let mut list = message.initn_root::<capnp::primitive_list::Builder<u16>>(self.0.len() as u32);
+/// #         for (i, sample) in self.0.iter().enumerate() {
+/// #             list.set(i as u32, *sample);
+/// #         }
+/// #         Ok(())
+/// #     }
+/// #
+/// #     fn read_message<S: ReaderSegments>(message: &Reader<S>) -> capnp::Result<Self> {
+/// #         let list = message.get_root::<capnp::primitive_list::Reader<u16>>()?;
+/// #         Ok(Samples(list.iter().collect()))
+/// #     }
+/// }
+///
+/// let samples = Samples(vec![1, 2, 3]);
+///
+/// let encoded = CapnpCodec::encode(&samples).unwrap();
+/// let decoded: Samples = CapnpCodec::decode(&encoded).unwrap();
+///
+/// assert_eq!(decoded, samples);
+/// ```
+pub struct CapnpCodec;
+
+impl<T: CapnpMessage> Encoder<T> for CapnpCodec {
+    type Error = capnp::Error;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        let message = build(val)?;
+
+        let mut buf = vec![];
+        serialize::write_message(&mut buf, &message)?;
+
+        Ok(buf)
+    }
+}
+
+impl<T: CapnpMessage> Decoder<T> for CapnpCodec {
+    type Error = capnp::Error;
+    type Encoded = [u8];
+
+    fn decode(mut val: &Self::Encoded) -> Result<T, Self::Error> {
+        let message = serialize::read_message(&mut val, ReaderOptions::new())?;
+        T::read_message(&message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Stand-in for a struct generated by `capnpc` from the schema
+    /// `struct Person { name @0 :Text; emails @1 :List(Text); }`.
+    /// It is stored as a text list with the name first.
+    #[derive(Debug, PartialEq)]
+    struct Person {
+        name: String,
+        emails: Vec<String>,
+    }
+
+    impl CapnpMessage for Person {
+        fn build_message(&self, message: &mut Builder<HeapAllocator>) -> capnp::Result<()> {
+            let mut list =
+                message.initn_root::<capnp::text_list::Builder>(1 + self.emails.len() as u32);
+            list.set(0, self.name.as_str());
+            for (i, email) in self.emails.iter().enumerate() {
+                list.set(1 + i as u32, email.as_str());
+            }
+            Ok(())
+        }
+
+        fn read_message<S: ReaderSegments>(message: &Reader<S>) -> capnp::Result<Self> {
+            let list = message.get_root::<capnp::text_list::Reader>()?;
+            let mut texts = list
+                .iter()
+                .map(|text| Ok(text?.to_string()?))
+                .collect::<capnp::Result<Vec<_>>>()?;
+
+            if texts.is_empty() {
+                return Err(capnp::Error::failed("missing name".to_owned()));
+            }
+            let name = texts.remove(0);
+
+            Ok(Person {
+                name,
+                emails: texts,
+            })
+        }
+    }
+
+    #[test]
+    fn test_capnp_codec() {
+        let person = Person {
+            name: String::from("party time 🎉"),
+            emails: vec!["a@example.com".to_owned(), "b@example.com".to_owned()],
+        };
+
+        let enc = CapnpCodec::encode(&person).unwrap();
+        // one segment, aligned to words
+        assert_eq!(&enc[..4], &[0, 0, 0, 0]);
+        assert_eq!(enc.len() % 8, 0);
+
+        let dec: Person = CapnpCodec::decode(&enc).unwrap();
+        assert_eq!(dec, person);
+
+        let dec: Result<Person, _> = CapnpCodec::decode(&enc[..enc.len() - 8]);
+        assert!(dec.is_err());
+    }
+}
@@ -0,0 +1,119 @@
+use super::capnp::build;
+use crate::binary::CapnpMessage;
+use crate::{Decoder, Encoder};
+use capnp::message::ReaderOptions;
+use capnp::serialize_packed;
+
+/// A binary codec that encodes types implementing [`CapnpMessage`] using the packed
+/// Cap'n Proto encoding.
+///
+/// The packed encoding compresses runs of zero bytes which makes it considerably smaller than
+/// the standard encoding for most messages at the cost of a little CPU time.
+///
+/// Only available with the **`capnp` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::{CapnpMessage, CapnpPackedCodec};
+/// # use capnp::message::{Builder, HeapAllocator, Reader, ReaderSegments};
+/// #
+/// #[derive(Debug, PartialEq)]
+/// struct Samples(Vec<u16>);
+///
+/// impl CapnpMessage for Samples {
+///     // ...
+/// #     fn build_message(&self, message: &mut Builder<HeapAllocator>) -> capnp::Result<()> {
+/// #         let mut list = message.initn_root::<capnp::primitive_list::Builder<u16>>(self.0.len() as u32);
+/// #         for (i, sample) in self.0.iter().enumerate() {
+/// #             list.set(i as u32, *sample);
+/// #         }
+/// #         Ok(())
+/// #     }
+/// #
+/// #     fn read_message<S: ReaderSegments>(message: &Reader<S>) -> capnp::Result<Self> {
+/// #         let list = message.get_root::<capnp::primitive_list::Reader<u16>>()?;
+/// #         Ok(Samples(list.iter().collect()))
+/// #     }
+/// }
+///
+/// let samples = Samples(vec![1, 2, 3]);
+///
+/// let encoded = CapnpPackedCodec::encode(&samples).unwrap();
+/// let decoded: Samples = CapnpPackedCodec::decode(&encoded).unwrap();
+///
+/// assert_eq!(decoded, samples);
+/// ```
+pub struct CapnpPackedCodec;
+
+impl<T: CapnpMessage> Encoder<T> for CapnpPackedCodec {
+    type Error = capnp::Error;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        let message = build(val)?;
+
+        let mut buf = vec![];
+        serialize_packed::write_message(&mut buf, &message)?;
+
+        Ok(buf)
+    }
+}
+
+impl<T: CapnpMessage> Decoder<T> for CapnpPackedCodec {
+    type Error = capnp::Error;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        let message = serialize_packed::read_message(val, ReaderOptions::new())?;
+        T::read_message(&message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use capnp::message::{Builder, HeapAllocator, Reader, ReaderSegments};
+
+    #[derive(Debug, PartialEq)]
+    struct Samples(Vec<u64>);
+
+    impl CapnpMessage for Samples {
+        fn build_message(&self, message: &mut Builder<HeapAllocator>) -> capnp::Result<()> {
+            let mut list =
+                message.initn_root::<capnp::primitive_list::Builder<u64>>(self.0.len() as u32);
+            for (i, sample) in self.0.iter().enumerate() {
+                list.set(i as u32, *sample);
+            }
+            Ok(())
+        }
+
+        fn read_message<S: ReaderSegments>(message: &Reader<S>) -> capnp::Result<Self> {
+            let list = message.get_root::<capnp::primitive_list::Reader<u64>>()?;
+            Ok(Samples(list.iter().collect()))
+        }
+    }
+
+    #[test]
+    fn test_capnp_packed_codec() {
+        let samples = Samples(vec![0, 1, 42, u64::MAX, 0, 0, 7]);
+
+        let enc = CapnpPackedCodec::encode(&samples).unwrap();
+        let dec: Samples = CapnpPackedCodec::decode(&enc).unwrap();
+        assert_eq!(dec, samples);
+
+        let dec: Result<Samples, _> = CapnpPackedCodec::decode(&enc[..enc.len() - 1]);
+        assert!(dec.is_err());
+    }
+
+    #[test]
+    fn test_capnp_packed_codec_is_smaller() {
+        let samples = Samples(vec![1; 100]);
+
+        let packed = CapnpPackedCodec::encode(&samples).unwrap();
+        let unpacked = capnp::serialize::write_message_to_words(&build(&samples).unwrap());
+
+        assert!(packed.len() < unpacked.len());
+    }
+}
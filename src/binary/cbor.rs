@@ -0,0 +1,402 @@
+use crate::{Decoder, DecoderWithRemainder, Encoder, StreamDecoder, StreamEncoder};
+use ciborium::Value;
+use serde::{de::DeserializeOwned, Serialize};
+use thiserror::Error;
+
+/// A binary codec that uses [`ciborium`] to encode data as CBOR with the field names of structs as
+/// map keys.
+///
+/// Storing the field names makes the output larger, but data stays decodable when fields are
+/// reordered or added (with `#[serde(default)]`). If size matters more than flexibility have a
+/// look at [`CiboriumIntKeyedCodec`] which replaces the field names with small integers.
+///
+/// Only available with the **`cbor` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::CiboriumNamedCodec;
+/// # use serde::{Deserialize, Serialize};
+/// #
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct MyState {
+///     chicken_count: u32,
+///     farm_name: String,
+/// }
+///
+/// let original_value = MyState {
+///     chicken_count: 10,
+///     farm_name: "My Farm".to_owned(),
+/// };
+///
+/// let encoded = CiboriumNamedCodec::encode(&original_value).unwrap();
+/// let decoded: MyState = CiboriumNamedCodec::decode(&encoded).unwrap();
+///
+/// assert_eq!(decoded, original_value);
+/// ```
+pub struct CiboriumNamedCodec;
+
+impl<T: Serialize> Encoder<T> for CiboriumNamedCodec {
+    type Error = ciborium::ser::Error<std::io::Error>;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        let mut buf = Vec::new();
+        ciborium::into_writer(val, &mut buf)?;
+        Ok(buf)
+    }
+}
+
+impl<T: Serialize> StreamEncoder<T> for CiboriumNamedCodec {
+    fn encode_to_writer<W: std::io::Write>(val: &T, writer: &mut W) -> Result<(), Self::Error> {
+        ciborium::into_writer(val, writer)
+    }
+}
+
+impl<T: DeserializeOwned> Decoder<T> for CiboriumNamedCodec {
+    type Error = ciborium::de::Error<std::io::Error>;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        ciborium::from_reader(val)
+    }
+}
+
+impl<T: DeserializeOwned> DecoderWithRemainder<T> for CiboriumNamedCodec {
+    fn decode_with_remainder(mut val: &[u8]) -> Result<(T, &[u8]), Self::Error> {
+        let value = ciborium::from_reader(&mut val)?;
+        Ok((value, val))
+    }
+}
+
+impl<T: DeserializeOwned> StreamDecoder<T> for CiboriumNamedCodec {
+    fn decode_from_reader<R: std::io::Read>(reader: &mut R) -> Result<T, Self::Error> {
+        ciborium::from_reader(reader)
+    }
+}
+
+/// Implement this to provide the integer keys that [`CiboriumIntKeyedCodec`] uses instead of the
+/// field names of `Self`.
+///
+/// Every field of the struct has to be listed. To stay compatible with existing data never reuse
+/// the key of a removed field and never change the key of an existing field.
+///
+/// Only available with the **`cbor` feature** enabled.
+pub trait CborIntKeys {
+    /// Pairs of field name and integer key.
+    const FIELD_KEYS: &'static [(&'static str, u64)];
+}
+
+/// Like [`CiboriumNamedCodec`] but the field names of the top-level struct are replaced with the
+/// integer keys from [`CborIntKeys`].
+///
+/// Small integers take only a single byte in CBOR so the output is much more compact. Like with
+/// the named variant fields can be added (with `#[serde(default)]`) or removed as long as their
+/// keys stay the same. Unknown keys are ignored when decoding. Only the fields of the top-level
+/// struct are mapped; nested structs keep their field names.
+///
+/// Only available with the **`cbor` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::{CborIntKeys, CiboriumIntKeyedCodec, CiboriumNamedCodec};
+/// # use serde::{Deserialize, Serialize};
+/// #
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct MyState {
+///     chicken_count: u32,
+///     farm_name: String,
+/// }
+///
+/// impl CborIntKeys for MyState {
+///     const FIELD_KEYS: &'static [(&'static str, u64)] = &[("chicken_count", 0), ("farm_name", 1)];
+/// }
+///
+/// let original_value = MyState {
+///     chicken_count: 10,
+///     farm_name: "My Farm".to_owned(),
+/// };
+///
+/// let encoded = CiboriumIntKeyedCodec::encode(&original_value).unwrap();
+/// let decoded: MyState = CiboriumIntKeyedCodec::decode(&encoded).unwrap();
+///
+/// assert_eq!(decoded, original_value);
+/// assert!(encoded.len() < CiboriumNamedCodec::encode(&original_value).unwrap().len());
+/// ```
+pub struct CiboriumIntKeyedCodec;
+
+#[derive(Error, Debug)]
+pub enum CiboriumIntKeyedEncodeError {
+    #[error("only structs and maps can be encoded with integer keys")]
+    NotAMap,
+    #[error("map key {0:?} is not a field name")]
+    NonStringKey(Value),
+    #[error("no integer key for field `{0}`")]
+    UnknownField(String),
+    #[error("failed to convert to CBOR value: {0}")]
+    Value(#[from] ciborium::value::Error),
+    #[error("failed to serialize: {0}")]
+    Serialize(#[from] ciborium::ser::Error<std::io::Error>),
+}
+
+#[derive(Error, Debug)]
+pub enum CiboriumIntKeyedDecodeError {
+    #[error("expected a map with integer keys")]
+    NotAMap,
+    #[error("failed to deserialize: {0}")]
+    Deserialize(#[from] ciborium::de::Error<std::io::Error>),
+    #[error("failed to convert from CBOR value: {0}")]
+    Value(#[from] ciborium::value::Error),
+}
+
+impl<T: Serialize + CborIntKeys> Encoder<T> for CiboriumIntKeyedCodec {
+    type Error = CiboriumIntKeyedEncodeError;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        let Value::Map(entries) = Value::serialized(val)? else {
+            return Err(CiboriumIntKeyedEncodeError::NotAMap);
+        };
+
+        let entries = entries
+            .into_iter()
+            .map(|(name, value)| {
+                let name = name
+                    .into_text()
+                    .map_err(CiboriumIntKeyedEncodeError::NonStringKey)?;
+                let key = T::FIELD_KEYS
+                    .iter()
+                    .find(|(field, _)| *field == name)
+                    .map(|(_, key)| *key)
+                    .ok_or(CiboriumIntKeyedEncodeError::UnknownField(name))?;
+
+                Ok((Value::from(key), value))
+            })
+            .collect::<Result<Vec<_>, Self::Error>>()?;
+
+        let mut buf = Vec::new();
+        ciborium::into_writer(&Value::Map(entries), &mut buf)?;
+        Ok(buf)
+    }
+}
+
+impl<T: DeserializeOwned + CborIntKeys> Decoder<T> for CiboriumIntKeyedCodec {
+    type Error = CiboriumIntKeyedDecodeError;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        let Value::Map(entries) = ciborium::from_reader(val)? else {
+            return Err(CiboriumIntKeyedDecodeError::NotAMap);
+        };
+
+        let entries = entries
+            .into_iter()
+            .filter_map(|(key, value)| {
+                let key = key.as_integer().and_then(|key| u64::try_from(key).ok())?;
+                T::FIELD_KEYS
+                    .iter()
+                    .find(|(_, k)| *k == key)
+                    .map(|(name, _)| (Value::from(*name), value))
+            })
+            .collect();
+
+        Ok(Value::Map(entries).deserialized()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    struct Test {
+        s: String,
+        i: i32,
+        list: Vec<u8>,
+    }
+
+    impl CborIntKeys for Test {
+        const FIELD_KEYS: &'static [(&'static str, u64)] = &[("s", 0), ("i", 1), ("list", 2)];
+    }
+
+    fn test_value() -> Test {
+        Test {
+            s: String::from("party time 🎉"),
+            i: 42,
+            list: vec![1, 2, 3],
+        }
+    }
+
+    #[test]
+    fn test_ciborium_named_codec() {
+        let t = test_value();
+        let enc = CiboriumNamedCodec::encode(&t).unwrap();
+        let dec: Test = CiboriumNamedCodec::decode(&enc).unwrap();
+        assert_eq!(dec, t);
+    }
+
+    #[test]
+    fn test_ciborium_named_codec_added_field() {
+        #[derive(Serialize)]
+        struct V1 {
+            volume: u8,
+        }
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct V2 {
+            #[serde(default)]
+            muted: bool,
+            volume: u8,
+        }
+
+        let enc = CiboriumNamedCodec::encode(&V1 { volume: 11 }).unwrap();
+        let dec: V2 = CiboriumNamedCodec::decode(&enc).unwrap();
+        assert_eq!(
+            dec,
+            V2 {
+                muted: false,
+                volume: 11
+            }
+        );
+    }
+
+    #[test]
+    fn test_ciborium_named_codec_decode_with_remainder() {
+        let first = (42_u32, String::from("party time 🎉"));
+        let second = vec![1.5_f64, -2.0];
+
+        let mut buf = CiboriumNamedCodec::encode(&first).unwrap();
+        let second_enc = CiboriumNamedCodec::encode(&second).unwrap();
+        buf.extend_from_slice(&second_enc);
+
+        let (dec, rest): ((u32, String), _) =
+            CiboriumNamedCodec::decode_with_remainder(&buf).unwrap();
+        assert_eq!(dec, first);
+        assert_eq!(rest, second_enc.as_slice());
+
+        let (dec, rest): (Vec<f64>, _) = CiboriumNamedCodec::decode_with_remainder(rest).unwrap();
+        assert_eq!(dec, second);
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn test_ciborium_named_codec_encode_to_writer() {
+        let t = test_value();
+
+        let mut writer: Vec<u8> = Vec::new();
+        CiboriumNamedCodec::encode_to_writer(&t, &mut writer).unwrap();
+        assert_eq!(writer, CiboriumNamedCodec::encode(&t).unwrap());
+    }
+
+    #[test]
+    fn test_ciborium_named_codec_decode_from_reader() {
+        let t = test_value();
+
+        let mut buf = CiboriumNamedCodec::encode(&t).unwrap();
+        buf.extend(CiboriumNamedCodec::encode(&7_u8).unwrap());
+
+        let mut reader = std::io::Cursor::new(buf);
+        let dec: Test = CiboriumNamedCodec::decode_from_reader(&mut reader).unwrap();
+        assert_eq!(dec, t);
+        let dec: u8 = CiboriumNamedCodec::decode_from_reader(&mut reader).unwrap();
+        assert_eq!(dec, 7);
+    }
+
+    #[test]
+    fn test_ciborium_int_keyed_codec() {
+        let t = test_value();
+        let enc = CiboriumIntKeyedCodec::encode(&t).unwrap();
+        let dec: Test = CiboriumIntKeyedCodec::decode(&enc).unwrap();
+        assert_eq!(dec, t);
+
+        assert!(enc.len() < CiboriumNamedCodec::encode(&t).unwrap().len());
+    }
+
+    #[test]
+    fn test_ciborium_int_keyed_codec_added_and_removed_fields() {
+        #[derive(Serialize)]
+        struct V1 {
+            removed: String,
+            volume: u8,
+        }
+
+        impl CborIntKeys for V1 {
+            const FIELD_KEYS: &'static [(&'static str, u64)] = &[("removed", 0), ("volume", 1)];
+        }
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct V2 {
+            #[serde(default)]
+            muted: bool,
+            volume: u8,
+        }
+
+        impl CborIntKeys for V2 {
+            const FIELD_KEYS: &'static [(&'static str, u64)] = &[("volume", 1), ("muted", 2)];
+        }
+
+        let enc = CiboriumIntKeyedCodec::encode(&V1 {
+            removed: "gone".to_owned(),
+            volume: 11,
+        })
+        .unwrap();
+        let dec: V2 = CiboriumIntKeyedCodec::decode(&enc).unwrap();
+        assert_eq!(
+            dec,
+            V2 {
+                muted: false,
+                volume: 11
+            }
+        );
+    }
+
+    #[test]
+    fn test_ciborium_int_keyed_codec_errors() {
+        #[derive(Serialize)]
+        struct Incomplete {
+            a: u8,
+            b: u8,
+        }
+
+        impl CborIntKeys for Incomplete {
+            const FIELD_KEYS: &'static [(&'static str, u64)] = &[("a", 0)];
+        }
+
+        let enc = CiboriumIntKeyedCodec::encode(&Incomplete { a: 1, b: 2 });
+        assert!(matches!(
+            enc,
+            Err(CiboriumIntKeyedEncodeError::UnknownField(field)) if field == "b"
+        ));
+
+        #[derive(Serialize)]
+        struct IntMap(std::collections::BTreeMap<u32, u8>);
+
+        impl CborIntKeys for IntMap {
+            const FIELD_KEYS: &'static [(&'static str, u64)] = &[];
+        }
+
+        let enc = CiboriumIntKeyedCodec::encode(&IntMap([(7, 1)].into()));
+        assert!(matches!(
+            enc,
+            Err(CiboriumIntKeyedEncodeError::NonStringKey(key)) if key == Value::from(7)
+        ));
+
+        #[derive(Serialize)]
+        struct Number(u8);
+
+        impl CborIntKeys for Number {
+            const FIELD_KEYS: &'static [(&'static str, u64)] = &[];
+        }
+
+        let enc = CiboriumIntKeyedCodec::encode(&Number(1));
+        assert!(matches!(enc, Err(CiboriumIntKeyedEncodeError::NotAMap)));
+
+        let enc = CiboriumNamedCodec::encode(&42).unwrap();
+        let dec: Result<Test, _> = CiboriumIntKeyedCodec::decode(&enc);
+        assert!(matches!(dec, Err(CiboriumIntKeyedDecodeError::NotAMap)));
+    }
+}
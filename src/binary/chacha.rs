@@ -0,0 +1,217 @@
+use crate::{Decoder, Encoder, KeyProvider};
+use alloc::vec::Vec;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+use core::marker::PhantomData;
+use thiserror::Error;
+
+/// Length of the random nonce that [`ChaChaPolyCodec`] prepends to the ciphertext.
+const NONCE_LEN: usize = 12;
+
+/// Length of the Poly1305 tag that is appended to the ciphertext.
+const TAG_LEN: usize = 16;
+
+/// Wraps a binary codec and encrypts its output with ChaCha20-Poly1305.
+///
+/// Every encoding uses a fresh random nonce which is prepended to the ciphertext, so the output
+/// is laid out as `[12 byte nonce][ciphertext][16 byte tag]`. Decoding rejects data that has been
+/// tampered with before it reaches the inner decoder.
+///
+/// The 32 byte key is provided by `K` which implements [`KeyProvider`]. ChaCha20-Poly1305 is fast
+/// in software, which makes it a good fit for WASM targets that can't use AES hardware
+/// instructions. On `wasm32` the random nonces are generated with the browser's crypto API.
+///
+/// Only available with the **`chacha` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder, KeyProvider};
+/// # use codee::binary::{ChaChaPolyCodec, ChaChaPolyDecodeError, FromToBytesCodec};
+/// #
+/// struct StorageKey;
+///
+/// impl KeyProvider for StorageKey {
+///     fn key() -> &'static [u8] {
+///         b"an example very very secret key."
+///     }
+/// }
+///
+/// type Codec = ChaChaPolyCodec<FromToBytesCodec, StorageKey>;
+///
+/// let mut encoded = Codec::encode(&42_u32).unwrap();
+/// assert_eq!(encoded.len(), 12 + 4 + 16);
+///
+/// let decoded: u32 = Codec::decode(&encoded).unwrap();
+/// assert_eq!(decoded, 42);
+///
+/// encoded[12] ^= 1;
+/// let decoded: Result<u32, _> = Codec::decode(&encoded);
+/// assert!(matches!(decoded, Err(ChaChaPolyDecodeError::Decryption)));
+/// ```
+pub struct ChaChaPolyCodec<C, K>(PhantomData<(C, K)>);
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ChaChaPolyEncodeError<Err> {
+    #[error("key must be 32 bytes long but is {0} bytes")]
+    InvalidKeyLength(usize),
+    #[error("failed to encrypt")]
+    Encryption,
+    #[error("failed to encode: {0}")]
+    Encoder(Err),
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ChaChaPolyDecodeError<Err> {
+    #[error("key must be 32 bytes long but is {0} bytes")]
+    InvalidKeyLength(usize),
+    #[error("input is too short to contain a nonce and a tag")]
+    UnexpectedEnd,
+    #[error("failed to decrypt: the data has been tampered with or the key is wrong")]
+    Decryption,
+    #[error("failed to decode: {0}")]
+    Decoder(Err),
+}
+
+/// Creates the cipher from the key of `K`. Returns the length of the key if it is invalid.
+fn cipher<K: KeyProvider>() -> Result<ChaCha20Poly1305, usize> {
+    let key = K::key();
+    ChaCha20Poly1305::new_from_slice(key).map_err(|_| key.len())
+}
+
+impl<T, E, K> Encoder<T> for ChaChaPolyCodec<E, K>
+where
+    E: Encoder<T, Encoded = Vec<u8>>,
+    K: KeyProvider,
+{
+    type Error = ChaChaPolyEncodeError<E::Error>;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        let cipher = cipher::<K>().map_err(ChaChaPolyEncodeError::InvalidKeyLength)?;
+        let plaintext = E::encode(val).map_err(ChaChaPolyEncodeError::Encoder)?;
+
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = cipher
+            .encrypt(&nonce, plaintext.as_slice())
+            .map_err(|_| ChaChaPolyEncodeError::Encryption)?;
+
+        let mut buf = Vec::with_capacity(NONCE_LEN + ciphertext.len());
+        buf.extend_from_slice(&nonce);
+        buf.extend(ciphertext);
+
+        Ok(buf)
+    }
+
+    fn size_hint(val: &T) -> Option<usize> {
+        E::size_hint(val).map(|len| NONCE_LEN + len + TAG_LEN)
+    }
+}
+
+impl<T, D, K> Decoder<T> for ChaChaPolyCodec<D, K>
+where
+    D: Decoder<T, Encoded = [u8]>,
+    K: KeyProvider,
+{
+    type Error = ChaChaPolyDecodeError<D::Error>;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        let cipher = cipher::<K>().map_err(ChaChaPolyDecodeError::InvalidKeyLength)?;
+
+        if val.len() < NONCE_LEN + TAG_LEN {
+            return Err(ChaChaPolyDecodeError::UnexpectedEnd);
+        }
+        let (nonce, ciphertext) = val.split_at(NONCE_LEN);
+
+        let plaintext = cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| ChaChaPolyDecodeError::Decryption)?;
+
+        D::decode(&plaintext).map_err(ChaChaPolyDecodeError::Decoder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary::FromToBytesCodec;
+
+    struct TestKey;
+
+    impl KeyProvider for TestKey {
+        fn key() -> &'static [u8] {
+            &[7; 32]
+        }
+    }
+
+    struct OtherKey;
+
+    impl KeyProvider for OtherKey {
+        fn key() -> &'static [u8] {
+            &[8; 32]
+        }
+    }
+
+    struct ShortKey;
+
+    impl KeyProvider for ShortKey {
+        fn key() -> &'static [u8] {
+            b"too short"
+        }
+    }
+
+    type Codec = ChaChaPolyCodec<FromToBytesCodec, TestKey>;
+
+    #[test]
+    fn test_chacha_poly_codec() {
+        let s = String::from("party time 🎉");
+
+        let enc = Codec::encode(&s).unwrap();
+        assert_eq!(enc.len(), NONCE_LEN + s.len() + TAG_LEN);
+        assert!(!enc.windows(s.len()).any(|w| w == s.as_bytes()));
+
+        let dec: String = Codec::decode(enc.as_slice()).unwrap();
+        assert_eq!(dec, s);
+    }
+
+    #[test]
+    fn test_chacha_poly_codec_tampered() {
+        let enc = Codec::encode(&42_u64).unwrap();
+
+        for i in 0..enc.len() {
+            let mut tampered = enc.clone();
+            tampered[i] ^= 0x80;
+            let dec: Result<u64, _> = Codec::decode(&tampered);
+            assert!(matches!(dec, Err(ChaChaPolyDecodeError::Decryption)));
+        }
+
+        let dec: Result<u64, _> = ChaChaPolyCodec::<FromToBytesCodec, OtherKey>::decode(&enc);
+        assert!(matches!(dec, Err(ChaChaPolyDecodeError::Decryption)));
+
+        let dec: Result<u64, _> = Codec::decode(&enc[..NONCE_LEN + TAG_LEN - 1]);
+        assert!(matches!(dec, Err(ChaChaPolyDecodeError::UnexpectedEnd)));
+    }
+
+    #[test]
+    fn test_chacha_poly_codec_unique_nonce() {
+        let first = Codec::encode(&42_u64).unwrap();
+        let second = Codec::encode(&42_u64).unwrap();
+
+        assert_ne!(first[..NONCE_LEN], second[..NONCE_LEN]);
+        assert_ne!(first, second);
+
+        let first: u64 = Codec::decode(&first).unwrap();
+        let second: u64 = Codec::decode(&second).unwrap();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_chacha_poly_codec_invalid_key() {
+        let enc = ChaChaPolyCodec::<FromToBytesCodec, ShortKey>::encode(&42_u64);
+        assert!(matches!(
+            enc,
+            Err(ChaChaPolyEncodeError::InvalidKeyLength(9))
+        ));
+    }
+}
@@ -0,0 +1,137 @@
+use crate::{Decoder, Encoder};
+use alloc::vec::Vec;
+use thiserror::Error;
+
+/// A hash function that is used by [`WithContentHash`] to hash the encoded value.
+pub trait ContentHasher: 'static {
+    /// The hash value
+    type Hash: AsRef<[u8]>;
+
+    /// Length of the hash in bytes
+    const LEN: usize;
+
+    fn hash(data: &[u8]) -> Self::Hash;
+}
+
+/// [`ContentHasher`] that uses the [BLAKE3](https://github.com/BLAKE3-team/BLAKE3) hash function.
+///
+/// Only available with the **`blake3` feature** enabled.
+#[cfg(feature = "blake3")]
+pub struct Blake3;
+
+#[cfg(feature = "blake3")]
+impl ContentHasher for Blake3 {
+    type Hash = [u8; 32];
+
+    const LEN: usize = blake3::OUT_LEN;
+
+    fn hash(data: &[u8]) -> Self::Hash {
+        *blake3::hash(data).as_bytes()
+    }
+}
+
+/// Wraps a binary codec and appends a hash of the encoded value computed by the
+/// [`ContentHasher`] `H`.
+///
+/// Decoding verifies the hash and returns it together with the decoded value. If the data has
+/// been modified the hashes don't match and an error is returned. The hash can be used as an
+/// ETag for optimistic concurrency control.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::{WithContentHash, Blake3, FromToBytesCodec};
+/// #
+/// let encoded = WithContentHash::<FromToBytesCodec, Blake3>::encode(&42_u32).unwrap();
+/// let (decoded, hash): (u32, _) = WithContentHash::<FromToBytesCodec, Blake3>::decode(&encoded).unwrap();
+///
+/// assert_eq!(decoded, 42);
+/// assert_eq!(hash, *blake3::hash(&42_u32.to_be_bytes()).as_bytes());
+/// ```
+pub struct WithContentHash<C, H>(C, H);
+
+#[derive(Error, Debug, PartialEq)]
+pub enum WithContentHashDecodeError<Err> {
+    #[error("input is too short to contain a content hash")]
+    TooShort,
+    #[error("content hash mismatch")]
+    HashMismatch,
+    #[error("failed to decode: {0}")]
+    Decoder(Err),
+}
+
+impl<T, E, H> Encoder<T> for WithContentHash<E, H>
+where
+    E: Encoder<T, Encoded = Vec<u8>>,
+    H: ContentHasher,
+{
+    type Error = E::Error;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        let mut buf = E::encode(val)?;
+        let hash = H::hash(&buf);
+        buf.extend_from_slice(hash.as_ref());
+
+        Ok(buf)
+    }
+}
+
+impl<T, D, H> Decoder<(T, H::Hash)> for WithContentHash<D, H>
+where
+    D: Decoder<T, Encoded = [u8]>,
+    H: ContentHasher,
+{
+    type Error = WithContentHashDecodeError<D::Error>;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<(T, H::Hash), Self::Error> {
+        let content_len = val
+            .len()
+            .checked_sub(H::LEN)
+            .ok_or(WithContentHashDecodeError::TooShort)?;
+        let (content, stored_hash) = val.split_at(content_len);
+
+        let hash = H::hash(content);
+        if hash.as_ref() != stored_hash {
+            return Err(WithContentHashDecodeError::HashMismatch);
+        }
+
+        let value = D::decode(content).map_err(WithContentHashDecodeError::Decoder)?;
+
+        Ok((value, hash))
+    }
+}
+
+#[cfg(all(test, feature = "blake3"))]
+mod tests {
+    use super::*;
+    use crate::binary::FromToBytesCodec;
+
+    type Codec = WithContentHash<FromToBytesCodec, Blake3>;
+
+    #[test]
+    fn test_with_content_hash() {
+        let s = String::from("party time 🎉");
+
+        let enc = Codec::encode(&s).unwrap();
+        assert_eq!(enc.len(), s.len() + 32);
+
+        let (dec, hash): (String, _) = Codec::decode(&enc).unwrap();
+        assert_eq!(dec, s);
+        assert_eq!(hash, *blake3::hash(s.as_bytes()).as_bytes());
+    }
+
+    #[test]
+    fn test_with_content_hash_corrupted() {
+        let mut enc = Codec::encode(&String::from("party time 🎉")).unwrap();
+        enc[0] ^= 1;
+
+        let dec: Result<(String, _), _> = Codec::decode(&enc);
+        assert!(matches!(dec, Err(WithContentHashDecodeError::HashMismatch)));
+
+        let dec: Result<(String, _), _> = Codec::decode(&enc[..31]);
+        assert!(matches!(dec, Err(WithContentHashDecodeError::TooShort)));
+    }
+}
@@ -0,0 +1,190 @@
+use crate::{Decoder, Encoder};
+use alloc::string::String;
+use alloc::vec::Vec;
+use thiserror::Error;
+
+/// Implemented by codecs that have a MIME content type. Used by [`ContentTyped`].
+pub trait ContentType {
+    /// The content type like `application/json`. Must not be longer than 255 bytes.
+    const CONTENT_TYPE: &'static str;
+}
+
+/// Wraps a binary codec and prepends its [`ContentType`] to the encoded data. When decoding the
+/// content type is verified to match the one of the wrapped codec.
+///
+/// This helps to route and validate heterogeneous stored values. The output is laid out as
+/// `[u8 len][content type][payload]`.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::{ContentTyped, ContentTypedDecodeError, BincodeSerdeCodec, MsgpackSerdeCodec};
+/// #
+/// let encoded = ContentTyped::<MsgpackSerdeCodec>::encode(&42).unwrap();
+/// assert!(encoded[1..].starts_with(b"application/msgpack"));
+///
+/// let decoded: i32 = ContentTyped::<MsgpackSerdeCodec>::decode(&encoded).unwrap();
+/// assert_eq!(decoded, 42);
+///
+/// let decoded: Result<i32, _> = ContentTyped::<BincodeSerdeCodec>::decode(&encoded);
+/// assert!(matches!(decoded, Err(ContentTypedDecodeError::Mismatch { .. })));
+/// ```
+pub struct ContentTyped<C>(C);
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContentTypedDecodeError<Err> {
+    #[error("unexpected end of input")]
+    UnexpectedEnd,
+    #[error("expected content type `{expected}` but found `{found}`")]
+    Mismatch {
+        expected: &'static str,
+        found: String,
+    },
+    #[error("failed to decode: {0}")]
+    Decoder(Err),
+}
+
+impl<T, E> Encoder<T> for ContentTyped<E>
+where
+    E: Encoder<T, Encoded = Vec<u8>> + ContentType,
+{
+    type Error = E::Error;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        let content_type = const {
+            assert!(E::CONTENT_TYPE.len() <= u8::MAX as usize);
+            E::CONTENT_TYPE.as_bytes()
+        };
+        let payload = E::encode(val)?;
+
+        let mut buf = Vec::with_capacity(1 + content_type.len() + payload.len());
+        buf.push(content_type.len() as u8);
+        buf.extend_from_slice(content_type);
+        buf.extend(payload);
+
+        Ok(buf)
+    }
+}
+
+impl<T, D> Decoder<T> for ContentTyped<D>
+where
+    D: Decoder<T, Encoded = [u8]> + ContentType,
+{
+    type Error = ContentTypedDecodeError<D::Error>;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        let (&len, rest) = val
+            .split_first()
+            .ok_or(ContentTypedDecodeError::UnexpectedEnd)?;
+        if rest.len() < len as usize {
+            return Err(ContentTypedDecodeError::UnexpectedEnd);
+        }
+        let (content_type, payload) = rest.split_at(len as usize);
+
+        if content_type != D::CONTENT_TYPE.as_bytes() {
+            return Err(ContentTypedDecodeError::Mismatch {
+                expected: D::CONTENT_TYPE,
+                found: String::from_utf8_lossy(content_type).into_owned(),
+            });
+        }
+
+        D::decode(payload).map_err(ContentTypedDecodeError::Decoder)
+    }
+}
+
+impl ContentType for crate::binary::FromToBytesCodec {
+    const CONTENT_TYPE: &'static str = "application/octet-stream";
+}
+
+#[cfg(feature = "bincode_serde")]
+impl ContentType for crate::binary::BincodeSerdeCodec {
+    const CONTENT_TYPE: &'static str = "application/x-bincode";
+}
+
+#[cfg(feature = "msgpack_serde")]
+impl ContentType for crate::binary::MsgpackSerdeCodec {
+    const CONTENT_TYPE: &'static str = "application/msgpack";
+}
+
+#[cfg(feature = "postcard_serde")]
+impl ContentType for crate::binary::PostcardSerdeCodec {
+    const CONTENT_TYPE: &'static str = "application/x-postcard";
+}
+
+#[cfg(feature = "prost")]
+impl ContentType for crate::binary::ProstCodec {
+    const CONTENT_TYPE: &'static str = "application/protobuf";
+}
+
+#[cfg(feature = "avro")]
+impl ContentType for crate::binary::AvroCodec {
+    const CONTENT_TYPE: &'static str = "application/avro";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary::FromToBytesCodec;
+
+    struct TextCodec;
+
+    impl ContentType for TextCodec {
+        const CONTENT_TYPE: &'static str = "text/plain";
+    }
+
+    impl Encoder<String> for TextCodec {
+        type Error = ();
+        type Encoded = Vec<u8>;
+
+        fn encode(val: &String) -> Result<Self::Encoded, Self::Error> {
+            Ok(val.as_bytes().to_vec())
+        }
+    }
+
+    impl Decoder<String> for TextCodec {
+        type Error = core::str::Utf8Error;
+        type Encoded = [u8];
+
+        fn decode(val: &Self::Encoded) -> Result<String, Self::Error> {
+            core::str::from_utf8(val).map(String::from)
+        }
+    }
+
+    #[test]
+    fn test_content_typed() {
+        let s = String::from("party time 🎉");
+
+        let enc = ContentTyped::<TextCodec>::encode(&s).unwrap();
+        assert_eq!(enc[0], 10);
+        assert_eq!(&enc[1..11], b"text/plain");
+        assert_eq!(&enc[11..], s.as_bytes());
+
+        assert_eq!(ContentTyped::<TextCodec>::decode(&enc), Ok(s));
+    }
+
+    #[test]
+    fn test_content_typed_mismatch() {
+        let enc = ContentTyped::<TextCodec>::encode(&String::from("hi")).unwrap();
+
+        let dec: Result<String, _> = ContentTyped::<FromToBytesCodec>::decode(&enc);
+        assert!(matches!(
+            dec,
+            Err(ContentTypedDecodeError::Mismatch {
+                expected: "application/octet-stream",
+                found,
+            }) if found == "text/plain"
+        ));
+
+        assert_eq!(
+            ContentTyped::<TextCodec>::decode(&enc[..5]),
+            Err(ContentTypedDecodeError::UnexpectedEnd)
+        );
+        assert_eq!(
+            ContentTyped::<TextCodec>::decode(&[]),
+            Err(ContentTypedDecodeError::UnexpectedEnd)
+        );
+    }
+}
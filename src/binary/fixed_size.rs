@@ -0,0 +1,161 @@
+use crate::{Decoder, Encoder};
+use alloc::vec::Vec;
+use thiserror::Error;
+
+/// Wraps a binary codec and makes its output always exactly `N` bytes long.
+///
+/// The output is laid out as `[u32 big-endian len][payload][zero padding]` where the length
+/// prefix counts towards `N`, so payloads can be at most `N - 4` bytes long. Encoding fails if
+/// the inner codec's output is longer than that. Decoding fails if the input isn't exactly `N`
+/// bytes long. This is useful for fixed-size storage slots like records in memory-mapped files.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::{FixedSizeCodec, FromToBytesCodec};
+/// #
+/// let encoded = FixedSizeCodec::<FromToBytesCodec, 16>::encode(&"hello".to_owned()).unwrap();
+/// assert_eq!(encoded.len(), 16);
+///
+/// let decoded: String = FixedSizeCodec::<FromToBytesCodec, 16>::decode(&encoded).unwrap();
+/// assert_eq!(decoded, "hello");
+/// ```
+pub struct FixedSizeCodec<C, const N: usize>(C);
+
+#[derive(Error, Debug, PartialEq)]
+pub enum FixedSizeEncodeError<Err> {
+    #[error("encoded value of {len} bytes is longer than the maximum of {max} bytes")]
+    TooLong { len: usize, max: usize },
+    #[error("failed to encode: {0}")]
+    Encoder(Err),
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum FixedSizeDecodeError<Err> {
+    #[error("expected {expected} bytes but found {found}")]
+    InvalidLength { expected: usize, found: usize },
+    #[error("stored payload length {0} exceeds the slot size")]
+    InvalidPayloadLength(usize),
+    #[error("failed to decode: {0}")]
+    Decoder(Err),
+}
+
+const HEADER_LEN: usize = 4;
+
+impl<T, E, const N: usize> Encoder<T> for FixedSizeCodec<E, N>
+where
+    E: Encoder<T, Encoded = Vec<u8>>,
+{
+    type Error = FixedSizeEncodeError<E::Error>;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        const {
+            assert!(N >= HEADER_LEN, "N has to be at least 4");
+        }
+
+        let payload = E::encode(val).map_err(FixedSizeEncodeError::Encoder)?;
+
+        let max = N - HEADER_LEN;
+        if payload.len() > max {
+            return Err(FixedSizeEncodeError::TooLong {
+                len: payload.len(),
+                max,
+            });
+        }
+
+        let mut buf = Vec::with_capacity(N);
+        buf.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+        buf.extend(payload);
+        buf.resize(N, 0);
+
+        Ok(buf)
+    }
+
+    fn size_hint(_val: &T) -> Option<usize> {
+        Some(N)
+    }
+}
+
+impl<T, D, const N: usize> Decoder<T> for FixedSizeCodec<D, N>
+where
+    D: Decoder<T, Encoded = [u8]>,
+{
+    type Error = FixedSizeDecodeError<D::Error>;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        if val.len() != N || N < HEADER_LEN {
+            return Err(FixedSizeDecodeError::InvalidLength {
+                expected: N,
+                found: val.len(),
+            });
+        }
+
+        let (len, rest) = val.split_at(HEADER_LEN);
+        let len = u32::from_be_bytes(len.try_into().unwrap()) as usize;
+
+        let payload = rest
+            .get(..len)
+            .ok_or(FixedSizeDecodeError::InvalidPayloadLength(len))?;
+
+        D::decode(payload).map_err(FixedSizeDecodeError::Decoder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary::FromToBytesCodec;
+
+    type Codec = FixedSizeCodec<FromToBytesCodec, 12>;
+
+    #[test]
+    fn test_fixed_size_codec_padding() {
+        let s = String::from("hello");
+
+        let enc = Codec::encode(&s).unwrap();
+        assert_eq!(enc, b"\0\0\0\x05hello\0\0\0");
+
+        let dec: String = Codec::decode(&enc).unwrap();
+        assert_eq!(dec, s);
+    }
+
+    #[test]
+    fn test_fixed_size_codec_exact() {
+        let s = String::from("hello!!!");
+
+        let enc = Codec::encode(&s).unwrap();
+        assert_eq!(enc, b"\0\0\0\x08hello!!!");
+
+        let dec: String = Codec::decode(&enc).unwrap();
+        assert_eq!(dec, s);
+    }
+
+    #[test]
+    fn test_fixed_size_codec_overflow() {
+        assert_eq!(
+            Codec::encode(&String::from("hello!!!!")),
+            Err(FixedSizeEncodeError::TooLong { len: 9, max: 8 })
+        );
+    }
+
+    #[test]
+    fn test_fixed_size_codec_invalid_input() {
+        let dec: Result<String, _> = Codec::decode(b"\0\0\0\x05hello\0\0");
+        assert!(matches!(
+            dec,
+            Err(FixedSizeDecodeError::InvalidLength {
+                expected: 12,
+                found: 11
+            })
+        ));
+
+        let dec: Result<String, _> = Codec::decode(b"\0\0\0\x09hello!!!");
+        assert!(matches!(
+            dec,
+            Err(FixedSizeDecodeError::InvalidPayloadLength(9))
+        ));
+    }
+}
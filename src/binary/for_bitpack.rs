@@ -0,0 +1,275 @@
+use crate::{Decoder, Encoder};
+use alloc::vec::Vec;
+use thiserror::Error;
+
+/// Binary codec for `Vec<u32>` and `Vec<u64>` that uses frame-of-reference encoding with
+/// bit-packing.
+///
+/// The smallest value is stored once and every value is stored as its difference to it, using
+/// only as many bits as the largest difference needs. This makes columns of integers that lie in
+/// a small range very compact, no matter how large the values themselves are.
+///
+/// The layout is `[u32 count][min][u8 bit width][packed residuals]` where the count and the min
+/// (4 bytes for `u32`, 8 bytes for `u64`) are in little-endian byte order and the residuals are
+/// packed least significant bit first.
+///
+/// At most [`ForBitpackCodec::MAX_LEN`] values can be encoded. Decoding rejects larger counts
+/// before allocating anything. Since equal values need zero bits, the count is otherwise not
+/// bounded by the length of the input. The cap keeps a crafted header of a few bytes from
+/// allocating more than 512 KiB.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::ForBitpackCodec;
+/// #
+/// let timestamps: Vec<u64> = vec![1_700_000_000, 1_700_000_003, 1_700_000_001, 1_700_000_007];
+///
+/// let encoded = ForBitpackCodec::encode(&timestamps).unwrap();
+/// // 4 + 8 + 1 bytes of header and 4 residuals of 3 bits
+/// assert_eq!(encoded.len(), 15);
+///
+/// let decoded: Vec<u64> = ForBitpackCodec::decode(&encoded[..]).unwrap();
+/// assert_eq!(decoded, timestamps);
+/// ```
+pub struct ForBitpackCodec;
+
+impl ForBitpackCodec {
+    /// The maximum number of values that are encoded or decoded.
+    pub const MAX_LEN: usize = 1 << 16;
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ForBitpackEncodeError {
+    #[error("{0} values are more than the maximum of {max}", max = ForBitpackCodec::MAX_LEN)]
+    TooLong(usize),
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ForBitpackDecodeError {
+    #[error("unexpected end of input")]
+    UnexpectedEnd,
+    #[error("{0} values are more than the maximum of {max}", max = ForBitpackCodec::MAX_LEN)]
+    TooLong(usize),
+    #[error("bit width {0} is larger than the integer type")]
+    InvalidBitWidth(u8),
+    #[error("{0} trailing bytes after the packed values")]
+    TrailingBytes(usize),
+    #[error("value overflows the integer type")]
+    Overflow,
+}
+
+/// Appends the lowest `width` bits of every value to `buf`, least significant bit first.
+fn pack(buf: &mut Vec<u8>, values: impl Iterator<Item = u64>, width: u32) {
+    let mut acc: u128 = 0;
+    let mut bits = 0;
+
+    for value in values {
+        acc |= (value as u128) << bits;
+        bits += width;
+
+        while bits >= 8 {
+            buf.push(acc as u8);
+            acc >>= 8;
+            bits -= 8;
+        }
+    }
+
+    if bits > 0 {
+        buf.push(acc as u8);
+    }
+}
+
+/// Reads `count` values of `width` bits from `val`, which has to be exactly the right length.
+fn unpack(
+    val: &[u8],
+    count: usize,
+    width: u32,
+) -> Result<impl Iterator<Item = u64> + '_, ForBitpackDecodeError> {
+    let len = (count as u64 * width as u64).div_ceil(8);
+
+    if (val.len() as u64) < len {
+        return Err(ForBitpackDecodeError::UnexpectedEnd);
+    }
+    if (val.len() as u64) > len {
+        return Err(ForBitpackDecodeError::TrailingBytes(
+            val.len() - len as usize,
+        ));
+    }
+
+    let mask = if width == 64 {
+        u64::MAX
+    } else {
+        (1 << width) - 1
+    };
+    let mut bytes = val.iter();
+    let mut acc: u128 = 0;
+    let mut bits = 0;
+
+    Ok((0..count).map(move |_| {
+        while bits < width {
+            // the length was checked above
+            acc |= (*bytes.next().unwrap() as u128) << bits;
+            bits += 8;
+        }
+
+        let value = acc as u64 & mask;
+        acc >>= width;
+        bits -= width;
+
+        value
+    }))
+}
+
+macro_rules! impl_for_bitpack_codec {
+    ($int:ty) => {
+        impl Encoder<Vec<$int>> for ForBitpackCodec {
+            type Error = ForBitpackEncodeError;
+            type Encoded = Vec<u8>;
+
+            fn encode(val: &Vec<$int>) -> Result<Self::Encoded, Self::Error> {
+                if val.len() > Self::MAX_LEN {
+                    return Err(ForBitpackEncodeError::TooLong(val.len()));
+                }
+                let count = val.len() as u32;
+
+                let min = val.iter().copied().min().unwrap_or(0);
+                let max = val.iter().copied().max().unwrap_or(0);
+                let width = <$int>::BITS - (max - min).leading_zeros();
+
+                let mut buf = Vec::with_capacity(
+                    4 + size_of::<$int>() + 1 + (val.len() * width as usize).div_ceil(8),
+                );
+                buf.extend_from_slice(&count.to_le_bytes());
+                buf.extend_from_slice(&min.to_le_bytes());
+                buf.push(width as u8);
+                pack(&mut buf, val.iter().map(|v| (v - min) as u64), width);
+
+                Ok(buf)
+            }
+        }
+
+        impl Decoder<Vec<$int>> for ForBitpackCodec {
+            type Error = ForBitpackDecodeError;
+            type Encoded = [u8];
+
+            fn decode(val: &Self::Encoded) -> Result<Vec<$int>, Self::Error> {
+                const MIN_END: usize = 4 + size_of::<$int>();
+
+                if val.len() < MIN_END + 1 {
+                    return Err(ForBitpackDecodeError::UnexpectedEnd);
+                }
+                let count = u32::from_le_bytes(val[..4].try_into().unwrap()) as usize;
+                let min = <$int>::from_le_bytes(val[4..MIN_END].try_into().unwrap());
+                let width = val[MIN_END];
+
+                if count > Self::MAX_LEN {
+                    return Err(ForBitpackDecodeError::TooLong(count));
+                }
+                if width as u32 > <$int>::BITS {
+                    return Err(ForBitpackDecodeError::InvalidBitWidth(width));
+                }
+
+                unpack(&val[MIN_END + 1..], count, width as u32)?
+                    .map(|residual| {
+                        // `residual` has at most `<$int>::BITS` bits
+                        min.checked_add(residual as $int)
+                            .ok_or(ForBitpackDecodeError::Overflow)
+                    })
+                    .collect()
+            }
+        }
+    };
+}
+
+impl_for_bitpack_codec!(u32);
+impl_for_bitpack_codec!(u64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Size of `values` as LEB128 varints
+    fn varint_len(values: &[u32]) -> usize {
+        values
+            .iter()
+            .map(|v| (u32::BITS - v.leading_zeros()).max(1).div_ceil(7) as usize)
+            .sum()
+    }
+
+    #[test]
+    fn test_for_bitpack_codec() {
+        let values: Vec<u32> = (0..1000).map(|i| 100_000 + (i * 37) % 61).collect();
+
+        let enc = ForBitpackCodec::encode(&values).unwrap();
+        // 61 distinct residuals need 6 bits
+        assert_eq!(enc[8], 6);
+        assert_eq!(enc.len(), 4 + 4 + 1 + 750);
+        // every value needs 3 bytes as a varint
+        assert_eq!(varint_len(&values), 3000);
+
+        let dec: Vec<u32> = ForBitpackCodec::decode(&enc[..]).unwrap();
+        assert_eq!(dec, values);
+    }
+
+    #[test]
+    fn test_for_bitpack_codec_edge_cases() {
+        for values in [
+            vec![],
+            vec![7_u64; 3],
+            vec![0, u64::MAX],
+            vec![u64::MAX, 1 << 63, 42],
+        ] {
+            let enc = ForBitpackCodec::encode(&values).unwrap();
+            let dec: Vec<u64> = ForBitpackCodec::decode(&enc[..]).unwrap();
+            assert_eq!(dec, values);
+        }
+
+        // equal values need no bits at all
+        let enc = ForBitpackCodec::encode(&vec![7_u32; 100]).unwrap();
+        assert_eq!(enc.len(), 9);
+    }
+
+    #[test]
+    fn test_for_bitpack_codec_invalid() {
+        let enc = ForBitpackCodec::encode(&vec![1_u32, 2, 3]).unwrap();
+
+        let dec: Result<Vec<u32>, _> = ForBitpackCodec::decode(&enc[..enc.len() - 1]);
+        assert_eq!(dec, Err(ForBitpackDecodeError::UnexpectedEnd));
+
+        let mut trailing = enc.clone();
+        trailing.push(0);
+        let dec: Result<Vec<u32>, _> = ForBitpackCodec::decode(&trailing[..]);
+        assert_eq!(dec, Err(ForBitpackDecodeError::TrailingBytes(1)));
+
+        let mut wide = enc.clone();
+        wide[8] = 33;
+        let dec: Result<Vec<u32>, _> = ForBitpackCodec::decode(&wide[..]);
+        assert_eq!(dec, Err(ForBitpackDecodeError::InvalidBitWidth(33)));
+
+        // a crafted header with zero-width values needs no packed bytes at all
+        let mut huge = vec![0; 9];
+        huge[..4].copy_from_slice(&u32::MAX.to_le_bytes());
+        let dec: Result<Vec<u32>, _> = ForBitpackCodec::decode(&huge[..]);
+        assert_eq!(dec, Err(ForBitpackDecodeError::TooLong(u32::MAX as usize)));
+
+        let mut max = huge;
+        max[..4].copy_from_slice(&(ForBitpackCodec::MAX_LEN as u32).to_le_bytes());
+        let dec: Vec<u32> = ForBitpackCodec::decode(&max[..]).unwrap();
+        assert_eq!(dec.len(), ForBitpackCodec::MAX_LEN);
+
+        max[..4].copy_from_slice(&(ForBitpackCodec::MAX_LEN as u32 + 1).to_le_bytes());
+        let dec: Result<Vec<u32>, _> = ForBitpackCodec::decode(&max[..]);
+        assert_eq!(
+            dec,
+            Err(ForBitpackDecodeError::TooLong(ForBitpackCodec::MAX_LEN + 1))
+        );
+
+        // min of u32::MAX plus a non-zero residual
+        let mut overflow = enc;
+        overflow[4..8].copy_from_slice(&u32::MAX.to_le_bytes());
+        let dec: Result<Vec<u32>, _> = ForBitpackCodec::decode(&overflow[..]);
+        assert_eq!(dec, Err(ForBitpackDecodeError::Overflow));
+    }
+}
@@ -1,19 +1,40 @@
+#[cfg(feature = "smallvec")]
+use crate::SmallVecEncoder;
 use crate::{Decoder, Encoder};
+use alloc::string::String;
+use alloc::vec::Vec;
 use thiserror::Error;
 
 /// A binary codec that uses rust own binary encoding functions to encode and decode data.
 /// This can be used if you want to encode only primitives and don't want to rely on third party
 /// crates like `bincode` or `rmp-serde`. If you have more complex data check out
 /// [`BincodeSerdeCodec`] or [`MsgpackSerdeCodec`].
+///
+/// Numbers are stored in big-endian byte order. If you need to be explicit about the byte order
+/// use [`FromToBytesBeCodec`](crate::binary::FromToBytesBeCodec) or
+/// [`FromToBytesLeCodec`](crate::binary::FromToBytesLeCodec).
 pub struct FromToBytesCodec;
 
 #[derive(Error, Debug)]
 pub enum FromToBytesCodecError {
     #[error("failed to convert byte slice to byte array")]
-    InvalidByteSlice(#[from] std::array::TryFromSliceError),
+    InvalidByteSlice(#[from] core::array::TryFromSliceError),
 
-    #[error("failed to convert byte array to string")]
-    InvalidString(#[from] std::string::FromUtf8Error),
+    #[error(
+        "failed to convert byte array to string: invalid UTF-8 at byte {}",
+        .0.utf8_error().valid_up_to()
+    )]
+    InvalidString(#[from] alloc::string::FromUtf8Error),
+}
+
+impl FromToBytesCodecError {
+    /// Returns the byte offset of the first invalid UTF-8 sequence if decoding a `String` failed.
+    pub fn invalid_utf8_offset(&self) -> Option<usize> {
+        match self {
+            Self::InvalidString(err) => Some(err.utf8_error().valid_up_to()),
+            Self::InvalidByteSlice(_) => None,
+        }
+    }
 }
 
 macro_rules! impl_bin_codec_for_number {
@@ -25,6 +46,19 @@ macro_rules! impl_bin_codec_for_number {
             fn encode(val: &$num) -> Result<Self::Encoded, Self::Error> {
                 Ok(val.to_be_bytes().to_vec())
             }
+
+            fn size_hint(_val: &$num) -> Option<usize> {
+                Some(core::mem::size_of::<$num>())
+            }
+        }
+
+        #[cfg(feature = "smallvec")]
+        impl SmallVecEncoder<$num> for FromToBytesCodec {
+            fn encode_smallvec<const N: usize>(
+                val: &$num,
+            ) -> Result<smallvec::SmallVec<[u8; N]>, Self::Error> {
+                Ok(smallvec::SmallVec::from_slice(&val.to_be_bytes()))
+            }
         }
 
         impl Decoder<$num> for FromToBytesCodec {
@@ -67,6 +101,19 @@ impl Encoder<bool> for FromToBytesCodec {
         let num: u8 = if *val { 1 } else { 0 };
         Self::encode(&num)
     }
+
+    fn size_hint(_val: &bool) -> Option<usize> {
+        Some(1)
+    }
+}
+
+#[cfg(feature = "smallvec")]
+impl SmallVecEncoder<bool> for FromToBytesCodec {
+    fn encode_smallvec<const N: usize>(
+        val: &bool,
+    ) -> Result<smallvec::SmallVec<[u8; N]>, Self::Error> {
+        Self::encode_smallvec(&u8::from(*val))
+    }
 }
 
 impl Decoder<bool> for FromToBytesCodec {
@@ -86,6 +133,19 @@ impl Encoder<String> for FromToBytesCodec {
     fn encode(val: &String) -> Result<Self::Encoded, Self::Error> {
         Ok(val.as_bytes().to_vec())
     }
+
+    fn size_hint(val: &String) -> Option<usize> {
+        Some(val.len())
+    }
+}
+
+#[cfg(feature = "smallvec")]
+impl SmallVecEncoder<String> for FromToBytesCodec {
+    fn encode_smallvec<const N: usize>(
+        val: &String,
+    ) -> Result<smallvec::SmallVec<[u8; N]>, Self::Error> {
+        Ok(smallvec::SmallVec::from_slice(val.as_bytes()))
+    }
 }
 
 impl Decoder<String> for FromToBytesCodec {
@@ -109,4 +169,56 @@ mod tests {
         let dec: i32 = FromToBytesCodec::decode(enc.as_slice()).unwrap();
         assert_eq!(dec, t);
     }
+
+    #[test]
+    fn test_fromtobytes_codec_size_hint() {
+        macro_rules! assert_exact_size_hint {
+            ($($num:ty),*) => {
+                $(
+                    let val = <$num>::default();
+                    let enc: Vec<u8> = FromToBytesCodec::encode(&val).unwrap();
+                    assert_eq!(FromToBytesCodec::size_hint(&val), Some(enc.len()));
+                    assert_eq!(enc.len(), core::mem::size_of::<$num>());
+                )*
+            };
+        }
+
+        assert_exact_size_hint!(
+            i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize, f32, f64, bool
+        );
+
+        let s = String::from("party time 🎉");
+        assert_eq!(FromToBytesCodec::size_hint(&s), Some(s.len()));
+    }
+
+    #[test]
+    fn test_fromtobytes_codec_invalid_utf8_offset() {
+        // "party" followed by a lone continuation byte
+        let buf = [b'p', b'a', b'r', b't', b'y', 0x80, b'!'];
+
+        let err = <FromToBytesCodec as Decoder<String>>::decode(&buf).unwrap_err();
+        assert_eq!(err.invalid_utf8_offset(), Some(5));
+        assert_eq!(
+            err.to_string(),
+            "failed to convert byte array to string: invalid UTF-8 at byte 5"
+        );
+
+        let err = <FromToBytesCodec as Decoder<i32>>::decode(&buf).unwrap_err();
+        assert_eq!(err.invalid_utf8_offset(), None);
+    }
+
+    #[cfg(feature = "smallvec")]
+    #[test]
+    fn test_fromtobytes_codec_encode_smallvec() {
+        let enc = FromToBytesCodec::encode_smallvec::<8>(&42_u32).unwrap();
+        assert!(!enc.spilled());
+        assert_eq!(enc.as_slice(), FromToBytesCodec::encode(&42_u32).unwrap());
+
+        let s = String::from("party time 🎉");
+        let enc = FromToBytesCodec::encode_smallvec::<8>(&s).unwrap();
+        assert!(enc.spilled());
+
+        let dec: String = FromToBytesCodec::decode(enc.as_slice()).unwrap();
+        assert_eq!(dec, s);
+    }
 }
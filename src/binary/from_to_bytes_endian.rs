@@ -0,0 +1,169 @@
+use crate::{Decoder, Encoder};
+use alloc::vec::Vec;
+use core::convert::Infallible;
+use thiserror::Error;
+
+/// Binary codec for primitive integers and floats that stores them in big-endian (network) byte
+/// order. Decoding fails if the input doesn't have exactly the size of the type.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::FromToBytesBeCodec;
+/// #
+/// let encoded = FromToBytesBeCodec::encode(&0x1234_u16).unwrap();
+/// assert_eq!(encoded, vec![0x12, 0x34]);
+///
+/// let decoded: u16 = FromToBytesBeCodec::decode(encoded.as_slice()).unwrap();
+/// assert_eq!(decoded, 0x1234);
+/// ```
+pub struct FromToBytesBeCodec;
+
+/// Binary codec for primitive integers and floats that stores them in little-endian byte order.
+/// Decoding fails if the input doesn't have exactly the size of the type.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::FromToBytesLeCodec;
+/// #
+/// let encoded = FromToBytesLeCodec::encode(&0x1234_u16).unwrap();
+/// assert_eq!(encoded, vec![0x34, 0x12]);
+///
+/// let decoded: u16 = FromToBytesLeCodec::decode(encoded.as_slice()).unwrap();
+/// assert_eq!(decoded, 0x1234);
+/// ```
+pub struct FromToBytesLeCodec;
+
+#[derive(Error, Debug, PartialEq)]
+#[error("expected {expected} bytes but got {found}")]
+pub struct InvalidByteLengthError {
+    pub expected: usize,
+    pub found: usize,
+}
+
+macro_rules! impl_endian_codec_for_number {
+    ($codec:ty, $to_bytes:ident, $from_bytes:ident, $($num:ty),*) => {
+        $(
+            impl Encoder<$num> for $codec {
+                type Error = Infallible;
+                type Encoded = Vec<u8>;
+
+                fn encode(val: &$num) -> Result<Self::Encoded, Self::Error> {
+                    Ok(val.$to_bytes().to_vec())
+                }
+
+                fn size_hint(_val: &$num) -> Option<usize> {
+                    Some(core::mem::size_of::<$num>())
+                }
+            }
+
+            impl Decoder<$num> for $codec {
+                type Error = InvalidByteLengthError;
+                type Encoded = [u8];
+
+                fn decode(val: &Self::Encoded) -> Result<$num, Self::Error> {
+                    let bytes = val.try_into().map_err(|_| InvalidByteLengthError {
+                        expected: core::mem::size_of::<$num>(),
+                        found: val.len(),
+                    })?;
+
+                    Ok(<$num>::$from_bytes(bytes))
+                }
+            }
+        )*
+    };
+}
+
+impl_endian_codec_for_number!(
+    FromToBytesBeCodec,
+    to_be_bytes,
+    from_be_bytes,
+    i8,
+    u8,
+    i16,
+    u16,
+    i32,
+    u32,
+    i64,
+    u64,
+    i128,
+    u128,
+    isize,
+    usize,
+    f32,
+    f64
+);
+
+impl_endian_codec_for_number!(
+    FromToBytesLeCodec,
+    to_le_bytes,
+    from_le_bytes,
+    i8,
+    u8,
+    i16,
+    u16,
+    i32,
+    u32,
+    i64,
+    u64,
+    i128,
+    u128,
+    isize,
+    usize,
+    f32,
+    f64
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_to_bytes_be_codec() {
+        let enc = FromToBytesBeCodec::encode(&0x0102_0304_i32).unwrap();
+        assert_eq!(enc, vec![1, 2, 3, 4]);
+        assert_eq!(
+            FromToBytesBeCodec::decode(enc.as_slice()),
+            Ok(0x0102_0304_i32)
+        );
+
+        let enc = FromToBytesBeCodec::encode(&1.5_f64).unwrap();
+        assert_eq!(enc, 1.5_f64.to_be_bytes());
+        assert_eq!(FromToBytesBeCodec::decode(enc.as_slice()), Ok(1.5_f64));
+    }
+
+    #[test]
+    fn test_from_to_bytes_le_codec() {
+        let enc = FromToBytesLeCodec::encode(&0x0102_0304_i32).unwrap();
+        assert_eq!(enc, vec![4, 3, 2, 1]);
+        assert_eq!(
+            FromToBytesLeCodec::decode(enc.as_slice()),
+            Ok(0x0102_0304_i32)
+        );
+
+        let enc = FromToBytesLeCodec::encode(&-1.5_f32).unwrap();
+        assert_eq!(enc, (-1.5_f32).to_le_bytes());
+        assert_eq!(FromToBytesLeCodec::decode(enc.as_slice()), Ok(-1.5_f32));
+    }
+
+    #[test]
+    fn test_from_to_bytes_endian_codec_wrong_length() {
+        assert_eq!(
+            <FromToBytesBeCodec as Decoder<u32>>::decode(&[1, 2, 3]),
+            Err(InvalidByteLengthError {
+                expected: 4,
+                found: 3
+            })
+        );
+        assert_eq!(
+            <FromToBytesLeCodec as Decoder<u16>>::decode(&[1, 2, 3]),
+            Err(InvalidByteLengthError {
+                expected: 2,
+                found: 3
+            })
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use super::length_prefixed::split_frame;
+use crate::binary::LengthPrefixedEncodeError;
+use crate::{Decoder, Encoder};
+use alloc::vec::Vec;
+use thiserror::Error;
+
+/// Wraps a binary codec, usually [`ProstCodec`](crate::binary::ProstCodec), and adds the gRPC
+/// message frame `[u8 compressed flag][u32 big-endian len][payload]`.
+///
+/// Encoded messages are never compressed. Decoding a message with the compressed flag set fails
+/// with [`GrpcFramedDecodeError::Compressed`] because no decompression is supported. Use
+/// [`GrpcFramedCodec::decode_frame`] to decode several messages that follow each other.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::{FromToBytesCodec, GrpcFramedCodec};
+/// #
+/// let encoded = GrpcFramedCodec::<FromToBytesCodec>::encode(&42_u16).unwrap();
+/// assert_eq!(encoded, vec![0, 0, 0, 0, 2, 0, 42]);
+///
+/// let decoded: u16 = GrpcFramedCodec::<FromToBytesCodec>::decode(&encoded).unwrap();
+/// assert_eq!(decoded, 42);
+/// ```
+pub struct GrpcFramedCodec<C>(C);
+
+#[derive(Error, Debug, PartialEq)]
+pub enum GrpcFramedDecodeError<Err> {
+    #[error("unexpected end of input")]
+    UnexpectedEnd,
+    #[error("message is compressed but no decompressor is configured")]
+    Compressed,
+    #[error("invalid compressed flag {0}")]
+    InvalidFlag(u8),
+    #[error("{0} trailing bytes after the frame")]
+    TrailingBytes(usize),
+    #[error("failed to decode: {0}")]
+    Decoder(Err),
+}
+
+impl<C> GrpcFramedCodec<C> {
+    /// Decodes the frame at the front of `val` and returns the decoded message together with the
+    /// number of bytes consumed (including the 5 byte prefix). Anything after the frame is ignored.
+    pub fn decode_frame<T>(val: &[u8]) -> Result<(T, usize), GrpcFramedDecodeError<C::Error>>
+    where
+        C: Decoder<T, Encoded = [u8]>,
+    {
+        let (&flag, rest) = val
+            .split_first()
+            .ok_or(GrpcFramedDecodeError::UnexpectedEnd)?;
+
+        match flag {
+            0 => {}
+            1 => return Err(GrpcFramedDecodeError::Compressed),
+            _ => return Err(GrpcFramedDecodeError::InvalidFlag(flag)),
+        }
+
+        let (payload, _) = split_frame(rest).ok_or(GrpcFramedDecodeError::UnexpectedEnd)?;
+        let message = C::decode(payload).map_err(GrpcFramedDecodeError::Decoder)?;
+
+        Ok((message, 5 + payload.len()))
+    }
+}
+
+impl<T, E> Encoder<T> for GrpcFramedCodec<E>
+where
+    E: Encoder<T, Encoded = Vec<u8>>,
+{
+    type Error = LengthPrefixedEncodeError<E::Error>;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        let payload = E::encode(val).map_err(LengthPrefixedEncodeError::Encoder)?;
+        let len = u32::try_from(payload.len())
+            .map_err(|_| LengthPrefixedEncodeError::TooLong(payload.len()))?;
+
+        let mut buf = Vec::with_capacity(5 + payload.len());
+        buf.push(0);
+        buf.extend_from_slice(&len.to_be_bytes());
+        buf.extend(payload);
+
+        Ok(buf)
+    }
+}
+
+impl<T, D> Decoder<T> for GrpcFramedCodec<D>
+where
+    D: Decoder<T, Encoded = [u8]>,
+{
+    type Error = GrpcFramedDecodeError<D::Error>;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        let (message, consumed) = Self::decode_frame(val)?;
+
+        if consumed < val.len() {
+            return Err(GrpcFramedDecodeError::TrailingBytes(val.len() - consumed));
+        }
+
+        Ok(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary::FromToBytesCodec;
+
+    type Codec = GrpcFramedCodec<FromToBytesCodec>;
+
+    #[test]
+    fn test_grpc_framed_codec() {
+        let s = String::from("party time 🎉");
+
+        let mut buf = Codec::encode(&s).unwrap();
+        assert_eq!(buf[..5], [0, 0, 0, 0, s.len() as u8]);
+        let dec: String = Codec::decode(&buf).unwrap();
+        assert_eq!(dec, s);
+
+        buf.extend(Codec::encode(&7_u8).unwrap());
+        let (first, consumed) = Codec::decode_frame::<String>(&buf).unwrap();
+        assert_eq!(first, s);
+        let (second, _) = Codec::decode_frame::<u8>(&buf[consumed..]).unwrap();
+        assert_eq!(second, 7);
+
+        let dec: Result<String, _> = Codec::decode(&buf);
+        assert!(matches!(dec, Err(GrpcFramedDecodeError::TrailingBytes(6))));
+    }
+
+    #[test]
+    fn test_grpc_framed_codec_compressed() {
+        let frame = [1, 0, 0, 0, 1, 42];
+
+        let dec: Result<u8, _> = Codec::decode(&frame);
+        assert!(matches!(dec, Err(GrpcFramedDecodeError::Compressed)));
+
+        let dec: Result<u8, _> = Codec::decode(&[2, 0, 0, 0, 1, 42]);
+        assert!(matches!(dec, Err(GrpcFramedDecodeError::InvalidFlag(2))));
+
+        let dec: Result<u8, _> = Codec::decode(&frame[..0]);
+        assert!(matches!(dec, Err(GrpcFramedDecodeError::UnexpectedEnd)));
+    }
+
+    #[cfg(feature = "prost")]
+    #[test]
+    fn test_grpc_framed_prost_codec() {
+        use crate::binary::ProstCodec;
+
+        #[derive(Clone, PartialEq, prost::Message)]
+        struct Test {
+            #[prost(string, tag = "1")]
+            s: String,
+            #[prost(int32, tag = "2")]
+            i: i32,
+        }
+        let t = Test {
+            s: String::from("party time 🎉"),
+            i: 42,
+        };
+
+        let enc = GrpcFramedCodec::<ProstCodec>::encode(&t).unwrap();
+        assert_eq!(GrpcFramedCodec::<ProstCodec>::decode(&enc), Ok(t));
+    }
+}
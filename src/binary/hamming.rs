@@ -0,0 +1,193 @@
+use crate::{Decoder, Encoder};
+use alloc::vec::Vec;
+use thiserror::Error;
+
+/// Wraps a binary codec and protects its output with an extended Hamming(8,4) code (Hamming(7,4)
+/// plus an overall parity bit).
+///
+/// Every nibble of the inner output is stored as its own byte. On decode a single flipped bit per
+/// byte is corrected and two flipped bits per byte are detected and reported as
+/// [`HammingDecodeError::Uncorrectable`]. Use this for storage on unreliable media where silently
+/// corrupted data is worse than the overhead: the encoded data is **twice as large** as the
+/// output of the inner codec.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::{FromToBytesCodec, Hamming};
+/// #
+/// let mut encoded = Hamming::<FromToBytesCodec>::encode(&42_u32).unwrap();
+/// assert_eq!(encoded.len(), 8);
+///
+/// // a bit flips on the disk
+/// encoded[7] ^= 0b0001_0000;
+///
+/// let decoded: u32 = Hamming::<FromToBytesCodec>::decode(&encoded).unwrap();
+/// assert_eq!(decoded, 42);
+/// ```
+pub struct Hamming<C>(C);
+
+#[derive(Error, Debug, PartialEq)]
+pub enum HammingDecodeError<Err> {
+    #[error("odd number of bytes ({0}) for Hamming encoded data")]
+    OddLength(usize),
+    #[error("more than one bit flipped in byte {0}")]
+    Uncorrectable(usize),
+    #[error("failed to decode: {0}")]
+    Decoder(Err),
+}
+
+/// Codewords of all nibbles. Bit `i` holds position `i` of the Hamming code where position 0 is
+/// the overall parity, positions 1, 2 and 4 are parity bits and positions 3, 5, 6 and 7 hold the
+/// data bits from least to most significant.
+const CODEWORDS: [u8; 16] = {
+    const fn bit(nibble: u8, i: u8) -> u8 {
+        (nibble >> i) & 1
+    }
+
+    let mut codewords = [0; 16];
+
+    let mut nibble: u8 = 0;
+    while nibble < 16 {
+        let (d0, d1, d2, d3) = (
+            bit(nibble, 0),
+            bit(nibble, 1),
+            bit(nibble, 2),
+            bit(nibble, 3),
+        );
+        let p1 = d0 ^ d1 ^ d3;
+        let p2 = d0 ^ d2 ^ d3;
+        let p4 = d1 ^ d2 ^ d3;
+
+        let codeword: u8 = p1 << 1 | p2 << 2 | d0 << 3 | p4 << 4 | d1 << 5 | d2 << 6 | d3 << 7;
+        codewords[nibble as usize] = codeword | (codeword.count_ones() as u8 & 1);
+
+        nibble += 1;
+    }
+
+    codewords
+};
+
+/// Corrects up to one flipped bit and returns the nibble, or `None` if two bits are flipped.
+fn decode_codeword(codeword: u8) -> Option<u8> {
+    // xor of the positions of all set bits is the position of a single flipped bit
+    let syndrome = (1..8)
+        .filter(|i| (codeword >> i) & 1 == 1)
+        .fold(0, |acc, i| acc ^ i);
+    let parity_error = codeword.count_ones() % 2 == 1;
+
+    let codeword = match (syndrome, parity_error) {
+        (0, false) => codeword,
+        (_, true) => codeword ^ (1 << syndrome),
+        (_, false) => return None,
+    };
+
+    Some((codeword >> 3) & 1 | (codeword >> 4) & 0b1110)
+}
+
+impl<T, E> Encoder<T> for Hamming<E>
+where
+    E: Encoder<T, Encoded = Vec<u8>>,
+{
+    type Error = E::Error;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        Ok(E::encode(val)?
+            .into_iter()
+            .flat_map(|byte| {
+                [
+                    CODEWORDS[(byte >> 4) as usize],
+                    CODEWORDS[(byte & 0x0f) as usize],
+                ]
+            })
+            .collect())
+    }
+
+    fn size_hint(val: &T) -> Option<usize> {
+        E::size_hint(val).map(|len| len * 2)
+    }
+}
+
+impl<T, D> Decoder<T> for Hamming<D>
+where
+    D: Decoder<T, Encoded = [u8]>,
+{
+    type Error = HammingDecodeError<D::Error>;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        if val.len() % 2 != 0 {
+            return Err(HammingDecodeError::OddLength(val.len()));
+        }
+
+        let nibble = |i: usize| decode_codeword(val[i]).ok_or(HammingDecodeError::Uncorrectable(i));
+
+        let buf = (0..val.len())
+            .step_by(2)
+            .map(|i| Ok(nibble(i)? << 4 | nibble(i + 1)?))
+            .collect::<Result<Vec<u8>, _>>()?;
+
+        D::decode(&buf).map_err(HammingDecodeError::Decoder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary::FromToBytesCodec;
+
+    type Codec = Hamming<FromToBytesCodec>;
+
+    #[test]
+    fn test_hamming_codec() {
+        let s = String::from("party time 🎉");
+
+        let enc = Codec::encode(&s).unwrap();
+        assert_eq!(enc.len(), s.len() * 2);
+        assert_eq!(Codec::size_hint(&s), Some(enc.len()));
+
+        let dec: String = Codec::decode(&enc).unwrap();
+        assert_eq!(dec, s);
+    }
+
+    #[test]
+    fn test_hamming_codec_single_bit_flip() {
+        let s = String::from("party time 🎉");
+        let enc = Codec::encode(&s).unwrap();
+
+        for i in 0..enc.len() {
+            for bit in 0..8 {
+                let mut flipped = enc.clone();
+                flipped[i] ^= 1 << bit;
+
+                let dec: String = Codec::decode(&flipped).unwrap();
+                assert_eq!(dec, s);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hamming_codec_double_bit_flip() {
+        let enc = Codec::encode(&0x1234_5678_u32).unwrap();
+
+        for i in 0..enc.len() {
+            for a in 0..8 {
+                for b in a + 1..8 {
+                    let mut flipped = enc.clone();
+                    flipped[i] ^= 1 << a | 1 << b;
+
+                    let dec: Result<u32, _> = Codec::decode(&flipped);
+                    assert!(matches!(dec, Err(HammingDecodeError::Uncorrectable(j)) if j == i));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_hamming_codec_odd_length() {
+        let dec: Result<u32, _> = Codec::decode(&[0; 7]);
+        assert!(matches!(dec, Err(HammingDecodeError::OddLength(7))));
+    }
+}
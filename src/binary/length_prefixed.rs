@@ -0,0 +1,146 @@
+use crate::{Decoder, Encoder};
+use alloc::vec::Vec;
+use thiserror::Error;
+
+/// Wraps a binary codec and prepends the length of its output as a `u32` in big-endian byte order.
+///
+/// This makes it possible to put several encoded values into one buffer one after the other.
+/// Use [`LengthPrefixed::decode_frame`] to decode the frame at the front of a buffer. It returns
+/// the number of bytes consumed as well so you can continue with the next frame.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::{LengthPrefixed, FromToBytesCodec};
+/// #
+/// let mut buf = LengthPrefixed::<FromToBytesCodec>::encode(&42_u32).unwrap();
+/// buf.extend(LengthPrefixed::<FromToBytesCodec>::encode(&"hello".to_owned()).unwrap());
+///
+/// let (first, consumed) = LengthPrefixed::<FromToBytesCodec>::decode_frame::<u32>(&buf).unwrap();
+/// let (second, _) = LengthPrefixed::<FromToBytesCodec>::decode_frame::<String>(&buf[consumed..]).unwrap();
+///
+/// assert_eq!(first, 42);
+/// assert_eq!(second, "hello");
+/// ```
+pub struct LengthPrefixed<C>(C);
+
+#[derive(Error, Debug, PartialEq)]
+pub enum LengthPrefixedEncodeError<Err> {
+    #[error("encoded value of {0} bytes is too long for a u32 length prefix")]
+    TooLong(usize),
+    #[error("failed to encode: {0}")]
+    Encoder(Err),
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum LengthPrefixedDecodeError<Err> {
+    #[error("unexpected end of input")]
+    UnexpectedEnd,
+    #[error("{0} trailing bytes after the frame")]
+    TrailingBytes(usize),
+    #[error("failed to decode: {0}")]
+    Decoder(Err),
+}
+
+/// Splits a `[u32 big-endian len][payload]` frame from the front of `val`.
+/// Returns the payload and the rest. Returns `None` if `val` is too short.
+pub(crate) fn split_frame(val: &[u8]) -> Option<(&[u8], &[u8])> {
+    if val.len() < 4 {
+        return None;
+    }
+    let (len, rest) = val.split_at(4);
+    let len = u32::from_be_bytes(len.try_into().ok()?) as usize;
+
+    if rest.len() < len {
+        return None;
+    }
+    Some(rest.split_at(len))
+}
+
+impl<C> LengthPrefixed<C> {
+    /// Decodes the frame at the front of `val` and returns the decoded value together with the
+    /// number of bytes consumed (including the length prefix). Anything after the frame is ignored.
+    pub fn decode_frame<T>(val: &[u8]) -> Result<(T, usize), LengthPrefixedDecodeError<C::Error>>
+    where
+        C: Decoder<T, Encoded = [u8]>,
+    {
+        let (payload, _) = split_frame(val).ok_or(LengthPrefixedDecodeError::UnexpectedEnd)?;
+        let value = C::decode(payload).map_err(LengthPrefixedDecodeError::Decoder)?;
+
+        Ok((value, 4 + payload.len()))
+    }
+}
+
+impl<T, E> Encoder<T> for LengthPrefixed<E>
+where
+    E: Encoder<T, Encoded = Vec<u8>>,
+{
+    type Error = LengthPrefixedEncodeError<E::Error>;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        let payload = E::encode(val).map_err(LengthPrefixedEncodeError::Encoder)?;
+        let len = u32::try_from(payload.len())
+            .map_err(|_| LengthPrefixedEncodeError::TooLong(payload.len()))?;
+
+        let mut buf = Vec::with_capacity(4 + payload.len());
+        buf.extend_from_slice(&len.to_be_bytes());
+        buf.extend(payload);
+
+        Ok(buf)
+    }
+}
+
+impl<T, D> Decoder<T> for LengthPrefixed<D>
+where
+    D: Decoder<T, Encoded = [u8]>,
+{
+    type Error = LengthPrefixedDecodeError<D::Error>;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        let (value, consumed) = Self::decode_frame(val)?;
+
+        if consumed < val.len() {
+            return Err(LengthPrefixedDecodeError::TrailingBytes(
+                val.len() - consumed,
+            ));
+        }
+
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary::FromToBytesCodec;
+
+    #[test]
+    fn test_length_prefixed_codec() {
+        type Codec = LengthPrefixed<FromToBytesCodec>;
+
+        let s = String::from("party time 🎉");
+
+        let mut buf = Codec::encode(&s).unwrap();
+        buf.extend(Codec::encode(&42_i64).unwrap());
+
+        let (first, consumed) = Codec::decode_frame::<String>(&buf).unwrap();
+        assert_eq!(first, s);
+        assert_eq!(consumed, 4 + s.len());
+
+        let (second, consumed) = Codec::decode_frame::<i64>(&buf[consumed..]).unwrap();
+        assert_eq!(second, 42);
+        assert_eq!(consumed, 4 + 8);
+
+        let dec: Result<String, _> = Codec::decode(&buf);
+        assert!(matches!(
+            dec,
+            Err(LengthPrefixedDecodeError::TrailingBytes(12))
+        ));
+
+        let dec: Result<String, _> = Codec::decode(&buf[..5]);
+        assert!(matches!(dec, Err(LengthPrefixedDecodeError::UnexpectedEnd)));
+    }
+}
@@ -0,0 +1,123 @@
+use super::length_prefixed::split_frame;
+use crate::{Decoder, Encoder};
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::str::Utf8Error;
+use thiserror::Error;
+
+/// A binary codec for a list of strings where each string is stored as
+/// `[u32 big-endian len][utf8 bytes]` one after the other.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::LengthPrefixedStringsCodec;
+/// #
+/// let strings = vec!["hi".to_owned(), "there".to_owned()];
+///
+/// let encoded = LengthPrefixedStringsCodec::encode(&strings).unwrap();
+/// assert_eq!(&encoded[..6], &[0, 0, 0, 2, b'h', b'i']);
+///
+/// let decoded: Vec<String> = LengthPrefixedStringsCodec::decode(&encoded).unwrap();
+/// assert_eq!(decoded, strings);
+/// ```
+pub struct LengthPrefixedStringsCodec;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum LengthPrefixedStringsEncodeError {
+    #[error("string of {0} bytes is too long for a u32 length prefix")]
+    TooLong(usize),
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum LengthPrefixedStringsDecodeError {
+    #[error("unexpected end of input")]
+    UnexpectedEnd,
+    #[error("invalid utf8: {0}")]
+    InvalidUtf8(#[from] Utf8Error),
+}
+
+impl Encoder<Vec<String>> for LengthPrefixedStringsCodec {
+    type Error = LengthPrefixedStringsEncodeError;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &Vec<String>) -> Result<Self::Encoded, Self::Error> {
+        let mut buf = Vec::with_capacity(val.iter().map(|s| 4 + s.len()).sum());
+
+        for s in val {
+            let len = u32::try_from(s.len())
+                .map_err(|_| LengthPrefixedStringsEncodeError::TooLong(s.len()))?;
+
+            buf.extend_from_slice(&len.to_be_bytes());
+            buf.extend_from_slice(s.as_bytes());
+        }
+
+        Ok(buf)
+    }
+}
+
+impl Decoder<Vec<String>> for LengthPrefixedStringsCodec {
+    type Error = LengthPrefixedStringsDecodeError;
+    type Encoded = [u8];
+
+    fn decode(mut val: &Self::Encoded) -> Result<Vec<String>, Self::Error> {
+        let mut strings = Vec::new();
+
+        while !val.is_empty() {
+            let (bytes, rest) =
+                split_frame(val).ok_or(LengthPrefixedStringsDecodeError::UnexpectedEnd)?;
+            strings.push(core::str::from_utf8(bytes)?.to_owned());
+            val = rest;
+        }
+
+        Ok(strings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_length_prefixed_strings_codec() {
+        let strings = vec![
+            String::from("party time 🎉"),
+            String::new(),
+            String::from("hi"),
+        ];
+
+        let enc = LengthPrefixedStringsCodec::encode(&strings).unwrap();
+        assert_eq!(
+            enc.len(),
+            3 * 4 + strings.iter().map(String::len).sum::<usize>()
+        );
+        assert_eq!(LengthPrefixedStringsCodec::decode(&enc), Ok(strings));
+
+        assert_eq!(LengthPrefixedStringsCodec::decode(&[]), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_length_prefixed_strings_codec_invalid() {
+        let enc = LengthPrefixedStringsCodec::encode(&vec![String::from("hello")]).unwrap();
+
+        for len in 1..enc.len() {
+            assert_eq!(
+                LengthPrefixedStringsCodec::decode(&enc[..len]),
+                Err(LengthPrefixedStringsDecodeError::UnexpectedEnd)
+            );
+        }
+
+        // declares u32::MAX bytes
+        assert_eq!(
+            LengthPrefixedStringsCodec::decode(&[0xff, 0xff, 0xff, 0xff, b'a']),
+            Err(LengthPrefixedStringsDecodeError::UnexpectedEnd)
+        );
+
+        assert!(matches!(
+            LengthPrefixedStringsCodec::decode(&[0, 0, 0, 1, 0xff]),
+            Err(LengthPrefixedStringsDecodeError::InvalidUtf8(_))
+        ));
+    }
+}
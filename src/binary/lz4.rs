@@ -0,0 +1,325 @@
+use crate::binary::Compressor;
+use crate::{Decoder, Encoder};
+use std::io::{Read, Write};
+use thiserror::Error;
+
+/// [`Compressor`] that uses the LZ4 block format with the uncompressed size prepended, just like
+/// [`Lz4Codec`].
+///
+/// Only available with the **`lz4` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::{FromToBytesCodec, Lz4, MaybeCompressed};
+/// #
+/// type Codec = MaybeCompressed<FromToBytesCodec, Lz4, 64>;
+///
+/// let long = "hello".repeat(100);
+/// let encoded = Codec::encode(&long).unwrap();
+/// assert_eq!(encoded[0], 1);
+/// assert!(encoded.len() < long.len());
+/// let decoded: String = Codec::decode(&encoded).unwrap();
+/// assert_eq!(decoded, long);
+/// ```
+pub struct Lz4;
+
+#[derive(Error, Debug)]
+pub enum Lz4DecompressError {
+    #[error("uncompressed size {size} is larger than the {max} bytes the input can expand to")]
+    SizeTooLarge { size: usize, max: usize },
+    #[error(transparent)]
+    Block(#[from] lz4_flex::block::DecompressError),
+}
+
+/// LZ4 can't compress data by more than this factor.
+const MAX_COMPRESSION_RATIO: usize = 255;
+
+impl Compressor for Lz4 {
+    type Error = Lz4DecompressError;
+
+    fn compress(val: &[u8]) -> Result<Vec<u8>, Self::Error> {
+        Ok(lz4_flex::compress_prepend_size(val))
+    }
+
+    fn decompress(val: &[u8]) -> Result<Vec<u8>, Self::Error> {
+        let (size, compressed) = lz4_flex::block::uncompressed_size(val)?;
+
+        // reject a bogus size before it is allocated
+        let max = compressed.len().saturating_mul(MAX_COMPRESSION_RATIO);
+        if size > max {
+            return Err(Lz4DecompressError::SizeTooLarge { size, max });
+        }
+
+        Ok(lz4_flex::block::decompress(compressed, size)?)
+    }
+}
+
+/// [`Compressor`] that uses the LZ4 frame format, just like [`Lz4FrameCodec`].
+///
+/// Only available with the **`lz4` feature** enabled.
+pub struct Lz4Frame;
+
+impl Compressor for Lz4Frame {
+    type Error = lz4_flex::frame::Error;
+
+    fn compress(val: &[u8]) -> Result<Vec<u8>, Self::Error> {
+        let mut encoder = lz4_flex::frame::FrameEncoder::new(Vec::new());
+        encoder.write_all(val)?;
+        encoder.finish()
+    }
+
+    fn decompress(val: &[u8]) -> Result<Vec<u8>, Self::Error> {
+        let mut buf = Vec::new();
+        lz4_flex::frame::FrameDecoder::new(val).read_to_end(&mut buf)?;
+        Ok(buf)
+    }
+}
+
+/// Wraps a binary codec and compresses its output with the LZ4 block format using [`lz4_flex`].
+///
+/// LZ4 is very fast but doesn't compress as well as gzip or brotli. This makes it a good fit
+/// for many small messages. The block format needs the size of the uncompressed data to
+/// decompress it, so it is prepended as a `u32` in little-endian byte order. If you need
+/// interoperability with the `lz4` command line tool, use [`Lz4FrameCodec`] instead.
+///
+/// Decoding rejects a prepended size that is larger than the compressed input could possibly
+/// expand to, so corrupt or malicious input can't trigger huge allocations.
+///
+/// Only available with the **`lz4` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::{Lz4Codec, FromToBytesCodec};
+/// #
+/// let original_value = "hello world ".repeat(100);
+///
+/// let encoded = Lz4Codec::<FromToBytesCodec>::encode(&original_value).unwrap();
+/// assert!(encoded.len() < original_value.len());
+///
+/// let decoded: String = Lz4Codec::<FromToBytesCodec>::decode(&encoded).unwrap();
+/// assert_eq!(decoded, original_value);
+/// ```
+pub struct Lz4Codec<C>(C);
+
+#[derive(Error, Debug)]
+pub enum Lz4DecodeError<Err> {
+    #[error("failed to decompress lz4: {0}")]
+    Decompress(#[from] Lz4DecompressError),
+    #[error("failed to decode: {0}")]
+    Decoder(Err),
+}
+
+impl<T, E> Encoder<T> for Lz4Codec<E>
+where
+    E: Encoder<T, Encoded = Vec<u8>>,
+{
+    type Error = E::Error;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        let payload = E::encode(val)?;
+        // compressing with the block format can't fail
+        Ok(lz4_flex::compress_prepend_size(&payload))
+    }
+}
+
+impl<T, D> Decoder<T> for Lz4Codec<D>
+where
+    D: Decoder<T, Encoded = [u8]>,
+{
+    type Error = Lz4DecodeError<D::Error>;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        let payload = Lz4::decompress(val)?;
+        D::decode(&payload).map_err(Lz4DecodeError::Decoder)
+    }
+}
+
+/// Wraps a binary codec and compresses its output with the
+/// [LZ4 frame format](https://github.com/lz4/lz4/blob/dev/doc/lz4_Frame_format.md) using
+/// [`lz4_flex`].
+///
+/// Compared to [`Lz4Codec`] the frame format has a larger header and a checksum but is
+/// compatible with other LZ4 implementations like the `lz4` command line tool.
+///
+/// Only available with the **`lz4` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::{Lz4FrameCodec, FromToBytesCodec};
+/// #
+/// let original_value = "hello world ".repeat(100);
+///
+/// let encoded = Lz4FrameCodec::<FromToBytesCodec>::encode(&original_value).unwrap();
+/// assert!(encoded.len() < original_value.len());
+///
+/// let decoded: String = Lz4FrameCodec::<FromToBytesCodec>::decode(&encoded).unwrap();
+/// assert_eq!(decoded, original_value);
+/// ```
+pub struct Lz4FrameCodec<C>(C);
+
+#[derive(Error, Debug)]
+pub enum Lz4FrameEncodeError<Err> {
+    #[error("failed to encode: {0}")]
+    Encoder(Err),
+    #[error("failed to compress lz4: {0}")]
+    Compress(#[from] lz4_flex::frame::Error),
+}
+
+#[derive(Error, Debug)]
+pub enum Lz4FrameDecodeError<Err> {
+    #[error("failed to decompress lz4: {0}")]
+    Decompress(#[from] lz4_flex::frame::Error),
+    #[error("failed to decode: {0}")]
+    Decoder(Err),
+}
+
+impl<T, E> Encoder<T> for Lz4FrameCodec<E>
+where
+    E: Encoder<T, Encoded = Vec<u8>>,
+{
+    type Error = Lz4FrameEncodeError<E::Error>;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        let payload = E::encode(val).map_err(Lz4FrameEncodeError::Encoder)?;
+
+        Ok(Lz4Frame::compress(&payload)?)
+    }
+}
+
+impl<T, D> Decoder<T> for Lz4FrameCodec<D>
+where
+    D: Decoder<T, Encoded = [u8]>,
+{
+    type Error = Lz4FrameDecodeError<D::Error>;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        let payload = Lz4Frame::decompress(val)?;
+
+        D::decode(&payload).map_err(Lz4FrameDecodeError::Decoder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary::{FromToBytesCodec, MaybeCompressed};
+
+    #[test]
+    fn test_lz4_codec() {
+        let s = "party time 🎉 ".repeat(100);
+
+        let enc = Lz4Codec::<FromToBytesCodec>::encode(&s).unwrap();
+        assert!(enc.len() < s.len() / 10);
+        assert_eq!(&enc[..4], &(s.len() as u32).to_le_bytes());
+
+        let dec: String = Lz4Codec::<FromToBytesCodec>::decode(&enc).unwrap();
+        assert_eq!(dec, s);
+
+        let dec: Result<String, _> = Lz4Codec::<FromToBytesCodec>::decode(&enc[..enc.len() - 1]);
+        assert!(matches!(dec, Err(Lz4DecodeError::Decompress(_))));
+    }
+
+    #[test]
+    fn test_lz4_codec_size_too_large() {
+        let dec: Result<String, _> = Lz4Codec::<FromToBytesCodec>::decode(&u32::MAX.to_le_bytes());
+        assert!(matches!(
+            dec,
+            Err(Lz4DecodeError::Decompress(
+                Lz4DecompressError::SizeTooLarge {
+                    size: 0xffff_ffff,
+                    max: 0
+                }
+            ))
+        ));
+
+        let mut enc = Lz4Codec::<FromToBytesCodec>::encode(&"a".repeat(1000)).unwrap();
+        let max = (enc.len() - 4) * 255;
+        enc[..4].copy_from_slice(&(max as u32 + 1).to_le_bytes());
+        let dec: Result<String, _> = Lz4Codec::<FromToBytesCodec>::decode(&enc);
+        assert!(matches!(
+            dec,
+            Err(Lz4DecodeError::Decompress(
+                Lz4DecompressError::SizeTooLarge { .. }
+            ))
+        ));
+
+        // highly compressible data stays within the bound
+        let s = "a".repeat(1 << 20);
+        let enc = Lz4Codec::<FromToBytesCodec>::encode(&s).unwrap();
+        let dec: String = Lz4Codec::<FromToBytesCodec>::decode(&enc).unwrap();
+        assert_eq!(dec, s);
+    }
+
+    #[test]
+    fn test_lz4_frame_codec() {
+        let s = "party time 🎉 ".repeat(100);
+
+        let enc = Lz4FrameCodec::<FromToBytesCodec>::encode(&s).unwrap();
+        assert!(enc.len() < s.len() / 10);
+        // LZ4 frame magic number
+        assert_eq!(&enc[..4], &[0x04, 0x22, 0x4d, 0x18]);
+
+        let dec: String = Lz4FrameCodec::<FromToBytesCodec>::decode(&enc).unwrap();
+        assert_eq!(dec, s);
+
+        let dec: Result<String, _> = Lz4FrameCodec::<FromToBytesCodec>::decode(b"not lz4");
+        assert!(matches!(dec, Err(Lz4FrameDecodeError::Decompress(_))));
+    }
+
+    #[test]
+    fn test_lz4_maybe_compressed() {
+        let short = String::from("party time 🎉");
+        let long = short.repeat(20);
+
+        type BlockCodec = MaybeCompressed<FromToBytesCodec, Lz4, 64>;
+
+        let enc = BlockCodec::encode(&short).unwrap();
+        assert_eq!(enc[0], 0);
+        let dec: String = BlockCodec::decode(&enc).unwrap();
+        assert_eq!(dec, short);
+
+        let enc = BlockCodec::encode(&long).unwrap();
+        assert_eq!(enc[0], 1);
+        assert_eq!(
+            &enc[1..],
+            Lz4Codec::<FromToBytesCodec>::encode(&long).unwrap()
+        );
+        let dec: String = BlockCodec::decode(&enc).unwrap();
+        assert_eq!(dec, long);
+
+        type FrameCodec = MaybeCompressed<FromToBytesCodec, Lz4Frame, 64>;
+
+        let enc = FrameCodec::encode(&long).unwrap();
+        assert_eq!(enc[0], 1);
+        assert!(enc.len() < long.len());
+        let dec: String = FrameCodec::decode(&enc).unwrap();
+        assert_eq!(dec, long);
+    }
+
+    #[test]
+    fn test_lz4_tiny_payload() {
+        let enc = Lz4Codec::<FromToBytesCodec>::encode(&7_u8).unwrap();
+        assert!(enc.len() > 1);
+        let dec: u8 = Lz4Codec::<FromToBytesCodec>::decode(&enc).unwrap();
+        assert_eq!(dec, 7);
+
+        let enc = Lz4FrameCodec::<FromToBytesCodec>::encode(&7_u8).unwrap();
+        assert!(enc.len() > 1);
+        let dec: u8 = Lz4FrameCodec::<FromToBytesCodec>::decode(&enc).unwrap();
+        assert_eq!(dec, 7);
+
+        let enc = Lz4Codec::<FromToBytesCodec>::encode(&String::new()).unwrap();
+        let dec: String = Lz4Codec::<FromToBytesCodec>::decode(&enc).unwrap();
+        assert_eq!(dec, "");
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{Decoder, Encoder};
+use alloc::vec::Vec;
+use thiserror::Error;
+
+/// Wraps a binary codec and prepends a 4 byte magic header that is checked when decoding.
+///
+/// This makes it safe to probe which format some stored data has. Decoding input that was written
+/// with a different magic fails with [`MagicDecodeError::WrongMagic`] instead of a confusing error
+/// of the inner decoder or, even worse, a garbage value.
+///
+/// `MAGIC` is written in big-endian byte order, so you can turn four ASCII characters into the
+/// magic with `u32::from_be_bytes(*b"MPK1")`.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::{FromToBytesCodec, MagicCodec, MagicDecodeError};
+/// #
+/// type V1 = MagicCodec<FromToBytesCodec, { u32::from_be_bytes(*b"CNT1") }>;
+/// type V2 = MagicCodec<FromToBytesCodec, { u32::from_be_bytes(*b"CNT2") }>;
+///
+/// let encoded = V1::encode(&42_u32).unwrap();
+/// assert!(encoded.starts_with(b"CNT1"));
+///
+/// let decoded: u32 = V1::decode(&encoded).unwrap();
+/// assert_eq!(decoded, 42);
+///
+/// let decoded: Result<u32, _> = V2::decode(&encoded);
+/// assert!(matches!(decoded, Err(MagicDecodeError::WrongMagic { .. })));
+/// ```
+pub struct MagicCodec<C, const MAGIC: u32>(C);
+
+#[derive(Error, Debug, PartialEq)]
+pub enum MagicDecodeError<Err> {
+    #[error("input is too short to contain the magic header")]
+    UnexpectedEnd,
+    #[error("expected magic header {expected:?} but found {found:?}")]
+    WrongMagic { expected: [u8; 4], found: [u8; 4] },
+    #[error("failed to decode: {0}")]
+    Decoder(Err),
+}
+
+impl<T, E, const MAGIC: u32> Encoder<T> for MagicCodec<E, MAGIC>
+where
+    E: Encoder<T, Encoded = Vec<u8>>,
+{
+    type Error = E::Error;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        let payload = E::encode(val)?;
+
+        let mut buf = Vec::with_capacity(4 + payload.len());
+        buf.extend_from_slice(&MAGIC.to_be_bytes());
+        buf.extend(payload);
+
+        Ok(buf)
+    }
+
+    fn size_hint(val: &T) -> Option<usize> {
+        E::size_hint(val).map(|len| 4 + len)
+    }
+}
+
+impl<T, D, const MAGIC: u32> Decoder<T> for MagicCodec<D, MAGIC>
+where
+    D: Decoder<T, Encoded = [u8]>,
+{
+    type Error = MagicDecodeError<D::Error>;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        if val.len() < 4 {
+            return Err(MagicDecodeError::UnexpectedEnd);
+        }
+        let (magic, payload) = val.split_at(4);
+
+        let found: [u8; 4] = magic.try_into().unwrap();
+        let expected = MAGIC.to_be_bytes();
+        if found != expected {
+            return Err(MagicDecodeError::WrongMagic { expected, found });
+        }
+
+        D::decode(payload).map_err(MagicDecodeError::Decoder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary::FromToBytesCodec;
+
+    type Codec = MagicCodec<FromToBytesCodec, { u32::from_be_bytes(*b"TEST") }>;
+
+    #[test]
+    fn test_magic_codec() {
+        let s = String::from("party time 🎉");
+
+        let enc = Codec::encode(&s).unwrap();
+        assert_eq!(&enc[..4], b"TEST");
+        assert_eq!(&enc[4..], s.as_bytes());
+
+        let dec: String = Codec::decode(enc.as_slice()).unwrap();
+        assert_eq!(dec, s);
+    }
+
+    #[test]
+    fn test_magic_codec_wrong_magic() {
+        let enc = MagicCodec::<FromToBytesCodec, { u32::from_be_bytes(*b"ABCD") }>::encode(&42_u32)
+            .unwrap();
+
+        let dec: Result<u32, _> = Codec::decode(&enc);
+        assert!(matches!(
+            dec,
+            Err(MagicDecodeError::WrongMagic {
+                expected: [b'T', b'E', b'S', b'T'],
+                found: [b'A', b'B', b'C', b'D'],
+            })
+        ));
+    }
+
+    #[test]
+    fn test_magic_codec_too_short() {
+        let dec: Result<u32, _> = Codec::decode(b"TES");
+        assert!(matches!(dec, Err(MagicDecodeError::UnexpectedEnd)));
+
+        let dec: Result<u32, _> = Codec::decode(b"TEST");
+        assert!(matches!(dec, Err(MagicDecodeError::Decoder(_))));
+    }
+}
@@ -0,0 +1,241 @@
+use crate::{Decoder, Encoder};
+use alloc::vec::Vec;
+use thiserror::Error;
+
+/// A compression algorithm that can be used with [`MaybeCompressed`].
+pub trait Compressor: 'static {
+    type Error;
+
+    fn compress(val: &[u8]) -> Result<Vec<u8>, Self::Error>;
+
+    fn decompress(val: &[u8]) -> Result<Vec<u8>, Self::Error>;
+}
+
+/// [`Compressor`] that uses gzip with the default compression level.
+///
+/// Only available with the **`gzip` feature** enabled.
+#[cfg(feature = "gzip")]
+pub struct Gzip;
+
+#[cfg(feature = "gzip")]
+impl Compressor for Gzip {
+    type Error = std::io::Error;
+
+    fn compress(val: &[u8]) -> Result<Vec<u8>, Self::Error> {
+        use std::io::Write;
+
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(val)?;
+        encoder.finish()
+    }
+
+    fn decompress(val: &[u8]) -> Result<Vec<u8>, Self::Error> {
+        use std::io::Read;
+
+        let mut buf = Vec::new();
+        flate2::read::GzDecoder::new(val).read_to_end(&mut buf)?;
+        Ok(buf)
+    }
+}
+
+/// Wraps a binary codec `C` and compresses its output with `Z` if it is at least `THRESHOLD`
+/// bytes long.
+///
+/// The output starts with a flag byte that is `1` if the rest is compressed and `0` if it isn't.
+/// This way small values don't pay the overhead of the compression format while large values
+/// are stored compactly. Decoding reads the flag and only decompresses if necessary.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::{FromToBytesCodec, Gzip, MaybeCompressed};
+/// #
+/// type Codec = MaybeCompressed<FromToBytesCodec, Gzip, 64>;
+///
+/// let short = "hello".to_owned();
+/// let encoded = Codec::encode(&short).unwrap();
+/// assert_eq!(encoded[0], 0);
+/// let decoded: String = Codec::decode(&encoded).unwrap();
+/// assert_eq!(decoded, short);
+///
+/// let long = "hello".repeat(100);
+/// let encoded = Codec::encode(&long).unwrap();
+/// assert_eq!(encoded[0], 1);
+/// assert!(encoded.len() < long.len());
+/// let decoded: String = Codec::decode(&encoded).unwrap();
+/// assert_eq!(decoded, long);
+/// ```
+pub struct MaybeCompressed<C, Z, const THRESHOLD: usize = 256>(C, Z);
+
+#[derive(Error, Debug, PartialEq)]
+pub enum MaybeCompressedEncodeError<Err, ZErr> {
+    #[error("failed to encode: {0}")]
+    Encoder(Err),
+    #[error("failed to compress: {0}")]
+    Compress(ZErr),
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum MaybeCompressedDecodeError<Err, ZErr> {
+    #[error("unexpected end of input")]
+    UnexpectedEnd,
+    #[error("invalid compression flag {0}")]
+    InvalidFlag(u8),
+    #[error("failed to decompress: {0}")]
+    Decompress(ZErr),
+    #[error("failed to decode: {0}")]
+    Decoder(Err),
+}
+
+const UNCOMPRESSED: u8 = 0;
+const COMPRESSED: u8 = 1;
+
+impl<T, E, Z, const THRESHOLD: usize> Encoder<T> for MaybeCompressed<E, Z, THRESHOLD>
+where
+    E: Encoder<T, Encoded = Vec<u8>>,
+    Z: Compressor,
+{
+    type Error = MaybeCompressedEncodeError<E::Error, Z::Error>;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        let payload = E::encode(val).map_err(MaybeCompressedEncodeError::Encoder)?;
+
+        let (flag, payload) = if payload.len() >= THRESHOLD {
+            let compressed = Z::compress(&payload).map_err(MaybeCompressedEncodeError::Compress)?;
+            (COMPRESSED, compressed)
+        } else {
+            (UNCOMPRESSED, payload)
+        };
+
+        let mut buf = Vec::with_capacity(1 + payload.len());
+        buf.push(flag);
+        buf.extend(payload);
+
+        Ok(buf)
+    }
+}
+
+impl<T, D, Z, const THRESHOLD: usize> Decoder<T> for MaybeCompressed<D, Z, THRESHOLD>
+where
+    D: Decoder<T, Encoded = [u8]>,
+    Z: Compressor,
+{
+    type Error = MaybeCompressedDecodeError<D::Error, Z::Error>;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        let (&flag, payload) = val
+            .split_first()
+            .ok_or(MaybeCompressedDecodeError::UnexpectedEnd)?;
+
+        match flag {
+            UNCOMPRESSED => D::decode(payload).map_err(MaybeCompressedDecodeError::Decoder),
+            COMPRESSED => {
+                let payload =
+                    Z::decompress(payload).map_err(MaybeCompressedDecodeError::Decompress)?;
+                D::decode(&payload).map_err(MaybeCompressedDecodeError::Decoder)
+            }
+            _ => Err(MaybeCompressedDecodeError::InvalidFlag(flag)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary::FromToBytesCodec;
+
+    /// Run-length encoding as a simple compressor that doesn't need any features
+    struct Rle;
+
+    impl Compressor for Rle {
+        type Error = ();
+
+        fn compress(val: &[u8]) -> Result<Vec<u8>, Self::Error> {
+            let mut buf = Vec::new();
+            for chunk in val.chunk_by(|a, b| a == b) {
+                for part in chunk.chunks(u8::MAX as usize) {
+                    buf.extend([part.len() as u8, part[0]]);
+                }
+            }
+            Ok(buf)
+        }
+
+        fn decompress(val: &[u8]) -> Result<Vec<u8>, Self::Error> {
+            if !val.len().is_multiple_of(2) {
+                return Err(());
+            }
+            Ok(val
+                .chunks(2)
+                .flat_map(|pair| core::iter::repeat_n(pair[1], pair[0] as usize))
+                .collect())
+        }
+    }
+
+    type Codec = MaybeCompressed<FromToBytesCodec, Rle, 16>;
+
+    #[test]
+    fn test_small_payload_is_uncompressed() {
+        let s = String::from("aaaa");
+
+        let enc = Codec::encode(&s).unwrap();
+        assert_eq!(enc, b"\0aaaa");
+
+        let dec: String = Codec::decode(&enc).unwrap();
+        assert_eq!(dec, s);
+    }
+
+    #[test]
+    fn test_large_payload_is_compressed() {
+        let s = "a".repeat(100);
+
+        let enc = Codec::encode(&s).unwrap();
+        assert_eq!(enc, [1, 100, b'a']);
+
+        let dec: String = Codec::decode(&enc).unwrap();
+        assert_eq!(dec, s);
+    }
+
+    #[test]
+    fn test_maybe_compressed_errors() {
+        let dec: Result<String, _> = Codec::decode(&[]);
+        assert!(matches!(
+            dec,
+            Err(MaybeCompressedDecodeError::UnexpectedEnd)
+        ));
+
+        let dec: Result<String, _> = Codec::decode(&[2, b'a']);
+        assert!(matches!(
+            dec,
+            Err(MaybeCompressedDecodeError::InvalidFlag(2))
+        ));
+
+        let dec: Result<String, _> = Codec::decode(&[1, 100]);
+        assert!(matches!(
+            dec,
+            Err(MaybeCompressedDecodeError::Decompress(()))
+        ));
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn test_maybe_compressed_gzip() {
+        type Codec = MaybeCompressed<FromToBytesCodec, Gzip, 64>;
+
+        let short = String::from("party time 🎉");
+        let enc = Codec::encode(&short).unwrap();
+        assert_eq!(enc[0], 0);
+        assert_eq!(&enc[1..], short.as_bytes());
+        let dec: String = Codec::decode(&enc).unwrap();
+        assert_eq!(dec, short);
+
+        let long = short.repeat(20);
+        let enc = Codec::encode(&long).unwrap();
+        assert_eq!(enc[0], 1);
+        assert!(enc.len() < long.len());
+        let dec: String = Codec::decode(&enc).unwrap();
+        assert_eq!(dec, long);
+    }
+}
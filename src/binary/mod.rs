@@ -1,20 +1,148 @@
+#[cfg(feature = "avro")]
+mod avro;
+#[cfg(all(feature = "bincode_serde", feature = "postcard_serde"))]
+mod bincode_or_postcard;
 #[cfg(feature = "bincode_serde")]
 mod bincode_serde;
+mod bit_pack;
+#[cfg(feature = "brotli")]
+mod brotli;
+#[cfg(feature = "bytes")]
+mod bytes;
+#[cfg(feature = "capnp")]
+mod capnp;
+#[cfg(feature = "capnp")]
+mod capnp_packed;
+#[cfg(feature = "cbor")]
+mod cbor;
+#[cfg(feature = "chacha")]
+mod chacha;
+mod content_hash;
+mod content_typed;
+mod fixed_size;
+mod for_bitpack;
 mod from_to_bytes;
+mod from_to_bytes_endian;
+mod grpc_framed;
+mod hamming;
+mod length_prefixed;
+mod length_prefixed_strings;
+#[cfg(feature = "lz4")]
+mod lz4;
+mod magic;
+mod maybe_compressed;
 #[cfg(feature = "msgpack_serde")]
 mod msgpack_serde;
+#[cfg(feature = "msgpack_serde")]
+mod msgpack_serde_max_len;
+#[cfg(feature = "msgpack_serde")]
+mod msgpack_serde_named;
+#[cfg(feature = "musli")]
+mod musli;
+mod pair;
+#[cfg(feature = "postcard_serde")]
+mod postcard_serde;
+#[cfg(feature = "postcard_serde")]
+mod postcard_versioned;
+#[cfg(feature = "pot")]
+mod pot;
 #[cfg(feature = "prost")]
 mod prost;
+#[cfg(feature = "prost")]
+mod prost_length_delimited;
+#[cfg(feature = "prost_reflect")]
+mod prost_reflect;
+#[cfg(feature = "protobuf")]
+mod protobuf;
 #[cfg(feature = "rkyv")]
 mod rkyv;
+mod safe_vec;
+mod seq;
+#[cfg(feature = "signing")]
+mod signed;
+mod smallest;
+#[cfg(feature = "speedy")]
+mod speedy;
+#[cfg(feature = "interning")]
+mod string_interning;
+mod tagged_stream;
+mod utf16;
+#[cfg(feature = "uuid")]
+mod uuid;
+mod varint;
+mod xor;
 
+#[cfg(feature = "avro")]
+pub use avro::*;
+#[cfg(all(feature = "bincode_serde", feature = "postcard_serde"))]
+pub use bincode_or_postcard::*;
 #[cfg(feature = "bincode_serde")]
 pub use bincode_serde::*;
+pub use bit_pack::*;
+#[cfg(feature = "brotli")]
+pub use brotli::*;
+#[cfg(feature = "bytes")]
+pub use bytes::*;
+#[cfg(feature = "capnp")]
+pub use capnp::*;
+#[cfg(feature = "capnp")]
+pub use capnp_packed::*;
+#[cfg(feature = "cbor")]
+pub use cbor::*;
+#[cfg(feature = "chacha")]
+pub use chacha::*;
+pub use content_hash::*;
+pub use content_typed::*;
+pub use fixed_size::*;
+pub use for_bitpack::*;
 #[allow(unused_imports)]
 pub use from_to_bytes::*;
+pub use from_to_bytes_endian::*;
+pub use grpc_framed::*;
+pub use hamming::*;
+pub use length_prefixed::*;
+pub use length_prefixed_strings::*;
+#[cfg(feature = "lz4")]
+pub use lz4::*;
+pub use magic::*;
+pub use maybe_compressed::*;
 #[cfg(feature = "msgpack_serde")]
 pub use msgpack_serde::*;
+#[cfg(feature = "msgpack_serde")]
+pub use msgpack_serde_max_len::*;
+#[cfg(feature = "msgpack_serde")]
+pub use msgpack_serde_named::*;
+#[cfg(feature = "musli")]
+pub use musli::*;
+pub use pair::*;
+#[cfg(feature = "postcard_serde")]
+pub use postcard_serde::*;
+#[cfg(feature = "postcard_serde")]
+pub use postcard_versioned::*;
+#[cfg(feature = "pot")]
+pub use pot::*;
 #[cfg(feature = "prost")]
 pub use prost::*;
+#[cfg(feature = "prost")]
+pub use prost_length_delimited::*;
+#[cfg(feature = "prost_reflect")]
+pub use prost_reflect::*;
+#[cfg(feature = "protobuf")]
+pub use protobuf::*;
 #[cfg(feature = "rkyv")]
 pub use rkyv::*;
+pub use safe_vec::*;
+pub use seq::*;
+#[cfg(feature = "signing")]
+pub use signed::*;
+pub use smallest::*;
+#[cfg(feature = "speedy")]
+pub use speedy::*;
+#[cfg(feature = "interning")]
+pub use string_interning::*;
+pub use tagged_stream::*;
+pub use utf16::*;
+#[cfg(feature = "uuid")]
+pub use uuid::*;
+pub use varint::*;
+pub use xor::*;
@@ -1,4 +1,6 @@
-use crate::{Decoder, Encoder};
+use crate::{Decoder, DecoderWithRemainder, Encoder, EncoderInto};
+#[cfg(feature = "std")]
+use crate::{StreamDecoder, StreamEncoder};
 
 /// A codec that relies on `rmp-serde` to encode data in the msgpack format.
 ///
@@ -14,6 +16,19 @@ impl<T: serde::Serialize> Encoder<T> for MsgpackSerdeCodec {
     }
 }
 
+impl<T: serde::Serialize> EncoderInto<T> for MsgpackSerdeCodec {
+    fn encode_into(val: &T, buf: &mut Self::Encoded) -> Result<(), Self::Error> {
+        rmp_serde::encode::write(buf, val)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: serde::Serialize> StreamEncoder<T> for MsgpackSerdeCodec {
+    fn encode_to_writer<W: std::io::Write>(val: &T, writer: &mut W) -> Result<(), Self::Error> {
+        rmp_serde::encode::write(writer, val)
+    }
+}
+
 impl<T: serde::de::DeserializeOwned> Decoder<T> for MsgpackSerdeCodec {
     type Error = rmp_serde::decode::Error;
     type Encoded = [u8];
@@ -23,6 +38,20 @@ impl<T: serde::de::DeserializeOwned> Decoder<T> for MsgpackSerdeCodec {
     }
 }
 
+impl<T: serde::de::DeserializeOwned> DecoderWithRemainder<T> for MsgpackSerdeCodec {
+    fn decode_with_remainder(mut val: &[u8]) -> Result<(T, &[u8]), Self::Error> {
+        let value = rmp_serde::from_read(&mut val)?;
+        Ok((value, val))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: serde::de::DeserializeOwned> StreamDecoder<T> for MsgpackSerdeCodec {
+    fn decode_from_reader<R: std::io::Read>(reader: &mut R) -> Result<T, Self::Error> {
+        rmp_serde::from_read(reader)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -42,4 +71,60 @@ mod tests {
         let dec: Test = MsgpackSerdeCodec::decode(&enc).unwrap();
         assert_eq!(dec, t);
     }
+
+    #[test]
+    fn test_msgpack_codec_encode_into() {
+        let mut buf = Vec::with_capacity(64);
+        let ptr = buf.as_ptr();
+
+        for i in 0..1000_u32 {
+            buf.clear();
+            MsgpackSerdeCodec::encode_into(&(i, "party time 🎉"), &mut buf).unwrap();
+
+            let dec: (u32, String) = MsgpackSerdeCodec::decode(&buf).unwrap();
+            assert_eq!(dec, (i, "party time 🎉".to_owned()));
+        }
+
+        assert_eq!(buf.capacity(), 64);
+        assert_eq!(buf.as_ptr(), ptr);
+    }
+
+    #[test]
+    fn test_msgpack_codec_decode_with_remainder() {
+        let first = (42_u32, String::from("party time 🎉"));
+        let second = vec![1.5_f64, -2.0];
+
+        let mut buf = MsgpackSerdeCodec::encode(&first).unwrap();
+        let second_enc = MsgpackSerdeCodec::encode(&second).unwrap();
+        buf.extend_from_slice(&second_enc);
+
+        let (dec, rest): ((u32, String), _) =
+            MsgpackSerdeCodec::decode_with_remainder(&buf).unwrap();
+        assert_eq!(dec, first);
+        assert_eq!(rest, second_enc.as_slice());
+
+        let (dec, rest): (Vec<f64>, _) = MsgpackSerdeCodec::decode_with_remainder(rest).unwrap();
+        assert_eq!(dec, second);
+        assert!(rest.is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_msgpack_codec_encode_to_writer() {
+        let t = (42_u32, String::from("party time 🎉"));
+
+        let mut writer: Vec<u8> = Vec::new();
+        MsgpackSerdeCodec::encode_to_writer(&t, &mut writer).unwrap();
+        assert_eq!(writer, MsgpackSerdeCodec::encode(&t).unwrap());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_msgpack_codec_decode_from_reader() {
+        let t = (42_u32, String::from("party time 🎉"));
+
+        let mut reader = std::io::Cursor::new(MsgpackSerdeCodec::encode(&t).unwrap());
+        let dec: (u32, String) = MsgpackSerdeCodec::decode_from_reader(&mut reader).unwrap();
+        assert_eq!(dec, t);
+    }
 }
@@ -0,0 +1,182 @@
+use crate::binary::MsgpackSerdeCodec;
+use crate::{Decoder, Encoder};
+use thiserror::Error;
+
+/// Like [`MsgpackSerdeCodec`] but decoding rejects input that declares an array or map with more
+/// than `MAX_LEN` elements.
+///
+/// Untrusted msgpack can declare huge collection lengths which can lead to large allocations.
+/// Before handing the input to `rmp-serde` this codec scans it and checks every declared
+/// collection length against `MAX_LEN`. It also checks that declared lengths don't exceed the
+/// size of the input.
+///
+/// This is only available with the **`msgpack_serde` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::{MsgpackSerdeMaxLenCodec, MsgpackMaxLenDecodeError};
+/// #
+/// let encoded = MsgpackSerdeMaxLenCodec::<4>::encode(&vec![1, 2, 3]).unwrap();
+/// let decoded: Vec<i32> = MsgpackSerdeMaxLenCodec::<4>::decode(&encoded).unwrap();
+/// assert_eq!(decoded, vec![1, 2, 3]);
+///
+/// let encoded = MsgpackSerdeMaxLenCodec::<4>::encode(&vec![1, 2, 3, 4, 5]).unwrap();
+/// let decoded: Result<Vec<i32>, _> = MsgpackSerdeMaxLenCodec::<4>::decode(&encoded);
+/// assert!(matches!(decoded, Err(MsgpackMaxLenDecodeError::TooLong { len: 5, max: 4 })));
+/// ```
+pub struct MsgpackSerdeMaxLenCodec<const MAX_LEN: usize>;
+
+#[derive(Error, Debug)]
+pub enum MsgpackMaxLenDecodeError {
+    #[error("declared collection length {len} exceeds the maximum of {max}")]
+    TooLong { len: usize, max: usize },
+    #[error("unexpected end of input")]
+    UnexpectedEnd,
+    #[error("invalid msgpack marker 0x{0:02x}")]
+    InvalidMarker(u8),
+    #[error("failed to decode: {0}")]
+    Decoder(#[from] rmp_serde::decode::Error),
+}
+
+impl<T: serde::Serialize, const MAX_LEN: usize> Encoder<T> for MsgpackSerdeMaxLenCodec<MAX_LEN> {
+    type Error = rmp_serde::encode::Error;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        MsgpackSerdeCodec::encode(val)
+    }
+}
+
+impl<T: serde::de::DeserializeOwned, const MAX_LEN: usize> Decoder<T>
+    for MsgpackSerdeMaxLenCodec<MAX_LEN>
+{
+    type Error = MsgpackMaxLenDecodeError;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        check_lengths(val, MAX_LEN)?;
+        Ok(MsgpackSerdeCodec::decode(val)?)
+    }
+}
+
+/// Walks the msgpack value at the start of `val` without allocating and checks that no array
+/// or map declares more than `max` elements.
+fn check_lengths(mut val: &[u8], max: usize) -> Result<(), MsgpackMaxLenDecodeError> {
+    // number of values that still have to be read
+    let mut pending: u64 = 1;
+
+    while pending > 0 {
+        // every value needs at least one byte
+        if pending > val.len() as u64 {
+            return Err(MsgpackMaxLenDecodeError::UnexpectedEnd);
+        }
+        pending -= 1;
+
+        let (&marker, rest) = val.split_first().unwrap();
+        val = rest;
+
+        // (number of nested values, number of bytes to skip)
+        let (items, skip) = match marker {
+            0x00..=0x7f | 0xc0 | 0xc2 | 0xc3 | 0xe0..=0xff => (0, 0),
+            0x80..=0x8f => (2 * collection_len((marker & 0x0f) as usize, max)?, 0),
+            0x90..=0x9f => (collection_len((marker & 0x0f) as usize, max)?, 0),
+            0xa0..=0xbf => (0, (marker & 0x1f) as usize),
+            0xc4 | 0xd9 => (0, read_len(&mut val, 1)?),
+            0xc5 | 0xda => (0, read_len(&mut val, 2)?),
+            0xc6 | 0xdb => (0, read_len(&mut val, 4)?),
+            0xc7 => (0, read_len(&mut val, 1)? + 1),
+            0xc8 => (0, read_len(&mut val, 2)? + 1),
+            0xc9 => (0, read_len(&mut val, 4)? + 1),
+            0xcc | 0xd0 => (0, 1),
+            0xcd | 0xd1 | 0xd4 => (0, 2),
+            0xd5 => (0, 3),
+            0xca | 0xce | 0xd2 => (0, 4),
+            0xd6 => (0, 5),
+            0xcb | 0xcf | 0xd3 => (0, 8),
+            0xd7 => (0, 9),
+            0xd8 => (0, 17),
+            0xdc => (collection_len(read_len(&mut val, 2)?, max)?, 0),
+            0xdd => (collection_len(read_len(&mut val, 4)?, max)?, 0),
+            0xde => (2 * collection_len(read_len(&mut val, 2)?, max)?, 0),
+            0xdf => (2 * collection_len(read_len(&mut val, 4)?, max)?, 0),
+            0xc1 => return Err(MsgpackMaxLenDecodeError::InvalidMarker(marker)),
+        };
+
+        if skip > val.len() {
+            return Err(MsgpackMaxLenDecodeError::UnexpectedEnd);
+        }
+        val = &val[skip..];
+        pending += items;
+    }
+
+    Ok(())
+}
+
+fn collection_len(len: usize, max: usize) -> Result<u64, MsgpackMaxLenDecodeError> {
+    if len > max {
+        Err(MsgpackMaxLenDecodeError::TooLong { len, max })
+    } else {
+        Ok(len as u64)
+    }
+}
+
+/// Reads a big-endian length of `bytes` bytes from the front of `val`.
+fn read_len(val: &mut &[u8], bytes: usize) -> Result<usize, MsgpackMaxLenDecodeError> {
+    if val.len() < bytes {
+        return Err(MsgpackMaxLenDecodeError::UnexpectedEnd);
+    }
+    let (len, rest) = val.split_at(bytes);
+    *val = rest;
+
+    Ok(len.iter().fold(0, |acc, &b| (acc << 8) | b as usize))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_msgpack_max_len_codec() {
+        #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Test {
+            s: String,
+            list: Vec<(i64, f64)>,
+            map: std::collections::HashMap<String, Vec<u8>>,
+        }
+        let t = Test {
+            s: String::from("party time 🎉"),
+            list: vec![(-1, 0.5), (1 << 40, 1.5)],
+            map: [("a".to_owned(), vec![1, 2, 3])].into_iter().collect(),
+        };
+
+        let enc = MsgpackSerdeMaxLenCodec::<3>::encode(&t).unwrap();
+        let dec: Test = MsgpackSerdeMaxLenCodec::<3>::decode(&enc).unwrap();
+        assert_eq!(dec, t);
+
+        let dec: Result<Test, _> = MsgpackSerdeMaxLenCodec::<2>::decode(&enc);
+        assert!(matches!(
+            dec,
+            Err(MsgpackMaxLenDecodeError::TooLong { len: 3, max: 2 })
+        ));
+    }
+
+    #[test]
+    fn test_msgpack_max_len_codec_huge_array() {
+        // array32 that declares u32::MAX elements but contains only one
+        let payload = [0xdd, 0xff, 0xff, 0xff, 0xff, 0x01];
+
+        let dec: Result<Vec<u64>, _> = MsgpackSerdeMaxLenCodec::<1024>::decode(&payload);
+        assert!(matches!(
+            dec,
+            Err(MsgpackMaxLenDecodeError::TooLong {
+                len: 0xffff_ffff,
+                max: 1024
+            })
+        ));
+
+        let dec: Result<Vec<u64>, _> = MsgpackSerdeMaxLenCodec::<{ usize::MAX }>::decode(&payload);
+        assert!(matches!(dec, Err(MsgpackMaxLenDecodeError::UnexpectedEnd)));
+    }
+}
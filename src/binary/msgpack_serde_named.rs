@@ -0,0 +1,112 @@
+use crate::{Decoder, Encoder};
+
+/// Like [`MsgpackSerdeCodec`](crate::binary::MsgpackSerdeCodec) but encodes structs as maps with
+/// the field names as keys instead of as arrays of the field values.
+///
+/// The output is larger because every field name is stored, but it stays decodable when fields
+/// are reordered or added (with `#[serde(default)]`), which makes it more suitable for data that
+/// outlives the current version of a struct. Decoding accepts both representations.
+///
+/// This is only available with the **`msgpack_serde` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::MsgpackNamedSerdeCodec;
+/// # use serde::{Deserialize, Serialize};
+/// #
+/// #[derive(Serialize, Deserialize)]
+/// struct SettingsV1 {
+///     volume: u8,
+/// }
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct SettingsV2 {
+///     #[serde(default)]
+///     muted: bool,
+///     volume: u8,
+/// }
+///
+/// let encoded = MsgpackNamedSerdeCodec::encode(&SettingsV1 { volume: 11 }).unwrap();
+/// let decoded: SettingsV2 = MsgpackNamedSerdeCodec::decode(&encoded).unwrap();
+///
+/// assert_eq!(decoded.volume, 11);
+/// assert!(!decoded.muted);
+/// ```
+pub struct MsgpackNamedSerdeCodec;
+
+impl<T: serde::Serialize> Encoder<T> for MsgpackNamedSerdeCodec {
+    type Error = rmp_serde::encode::Error;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        rmp_serde::to_vec_named(val)
+    }
+}
+
+impl<T: serde::de::DeserializeOwned> Decoder<T> for MsgpackNamedSerdeCodec {
+    type Error = rmp_serde::decode::Error;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        rmp_serde::from_slice(val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary::MsgpackSerdeCodec;
+
+    #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct TestV1 {
+        s: String,
+        i: i32,
+    }
+
+    #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct TestV2 {
+        #[serde(default)]
+        extra: Option<String>,
+        s: String,
+        i: i32,
+    }
+
+    #[test]
+    fn test_msgpack_named_codec() {
+        let t = TestV1 {
+            s: String::from("party time 🎉"),
+            i: 42,
+        };
+        let enc = MsgpackNamedSerdeCodec::encode(&t).unwrap();
+        assert!(enc.len() > MsgpackSerdeCodec::encode(&t).unwrap().len());
+
+        let dec: TestV1 = MsgpackNamedSerdeCodec::decode(&enc).unwrap();
+        assert_eq!(dec, t);
+    }
+
+    #[test]
+    fn test_msgpack_named_codec_added_field() {
+        let t = TestV1 {
+            s: String::from("party time 🎉"),
+            i: 42,
+        };
+
+        let enc = MsgpackNamedSerdeCodec::encode(&t).unwrap();
+        let dec: TestV2 = MsgpackNamedSerdeCodec::decode(&enc).unwrap();
+        assert_eq!(
+            dec,
+            TestV2 {
+                extra: None,
+                s: t.s.clone(),
+                i: t.i,
+            }
+        );
+
+        // the compact representation relies on the field order
+        let enc = MsgpackSerdeCodec::encode(&t).unwrap();
+        let dec: Result<TestV2, _> = MsgpackSerdeCodec::decode(&enc);
+        assert!(dec.is_err());
+    }
+}
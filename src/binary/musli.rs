@@ -0,0 +1,202 @@
+use crate::{Decoder, Encoder};
+use musli::alloc::Global;
+use musli::mode::Binary;
+use musli::{Decode, Encode};
+
+/// A binary codec that uses the [`musli`] storage format.
+///
+/// This is the most compact of the musli codecs. Fields are stored in order without any names
+/// or type information. Fields that are added at the end with `#[musli(default)]` can still be
+/// decoded from old data but data with unknown fields can't be decoded. Use it for data that
+/// is only ever read by the same version of your types. If you need something that can evolve
+/// use [`MusliDescriptiveCodec`] instead.
+///
+/// Types have to implement musli's own [`Encode`] and [`Decode`] traits (not serde's).
+///
+/// Only available with the **`musli` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::MusliStorageCodec;
+/// # use musli::{Decode, Encode};
+/// #
+/// #[derive(Encode, Decode, PartialEq, Debug)]
+/// struct MyState {
+///     chicken_count: u32,
+///     farm_name: String,
+/// }
+///
+/// let original_value = MyState {
+///     chicken_count: 10,
+///     farm_name: "My Farm".to_owned(),
+/// };
+///
+/// let encoded = MusliStorageCodec::encode(&original_value).unwrap();
+/// let decoded: MyState = MusliStorageCodec::decode(&encoded).unwrap();
+///
+/// assert_eq!(decoded, original_value);
+/// ```
+pub struct MusliStorageCodec;
+
+impl<T: Encode<Binary>> Encoder<T> for MusliStorageCodec {
+    type Error = musli::storage::Error;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        musli::storage::to_vec(val)
+    }
+}
+
+impl<T> Decoder<T> for MusliStorageCodec
+where
+    T: for<'de> Decode<'de, Binary, Global>,
+{
+    type Error = musli::storage::Error;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        musli::storage::from_slice(val)
+    }
+}
+
+/// A binary codec that uses the self-describing [`musli`] descriptive format.
+///
+/// Every value carries its type information so decoders can skip fields they don't know.
+/// This makes the output larger than [`MusliStorageCodec`] but data stays decodable when fields
+/// are added (with `#[musli(default)]`) or removed. Use it for data that is exchanged between
+/// different versions of your app.
+///
+/// Types have to implement musli's own [`Encode`] and [`Decode`] traits (not serde's).
+///
+/// Only available with the **`musli` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::MusliDescriptiveCodec;
+/// # use musli::{Decode, Encode};
+/// #
+/// #[derive(Encode, Decode, PartialEq, Debug)]
+/// struct MyState {
+///     chicken_count: u32,
+///     farm_name: String,
+/// }
+///
+/// let original_value = MyState {
+///     chicken_count: 10,
+///     farm_name: "My Farm".to_owned(),
+/// };
+///
+/// let encoded = MusliDescriptiveCodec::encode(&original_value).unwrap();
+/// let decoded: MyState = MusliDescriptiveCodec::decode(&encoded).unwrap();
+///
+/// assert_eq!(decoded, original_value);
+/// ```
+pub struct MusliDescriptiveCodec;
+
+impl<T: Encode<Binary>> Encoder<T> for MusliDescriptiveCodec {
+    type Error = musli::descriptive::Error;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        musli::descriptive::to_vec(val)
+    }
+}
+
+impl<T> Decoder<T> for MusliDescriptiveCodec
+where
+    T: for<'de> Decode<'de, Binary, Global>,
+{
+    type Error = musli::descriptive::Error;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        musli::descriptive::from_slice(val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq, Encode, Decode)]
+    struct Test {
+        s: String,
+        i: i32,
+    }
+
+    #[test]
+    fn test_musli_storage_codec() {
+        let t = Test {
+            s: String::from("party time 🎉"),
+            i: 42,
+        };
+        let enc = MusliStorageCodec::encode(&t).unwrap();
+        let dec: Test = MusliStorageCodec::decode(&enc).unwrap();
+        assert_eq!(dec, t);
+    }
+
+    #[test]
+    fn test_musli_descriptive_codec() {
+        let t = Test {
+            s: String::from("party time 🎉"),
+            i: 42,
+        };
+        let enc = MusliDescriptiveCodec::encode(&t).unwrap();
+        let dec: Test = MusliDescriptiveCodec::decode(&enc).unwrap();
+        assert_eq!(dec, t);
+
+        assert!(enc.len() > MusliStorageCodec::encode(&t).unwrap().len());
+    }
+
+    #[test]
+    fn test_musli_codecs_added_and_removed_fields() {
+        #[derive(Debug, PartialEq, Encode, Decode)]
+        struct V1 {
+            volume: u8,
+        }
+
+        #[derive(Debug, PartialEq, Encode, Decode)]
+        struct V2 {
+            volume: u8,
+            #[musli(default)]
+            muted: bool,
+        }
+
+        let v1 = V1 { volume: 11 };
+        let v2 = V2 {
+            volume: 11,
+            muted: true,
+        };
+
+        // both can decode data with missing default fields
+        let dec: V2 = MusliStorageCodec::decode(&MusliStorageCodec::encode(&v1).unwrap()).unwrap();
+        assert_eq!(
+            dec,
+            V2 {
+                volume: 11,
+                muted: false
+            }
+        );
+        let dec: V2 =
+            MusliDescriptiveCodec::decode(&MusliDescriptiveCodec::encode(&v1).unwrap()).unwrap();
+        assert_eq!(
+            dec,
+            V2 {
+                volume: 11,
+                muted: false
+            }
+        );
+
+        // only the descriptive format can skip unknown fields
+        let dec: V1 =
+            MusliDescriptiveCodec::decode(&MusliDescriptiveCodec::encode(&v2).unwrap()).unwrap();
+        assert_eq!(dec, v1);
+        let dec: Result<V1, _> =
+            MusliStorageCodec::decode(&MusliStorageCodec::encode(&v2).unwrap());
+        assert!(dec.is_err());
+    }
+}
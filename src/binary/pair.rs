@@ -0,0 +1,166 @@
+use super::length_prefixed::split_frame;
+use crate::{Decoder, Encoder};
+use alloc::vec::Vec;
+use thiserror::Error;
+
+/// Encoded types that [`PairCodec`] can borrow from a slice of bytes. Implemented for `[u8]` and
+/// `str` so both binary and string codecs can be used for the elements.
+pub trait FromFrame {
+    /// Returns `None` if `bytes` isn't a valid value of this type.
+    fn from_frame(bytes: &[u8]) -> Option<&Self>;
+}
+
+impl FromFrame for [u8] {
+    fn from_frame(bytes: &[u8]) -> Option<&Self> {
+        Some(bytes)
+    }
+}
+
+impl FromFrame for str {
+    fn from_frame(bytes: &[u8]) -> Option<&Self> {
+        core::str::from_utf8(bytes).ok()
+    }
+}
+
+/// A binary codec that encodes a pair `(A, B)` with a different codec for each element.
+///
+/// The output of `CA` is prefixed with its length as a `u32` in big-endian byte order, followed
+/// by the output of `CB`. The inner codecs can be binary or string codecs, so you can for example
+/// combine a compact binary codec with a human-readable string codec.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::{FromToBytesCodec, PairCodec};
+/// # use codee::string::FromToStringCodec;
+/// #
+/// type Codec = PairCodec<FromToBytesCodec, FromToStringCodec>;
+///
+/// let encoded = Codec::encode(&(42_u16, 3.5_f64)).unwrap();
+/// assert_eq!(encoded, b"\0\0\0\x02\0\x2a3.5");
+///
+/// let decoded: (u16, f64) = Codec::decode(&encoded).unwrap();
+/// assert_eq!(decoded, (42, 3.5));
+/// ```
+pub struct PairCodec<CA, CB>(CA, CB);
+
+#[derive(Error, Debug, PartialEq)]
+pub enum PairEncodeError<ErrA, ErrB> {
+    #[error("encoded first element of {0} bytes is too long for a u32 length prefix")]
+    TooLong(usize),
+    #[error("failed to encode first element: {0}")]
+    First(ErrA),
+    #[error("failed to encode second element: {0}")]
+    Second(ErrB),
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum PairDecodeError<ErrA, ErrB> {
+    #[error("unexpected end of input")]
+    UnexpectedEnd,
+    #[error("element is not valid for the inner codec's encoded type")]
+    InvalidFrame,
+    #[error("failed to decode first element: {0}")]
+    First(ErrA),
+    #[error("failed to decode second element: {0}")]
+    Second(ErrB),
+}
+
+impl<A, B, CA, CB> Encoder<(A, B)> for PairCodec<CA, CB>
+where
+    CA: Encoder<A>,
+    CA::Encoded: AsRef<[u8]>,
+    CB: Encoder<B>,
+    CB::Encoded: AsRef<[u8]>,
+{
+    type Error = PairEncodeError<CA::Error, CB::Error>;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &(A, B)) -> Result<Self::Encoded, Self::Error> {
+        let first = CA::encode(&val.0).map_err(PairEncodeError::First)?;
+        let first = first.as_ref();
+        let second = CB::encode(&val.1).map_err(PairEncodeError::Second)?;
+        let second = second.as_ref();
+
+        let len = u32::try_from(first.len()).map_err(|_| PairEncodeError::TooLong(first.len()))?;
+
+        let mut buf = Vec::with_capacity(4 + first.len() + second.len());
+        buf.extend_from_slice(&len.to_be_bytes());
+        buf.extend_from_slice(first);
+        buf.extend_from_slice(second);
+
+        Ok(buf)
+    }
+}
+
+impl<A, B, CA, CB> Decoder<(A, B)> for PairCodec<CA, CB>
+where
+    CA: Decoder<A>,
+    CA::Encoded: FromFrame,
+    CB: Decoder<B>,
+    CB::Encoded: FromFrame,
+{
+    type Error = PairDecodeError<CA::Error, CB::Error>;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<(A, B), Self::Error> {
+        let (first, second) = split_frame(val).ok_or(PairDecodeError::UnexpectedEnd)?;
+
+        let first = CA::Encoded::from_frame(first).ok_or(PairDecodeError::InvalidFrame)?;
+        let second = CB::Encoded::from_frame(second).ok_or(PairDecodeError::InvalidFrame)?;
+
+        Ok((
+            CA::decode(first).map_err(PairDecodeError::First)?,
+            CB::decode(second).map_err(PairDecodeError::Second)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary::FromToBytesCodec;
+    use crate::string::FromToStringCodec;
+
+    #[test]
+    fn test_pair_codec() {
+        type Codec = PairCodec<FromToBytesCodec, FromToStringCodec>;
+
+        let pair = (String::from("party time 🎉"), -42_i64);
+
+        let enc = Codec::encode(&pair).unwrap();
+        let dec: (String, i64) = Codec::decode(&enc).unwrap();
+        assert_eq!(dec, pair);
+    }
+
+    #[test]
+    fn test_pair_codec_frame_boundaries() {
+        type Codec = PairCodec<FromToBytesCodec, FromToBytesCodec>;
+
+        for first in ["", "a", "abc", "a longer first element"] {
+            let pair = (first.to_owned(), String::from("second"));
+
+            let enc = Codec::encode(&pair).unwrap();
+            assert_eq!(&enc[..4], &(first.len() as u32).to_be_bytes());
+            assert_eq!(&enc[4 + first.len()..], b"second");
+
+            let dec: (String, String) = Codec::decode(&enc).unwrap();
+            assert_eq!(dec, pair);
+        }
+    }
+
+    #[test]
+    fn test_pair_codec_errors() {
+        type Codec = PairCodec<FromToBytesCodec, FromToStringCodec>;
+
+        let dec: Result<(String, i32), _> = Codec::decode(b"\0\0\0\x05abc");
+        assert!(matches!(dec, Err(PairDecodeError::UnexpectedEnd)));
+
+        let dec: Result<(String, i32), _> = Codec::decode(b"\0\0\0\x01a\xff");
+        assert!(matches!(dec, Err(PairDecodeError::InvalidFrame)));
+
+        let dec: Result<(String, i32), _> = Codec::decode(b"\0\0\0\x01ax");
+        assert!(matches!(dec, Err(PairDecodeError::Second(_))));
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{Decoder, Encoder};
+use alloc::vec::Vec;
+
+/// A codec that relies on `postcard` and `serde` to encode data in the postcard format.
+///
+/// This is only available with the **`postcard_serde` feature** enabled.
+pub struct PostcardSerdeCodec;
+
+impl<T: serde::Serialize> Encoder<T> for PostcardSerdeCodec {
+    type Error = postcard::Error;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        postcard::to_allocvec(val)
+    }
+}
+
+impl<T: serde::de::DeserializeOwned> Decoder<T> for PostcardSerdeCodec {
+    type Error = postcard::Error;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        postcard::from_bytes(val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_postcard_codec() {
+        #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Test {
+            s: String,
+            i: i32,
+        }
+        let t = Test {
+            s: String::from("party time 🎉"),
+            i: 42,
+        };
+        let enc = PostcardSerdeCodec::encode(&t).unwrap();
+        let dec: Test = PostcardSerdeCodec::decode(&enc).unwrap();
+        assert_eq!(dec, t);
+    }
+}
@@ -0,0 +1,147 @@
+use crate::{Decoder, Encoder};
+use alloc::vec::Vec;
+use thiserror::Error;
+
+/// Implement this for types that are encoded with [`PostcardVersionedCodec`].
+///
+/// `SCHEMA_HASH` identifies the layout of the type. Change it whenever the serialized layout of
+/// the type changes, for example by hashing a description of the fields or simply by bumping a
+/// version number.
+pub trait PostcardSchemaHash {
+    const SCHEMA_HASH: u64;
+}
+
+/// Like [`PostcardSerdeCodec`](crate::binary::PostcardSerdeCodec) but prepends the
+/// [`PostcardSchemaHash::SCHEMA_HASH`] of the type and verifies it when decoding.
+///
+/// postcard is not self-describing so decoding data that was written with a different version of
+/// a type silently produces garbage or fails with an unhelpful error. This codec returns
+/// [`PostcardVersionedDecodeError::SchemaMismatch`] instead which makes postcard safer for
+/// persisted data. The hash is stored as 8 bytes in little-endian byte order.
+///
+/// This is only available with the **`postcard_serde` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::{PostcardSchemaHash, PostcardVersionedCodec};
+/// # use serde::{Deserialize, Serialize};
+/// #
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Settings {
+///     volume: u8,
+/// }
+///
+/// impl PostcardSchemaHash for Settings {
+///     const SCHEMA_HASH: u64 = 1;
+/// }
+///
+/// let settings = Settings { volume: 11 };
+///
+/// let encoded = PostcardVersionedCodec::encode(&settings).unwrap();
+/// let decoded: Settings = PostcardVersionedCodec::decode(&encoded).unwrap();
+///
+/// assert_eq!(decoded, settings);
+/// ```
+pub struct PostcardVersionedCodec;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum PostcardVersionedDecodeError {
+    #[error("unexpected end of input")]
+    UnexpectedEnd,
+    #[error("schema hash mismatch: expected {expected:#018x} but found {found:#018x}")]
+    SchemaMismatch { expected: u64, found: u64 },
+    #[error("failed to decode: {0}")]
+    Decoder(#[from] postcard::Error),
+}
+
+impl<T: serde::Serialize + PostcardSchemaHash> Encoder<T> for PostcardVersionedCodec {
+    type Error = postcard::Error;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        postcard::to_extend(val, T::SCHEMA_HASH.to_le_bytes().to_vec())
+    }
+}
+
+impl<T: serde::de::DeserializeOwned + PostcardSchemaHash> Decoder<T> for PostcardVersionedCodec {
+    type Error = PostcardVersionedDecodeError;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        if val.len() < 8 {
+            return Err(PostcardVersionedDecodeError::UnexpectedEnd);
+        }
+        let (hash, payload) = val.split_at(8);
+
+        let found = u64::from_le_bytes(hash.try_into().unwrap());
+        if found != T::SCHEMA_HASH {
+            return Err(PostcardVersionedDecodeError::SchemaMismatch {
+                expected: T::SCHEMA_HASH,
+                found,
+            });
+        }
+
+        Ok(postcard::from_bytes(payload)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct TestV1 {
+        s: String,
+        i: i32,
+    }
+
+    impl PostcardSchemaHash for TestV1 {
+        const SCHEMA_HASH: u64 = 0x1234_5678_9abc_def0;
+    }
+
+    #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct TestV2 {
+        s: String,
+        i: i64,
+    }
+
+    impl PostcardSchemaHash for TestV2 {
+        const SCHEMA_HASH: u64 = 0x0fed_cba9_8765_4321;
+    }
+
+    #[test]
+    fn test_postcard_versioned_codec() {
+        let t = TestV1 {
+            s: String::from("party time 🎉"),
+            i: 42,
+        };
+        let enc = PostcardVersionedCodec::encode(&t).unwrap();
+        assert_eq!(enc[..8], TestV1::SCHEMA_HASH.to_le_bytes());
+
+        let dec: TestV1 = PostcardVersionedCodec::decode(&enc).unwrap();
+        assert_eq!(dec, t);
+    }
+
+    #[test]
+    fn test_postcard_versioned_codec_schema_mismatch() {
+        let t = TestV1 {
+            s: String::from("party time 🎉"),
+            i: 42,
+        };
+        let enc = PostcardVersionedCodec::encode(&t).unwrap();
+
+        let dec: Result<TestV2, _> = PostcardVersionedCodec::decode(&enc);
+        assert_eq!(
+            dec,
+            Err(PostcardVersionedDecodeError::SchemaMismatch {
+                expected: TestV2::SCHEMA_HASH,
+                found: TestV1::SCHEMA_HASH,
+            })
+        );
+
+        let dec: Result<TestV1, _> = PostcardVersionedCodec::decode(&enc[..7]);
+        assert_eq!(dec, Err(PostcardVersionedDecodeError::UnexpectedEnd));
+    }
+}
@@ -0,0 +1,108 @@
+use crate::{Decoder, Encoder};
+
+/// A binary codec that uses [`pot`], a self-describing binary format designed for long-term
+/// storage.
+///
+/// Unlike [`BincodeSerdeCodec`](crate::binary::BincodeSerdeCodec) pot stores field names (only
+/// once per message, so the overhead stays small). This means stored data stays decodable
+/// when fields are added (with `#[serde(default)]`), removed or reordered, which makes it a
+/// good fit for data that has to survive changes to your types. See the
+/// [versioning section](crate#versioning) for more.
+///
+/// Only available with the **`pot` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::PotCodec;
+/// # use serde::{Deserialize, Serialize};
+/// #
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct MyState {
+///     chicken_count: u32,
+///     farm_name: String,
+/// }
+///
+/// let original_value = MyState {
+///     chicken_count: 10,
+///     farm_name: "My Farm".to_owned(),
+/// };
+///
+/// let encoded = PotCodec::encode(&original_value).unwrap();
+/// let decoded: MyState = PotCodec::decode(&encoded).unwrap();
+///
+/// assert_eq!(decoded, original_value);
+/// ```
+pub struct PotCodec;
+
+impl<T: serde::Serialize> Encoder<T> for PotCodec {
+    type Error = pot::Error;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        pot::to_vec(val)
+    }
+}
+
+impl<T: serde::de::DeserializeOwned> Decoder<T> for PotCodec {
+    type Error = pot::Error;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        pot::from_slice(val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[test]
+    fn test_pot_codec() {
+        #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+        struct Test {
+            s: String,
+            i: i32,
+        }
+        let t = Test {
+            s: String::from("party time 🎉"),
+            i: 42,
+        };
+        let enc = PotCodec::encode(&t).unwrap();
+        let dec: Test = PotCodec::decode(&enc).unwrap();
+        assert_eq!(dec, t);
+    }
+
+    #[test]
+    fn test_pot_codec_added_and_removed_fields() {
+        #[derive(Debug, Serialize, Deserialize)]
+        struct V1 {
+            removed: String,
+            volume: u8,
+        }
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct V2 {
+            #[serde(default)]
+            muted: bool,
+            volume: u8,
+        }
+
+        let enc = PotCodec::encode(&V1 {
+            removed: "gone".to_owned(),
+            volume: 11,
+        })
+        .unwrap();
+
+        let dec: V2 = PotCodec::decode(&enc).unwrap();
+        assert_eq!(
+            dec,
+            V2 {
+                muted: false,
+                volume: 11
+            }
+        );
+    }
+}
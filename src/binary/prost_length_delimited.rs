@@ -0,0 +1,124 @@
+use crate::{Decoder, Encoder};
+
+/// Like [`ProstCodec`](crate::binary::ProstCodec) but uses the length-delimited wire form of
+/// protobuf where the message is prefixed by its length as a varint.
+///
+/// This is the form that gRPC and many protobuf tools expect and makes it possible to store
+/// several messages in one buffer one after the other. Use
+/// [`ProstLengthDelimitedCodec::decode_frame`] to decode the message at the front of a buffer
+/// together with the number of bytes it occupies.
+///
+/// The [`Decoder`] implementation decodes the first message and ignores anything after it.
+///
+/// Only available with the **`prost` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::ProstLengthDelimitedCodec;
+/// #
+/// #[derive(Clone, PartialEq, prost::Message)]
+/// pub struct Event {
+///     #[prost(string, tag = "1")]
+///     pub name: String,
+/// }
+///
+/// let first = Event { name: "open".to_owned() };
+/// let second = Event { name: "close".to_owned() };
+///
+/// let mut buf = ProstLengthDelimitedCodec::encode(&first).unwrap();
+/// buf.extend(ProstLengthDelimitedCodec::encode(&second).unwrap());
+///
+/// let (decoded, consumed) = ProstLengthDelimitedCodec::decode_frame::<Event>(&buf).unwrap();
+/// assert_eq!(decoded, first);
+///
+/// let decoded: Event = ProstLengthDelimitedCodec::decode(&buf[consumed..]).unwrap();
+/// assert_eq!(decoded, second);
+/// ```
+pub struct ProstLengthDelimitedCodec;
+
+impl ProstLengthDelimitedCodec {
+    /// Decodes the message at the front of `val` and returns it together with the number of bytes
+    /// consumed (including the length delimiter).
+    pub fn decode_frame<T: prost::Message + Default>(
+        val: &[u8],
+    ) -> Result<(T, usize), prost::DecodeError> {
+        let mut buf = val;
+        let len = prost::decode_length_delimiter(&mut buf)?;
+        let consumed = val.len() - buf.len() + len;
+
+        let msg = T::decode_length_delimited(val)?;
+
+        Ok((msg, consumed))
+    }
+}
+
+impl<T: prost::Message> Encoder<T> for ProstLengthDelimitedCodec {
+    type Error = ();
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        Ok(val.encode_length_delimited_to_vec())
+    }
+}
+
+impl<T: prost::Message + Default> Decoder<T> for ProstLengthDelimitedCodec {
+    type Error = prost::DecodeError;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        T::decode_length_delimited(val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct Test {
+        #[prost(string, tag = "1")]
+        s: String,
+        #[prost(int32, tag = "2")]
+        i: i32,
+    }
+
+    #[test]
+    fn test_prost_length_delimited_codec() {
+        let t = Test {
+            s: String::from("party time 🎉"),
+            i: 42,
+        };
+        let enc = ProstLengthDelimitedCodec::encode(&t).unwrap();
+        assert_eq!(enc[0] as usize, enc.len() - 1);
+        assert_eq!(ProstLengthDelimitedCodec::decode(&enc), Ok(t));
+    }
+
+    #[test]
+    fn test_prost_length_delimited_codec_two_messages() {
+        let first = Test {
+            s: String::from("first"),
+            i: 1,
+        };
+        let second = Test {
+            s: String::from("second"),
+            i: -2,
+        };
+
+        let mut buf = ProstLengthDelimitedCodec::encode(&first).unwrap();
+        let first_len = buf.len();
+        buf.extend(ProstLengthDelimitedCodec::encode(&second).unwrap());
+
+        let (dec, consumed) = ProstLengthDelimitedCodec::decode_frame::<Test>(&buf).unwrap();
+        assert_eq!(dec, first);
+        assert_eq!(consumed, first_len);
+
+        let (dec, consumed) =
+            ProstLengthDelimitedCodec::decode_frame::<Test>(&buf[first_len..]).unwrap();
+        assert_eq!(dec, second);
+        assert_eq!(first_len + consumed, buf.len());
+
+        assert!(ProstLengthDelimitedCodec::decode_frame::<Test>(&buf[..first_len - 1]).is_err());
+    }
+}
@@ -0,0 +1,123 @@
+use crate::binary::ProstCodec;
+use ::prost_reflect::{DescriptorError, DescriptorPool, DynamicMessage};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ProstValidationError {
+    #[error("invalid file descriptor set: {0}")]
+    InvalidDescriptorSet(#[from] DescriptorError),
+    #[error("message `{0}` not found in the descriptor set")]
+    UnknownMessage(String),
+    #[error("bytes don't match the message descriptor: {0}")]
+    InvalidMessage(#[from] prost::DecodeError),
+}
+
+impl ProstCodec {
+    /// Checks that `bytes` are a valid encoding of the message `message_name` (fully qualified,
+    /// e.g. `my.package.MyMessage`) as described by the encoded protobuf `FileDescriptorSet`
+    /// `descriptor_set`.
+    ///
+    /// This doesn't require a Rust type for the message which makes it possible to validate
+    /// messages against a schema that is only known at runtime.
+    ///
+    /// Only available with the **`prost_reflect` feature** enabled.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use codee::binary::{ProstCodec, ProstValidationError};
+    /// #
+    /// # fn check(descriptor_set: &[u8], bytes: &[u8]) -> Result<(), ProstValidationError> {
+    /// // `descriptor_set` can be generated with `protoc --descriptor_set_out`
+    /// ProstCodec::validate_against(descriptor_set, "my.package.MyMessage", bytes)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn validate_against(
+        descriptor_set: &[u8],
+        message_name: &str,
+        bytes: &[u8],
+    ) -> Result<(), ProstValidationError> {
+        let pool = DescriptorPool::decode(descriptor_set)?;
+        let descriptor = pool
+            .get_message_by_name(message_name)
+            .ok_or_else(|| ProstValidationError::UnknownMessage(message_name.to_owned()))?;
+
+        DynamicMessage::decode(descriptor, bytes)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Encoder;
+    use ::prost_reflect::prost::Message;
+    use ::prost_reflect::prost_types::{
+        field_descriptor_proto::{Label, Type},
+        DescriptorProto, FieldDescriptorProto, FileDescriptorProto, FileDescriptorSet,
+    };
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct Test {
+        #[prost(string, tag = "1")]
+        s: String,
+        #[prost(int32, tag = "2")]
+        i: i32,
+    }
+
+    fn descriptor_set() -> Vec<u8> {
+        let field = |name: &str, number, ty: Type| FieldDescriptorProto {
+            name: Some(name.to_owned()),
+            number: Some(number),
+            label: Some(Label::Optional as i32),
+            r#type: Some(ty as i32),
+            ..Default::default()
+        };
+
+        FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                name: Some("test.proto".to_owned()),
+                package: Some("test".to_owned()),
+                message_type: vec![DescriptorProto {
+                    name: Some("Test".to_owned()),
+                    field: vec![field("s", 1, Type::String), field("i", 2, Type::Int32)],
+                    ..Default::default()
+                }],
+                syntax: Some("proto3".to_owned()),
+                ..Default::default()
+            }],
+        }
+        .encode_to_vec()
+    }
+
+    #[test]
+    fn test_validate_against() {
+        let descriptor_set = descriptor_set();
+
+        let valid = ProstCodec::encode(&Test {
+            s: String::from("party time 🎉"),
+            i: 42,
+        })
+        .unwrap();
+        assert!(ProstCodec::validate_against(&descriptor_set, "test.Test", &valid).is_ok());
+
+        // field 1 (string) encoded with the varint wire type
+        let invalid = [0x08, 0x2a];
+        assert!(matches!(
+            ProstCodec::validate_against(&descriptor_set, "test.Test", &invalid),
+            Err(ProstValidationError::InvalidMessage(_))
+        ));
+
+        assert!(matches!(
+            ProstCodec::validate_against(&descriptor_set, "test.Unknown", &valid),
+            Err(ProstValidationError::UnknownMessage(name)) if name == "test.Unknown"
+        ));
+
+        assert!(matches!(
+            ProstCodec::validate_against(&[0xff], "test.Test", &valid),
+            Err(ProstValidationError::InvalidDescriptorSet(_))
+        ));
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{Decoder, Encoder};
+
+/// A codec for storing ProtoBuf messages that relies on
+/// [`rust-protobuf`](https://github.com/stepancheg/rust-protobuf) to parse.
+///
+/// This is the counterpart of [`ProstCodec`](crate::binary::ProstCodec) for types generated by
+/// `rust-protobuf` (the [`protobuf`] crate) with
+/// [`protobuf-codegen`](https://docs.rs/protobuf-codegen). Both produce the same wire format so
+/// data can be exchanged between the two.
+///
+/// This is only available with the **`protobuf` feature** enabled.
+///
+/// ## Example
+/// ```ignore
+/// # use leptos::*;
+/// # use leptos_use::storage::use_local_storage;
+/// # use codee::{string::Base64, binary::ProtobufCodec};
+/// #
+/// # #[component]
+/// # pub fn Demo() -> impl IntoView {
+/// // `MyState` is generated from a .proto file by `protobuf-codegen`
+/// let (get, set, remove) = use_local_storage::<MyState, Base64<ProtobufCodec>>("my-struct-key");
+/// #    view! { }
+/// # }
+/// ```
+pub struct ProtobufCodec;
+
+impl<T: protobuf::Message> Encoder<T> for ProtobufCodec {
+    type Error = protobuf::Error;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        val.write_to_bytes()
+    }
+}
+
+impl<T: protobuf::Message> Decoder<T> for ProtobufCodec {
+    type Error = protobuf::Error;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        T::parse_from_bytes(val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use protobuf::{CodedInputStream, CodedOutputStream, Message, SpecialFields};
+
+    /// What `protobuf-codegen` generates for
+    /// `message Test { string s = 1; int32 i = 2; }`
+    #[derive(Clone, Debug, Default, PartialEq)]
+    struct Test {
+        s: String,
+        i: i32,
+        special_fields: SpecialFields,
+    }
+
+    impl Message for Test {
+        const NAME: &'static str = "Test";
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+
+        fn merge_from(&mut self, is: &mut CodedInputStream<'_>) -> protobuf::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    10 => self.s = is.read_string()?,
+                    16 => self.i = is.read_int32()?,
+                    tag => protobuf::rt::read_unknown_or_skip_group(
+                        tag,
+                        is,
+                        self.special_fields.mut_unknown_fields(),
+                    )?,
+                }
+            }
+            Ok(())
+        }
+
+        fn compute_size(&self) -> u64 {
+            let mut size = 0;
+            if !self.s.is_empty() {
+                size += protobuf::rt::string_size(1, &self.s);
+            }
+            if self.i != 0 {
+                size += protobuf::rt::int32_size(2, self.i);
+            }
+            size += protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(size as u32);
+            size
+        }
+
+        fn write_to_with_cached_sizes(
+            &self,
+            os: &mut CodedOutputStream<'_>,
+        ) -> protobuf::Result<()> {
+            if !self.s.is_empty() {
+                os.write_string(1, &self.s)?;
+            }
+            if self.i != 0 {
+                os.write_int32(2, self.i)?;
+            }
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            Ok(())
+        }
+
+        fn special_fields(&self) -> &SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> Self {
+            Self::default()
+        }
+
+        fn default_instance() -> &'static Self {
+            static INSTANCE: Test = Test {
+                s: String::new(),
+                i: 0,
+                special_fields: SpecialFields::new(),
+            };
+            &INSTANCE
+        }
+    }
+
+    #[test]
+    fn test_protobuf_codec() {
+        let t = Test {
+            s: String::from("party time 🎉"),
+            i: 42,
+            ..Default::default()
+        };
+
+        let enc = ProtobufCodec::encode(&t).unwrap();
+        let dec: Test = ProtobufCodec::decode(&enc).unwrap();
+        assert_eq!(dec, t);
+
+        assert!(<ProtobufCodec as Decoder<Test>>::decode(&[0x0a, 0xff]).is_err());
+    }
+
+    #[cfg(feature = "prost")]
+    #[test]
+    fn test_protobuf_codec_prost_compatible() {
+        use crate::binary::ProstCodec;
+
+        #[derive(Clone, PartialEq, prost::Message)]
+        struct ProstTest {
+            #[prost(string, tag = "1")]
+            s: String,
+            #[prost(int32, tag = "2")]
+            i: i32,
+        }
+        let t = ProstTest {
+            s: String::from("party time 🎉"),
+            i: 42,
+        };
+
+        let dec: Test = ProtobufCodec::decode(&ProstCodec::encode(&t).unwrap()).unwrap();
+        assert_eq!(dec.s, t.s);
+        assert_eq!(dec.i, t.i);
+    }
+}
@@ -1,16 +1,48 @@
 use crate::{Decoder, Encoder};
-use rkyv::de::deserializers::SharedDeserializeMap;
+use rkyv::de::deserializers::{SharedDeserializeMap, SharedDeserializeMapError};
 use rkyv::ser::serializers::AllocSerializer;
-use rkyv::validation::validators::DefaultValidator;
+use rkyv::validation::validators::{
+    CheckDeserializeError, DefaultValidator, DefaultValidatorError,
+};
+use rkyv::validation::CheckArchiveError;
 use rkyv::{Archive, CheckBytes, Deserialize, Fallible, Serialize};
-use std::error::Error;
-use std::sync::Arc;
+use thiserror::Error;
 
 /// A codec that relies on `rkyv` to encode data in the msgpack format.
 ///
 /// This is only available with the **`rkyv` feature** enabled.
 pub struct RkyvCodec;
 
+/// Error returned by the [`Decoder`] implementation of [`RkyvCodec`]. `E` is the
+/// [`CheckBytes`] error of the archived type.
+#[derive(Error, Debug)]
+pub enum RkyvDecodeError<E> {
+    #[error("failed to validate archived value: {0}")]
+    CheckBytes(E),
+    #[error("failed to validate archive: {0}")]
+    Validator(DefaultValidatorError),
+    #[error("failed to deserialize: {0}")]
+    Deserialize(SharedDeserializeMapError),
+}
+
+/// Error returned by [`rkyv::from_bytes`]
+type FromBytesError<E> =
+    CheckDeserializeError<CheckArchiveError<E, DefaultValidatorError>, SharedDeserializeMapError>;
+
+impl<E> From<FromBytesError<E>> for RkyvDecodeError<E> {
+    fn from(err: FromBytesError<E>) -> Self {
+        match err {
+            CheckDeserializeError::CheckBytesError(CheckArchiveError::CheckBytesError(err)) => {
+                Self::CheckBytes(err)
+            }
+            CheckDeserializeError::CheckBytesError(CheckArchiveError::ContextError(err)) => {
+                Self::Validator(err)
+            }
+            CheckDeserializeError::DeserializeError(err) => Self::Deserialize(err),
+        }
+    }
+}
+
 impl<T> Encoder<T> for RkyvCodec
 where
     T: Serialize<AllocSerializer<1024>>,
@@ -23,32 +55,35 @@ where
     }
 }
 
-impl<T> Decoder<T> for RkyvCodec
+impl<T, E> Decoder<T> for RkyvCodec
 where
     T: Archive,
     for<'a> T::Archived:
-        'a + CheckBytes<DefaultValidator<'a>> + Deserialize<T, SharedDeserializeMap>,
+        'a + CheckBytes<DefaultValidator<'a>, Error = E> + Deserialize<T, SharedDeserializeMap>,
+    E: 'static,
 {
-    type Error = Arc<dyn Error>;
+    type Error = RkyvDecodeError<E>;
     type Encoded = [u8];
 
     fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
-        rkyv::from_bytes::<T>(val).map_err(|e| Arc::new(e) as Arc<dyn Error>)
+        Ok(rkyv::from_bytes::<T>(val)?)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rkyv::validation::validators::ArchiveError;
+
+    #[derive(Clone, Debug, PartialEq, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)]
+    #[archive(check_bytes)]
+    struct Test {
+        s: String,
+        i: i32,
+    }
 
     #[test]
     fn test_rkyv_codec() {
-        #[derive(Clone, Debug, PartialEq, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)]
-        #[archive(check_bytes)]
-        struct Test {
-            s: String,
-            i: i32,
-        }
         let t = Test {
             s: String::from("party time 🎉"),
             i: 42,
@@ -57,4 +92,21 @@ mod tests {
         let dec: Test = RkyvCodec::decode(&enc).unwrap();
         assert_eq!(dec, t);
     }
+
+    #[test]
+    fn test_rkyv_codec_truncated() {
+        let t = Test {
+            s: String::from("party time 🎉"),
+            i: 42,
+        };
+        let enc = RkyvCodec::encode(&t).unwrap();
+
+        let dec: Result<Test, _> = RkyvCodec::decode(&enc[..2]);
+        assert!(matches!(
+            dec,
+            Err(RkyvDecodeError::Validator(
+                DefaultValidatorError::ArchiveError(ArchiveError::OutOfBounds { .. })
+            ))
+        ));
+    }
 }
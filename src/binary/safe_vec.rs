@@ -0,0 +1,185 @@
+use crate::binary::{
+    LengthPrefixed, LengthPrefixedDecodeError, LengthPrefixedEncodeError, SeqCodec,
+};
+use crate::{Decoder, Encoder};
+use alloc::vec::Vec;
+use thiserror::Error;
+
+/// Like [`SeqCodec`] but with a magic header and the number of elements in front so that
+/// truncated or corrupted data is detected instead of silently decoded into a shorter `Vec`.
+///
+/// The layout is `[magic "CVEC"][u32 count]` followed by `count` elements that are each
+/// prefixed with their length as a `u32`. All integers are in big-endian byte order. Decoding
+/// fails if the magic doesn't match, if fewer than `count` elements are present or if there are
+/// any bytes after the last element.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::{FromToBytesCodec, SafeVecCodec, SafeVecDecodeError};
+/// #
+/// let encoded = SafeVecCodec::<FromToBytesCodec>::encode(&vec![1_u16, 2, 3]).unwrap();
+/// assert_eq!(&encoded[..4], b"CVEC");
+///
+/// let decoded: Vec<u16> = SafeVecCodec::<FromToBytesCodec>::decode(&encoded).unwrap();
+/// assert_eq!(decoded, vec![1, 2, 3]);
+///
+/// let truncated: Result<Vec<u16>, _> =
+///     SafeVecCodec::<FromToBytesCodec>::decode(&encoded[..encoded.len() - 6]);
+/// assert!(matches!(truncated, Err(SafeVecDecodeError::UnexpectedEnd)));
+/// ```
+pub struct SafeVecCodec<C>(C);
+
+impl<C> SafeVecCodec<C> {
+    /// The magic bytes at the start of the encoded data.
+    pub const MAGIC: [u8; 4] = *b"CVEC";
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum SafeVecEncodeError<Err> {
+    #[error("{0} elements are too many for a u32 count")]
+    TooManyElements(usize),
+    #[error("encoded element of {0} bytes is too long for a u32 length prefix")]
+    TooLong(usize),
+    #[error("failed to encode: {0}")]
+    Encoder(Err),
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum SafeVecDecodeError<Err> {
+    #[error("magic bytes don't match")]
+    InvalidMagic,
+    #[error("unexpected end of input")]
+    UnexpectedEnd,
+    #[error("{0} trailing bytes after the last element")]
+    TrailingBytes(usize),
+    #[error("failed to decode: {0}")]
+    Decoder(Err),
+}
+
+impl<Err> From<LengthPrefixedEncodeError<Err>> for SafeVecEncodeError<Err> {
+    fn from(err: LengthPrefixedEncodeError<Err>) -> Self {
+        match err {
+            LengthPrefixedEncodeError::TooLong(len) => Self::TooLong(len),
+            LengthPrefixedEncodeError::Encoder(err) => Self::Encoder(err),
+        }
+    }
+}
+
+impl<T, E> Encoder<Vec<T>> for SafeVecCodec<E>
+where
+    E: Encoder<T, Encoded = Vec<u8>>,
+{
+    type Error = SafeVecEncodeError<E::Error>;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &Vec<T>) -> Result<Self::Encoded, Self::Error> {
+        let count =
+            u32::try_from(val.len()).map_err(|_| SafeVecEncodeError::TooManyElements(val.len()))?;
+
+        let mut buf = Vec::new();
+        buf.extend_from_slice(&Self::MAGIC);
+        buf.extend_from_slice(&count.to_be_bytes());
+        buf.extend(SeqCodec::<E>::encode(val)?);
+
+        Ok(buf)
+    }
+}
+
+impl<T, D> Decoder<Vec<T>> for SafeVecCodec<D>
+where
+    D: Decoder<T, Encoded = [u8]>,
+{
+    type Error = SafeVecDecodeError<D::Error>;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<Vec<T>, Self::Error> {
+        if val.len() < 8 {
+            return Err(SafeVecDecodeError::UnexpectedEnd);
+        }
+        let (header, mut val) = val.split_at(8);
+
+        if header[..4] != Self::MAGIC {
+            return Err(SafeVecDecodeError::InvalidMagic);
+        }
+        let count = u32::from_be_bytes(header[4..].try_into().unwrap());
+
+        let mut items = Vec::new();
+        for _ in 0..count {
+            let (item, consumed) =
+                LengthPrefixed::<D>::decode_frame(val).map_err(|err| match err {
+                    LengthPrefixedDecodeError::Decoder(err) => SafeVecDecodeError::Decoder(err),
+                    _ => SafeVecDecodeError::UnexpectedEnd,
+                })?;
+            items.push(item);
+            val = &val[consumed..];
+        }
+
+        if !val.is_empty() {
+            return Err(SafeVecDecodeError::TrailingBytes(val.len()));
+        }
+
+        Ok(items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary::FromToBytesCodec;
+
+    type Codec = SafeVecCodec<FromToBytesCodec>;
+
+    #[test]
+    fn test_safe_vec_codec() {
+        for v in [
+            vec![],
+            vec![String::from("party time 🎉")],
+            vec![String::from("party"), String::new(), String::from("time")],
+        ] {
+            let enc = Codec::encode(&v).unwrap();
+            assert_eq!(enc[4..8], (v.len() as u32).to_be_bytes());
+
+            let dec: Vec<String> = Codec::decode(&enc).unwrap();
+            assert_eq!(dec, v);
+        }
+    }
+
+    #[test]
+    fn test_safe_vec_codec_truncated() {
+        let enc = Codec::encode(&vec![1_u32, 2]).unwrap();
+
+        // second element cut off completely
+        let dec: Result<Vec<u32>, _> = Codec::decode(&enc[..8 + 8]);
+        assert!(matches!(dec, Err(SafeVecDecodeError::UnexpectedEnd)));
+
+        // second element cut off partially
+        let dec: Result<Vec<u32>, _> = Codec::decode(&enc[..enc.len() - 1]);
+        assert!(matches!(dec, Err(SafeVecDecodeError::UnexpectedEnd)));
+
+        let dec: Result<Vec<u32>, _> = Codec::decode(&enc[..6]);
+        assert!(matches!(dec, Err(SafeVecDecodeError::UnexpectedEnd)));
+    }
+
+    #[test]
+    fn test_safe_vec_codec_corrupted() {
+        let enc = Codec::encode(&vec![1_u32, 2]).unwrap();
+
+        let mut trailing = enc.clone();
+        trailing.extend_from_slice(&[0, 0, 0]);
+        let dec: Result<Vec<u32>, _> = Codec::decode(&trailing);
+        assert!(matches!(dec, Err(SafeVecDecodeError::TrailingBytes(3))));
+
+        let mut magic = enc.clone();
+        magic[0] = b'X';
+        let dec: Result<Vec<u32>, _> = Codec::decode(&magic);
+        assert!(matches!(dec, Err(SafeVecDecodeError::InvalidMagic)));
+
+        // element length changed from 4 to 3
+        let mut length = enc;
+        length[11] = 3;
+        let dec: Result<Vec<u32>, _> = Codec::decode(&length);
+        assert!(matches!(dec, Err(SafeVecDecodeError::Decoder(_))));
+    }
+}
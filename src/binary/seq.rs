@@ -0,0 +1,102 @@
+use crate::binary::{LengthPrefixed, LengthPrefixedDecodeError, LengthPrefixedEncodeError};
+use crate::{Decoder, Encoder};
+use alloc::vec::Vec;
+
+/// Wraps a binary codec that encodes `T` to create a codec that encodes `Vec<T>` as a sequence
+/// of independently framed elements.
+///
+/// Every element is encoded with `C` and prefixed with its length as a `u32` in big-endian byte
+/// order, exactly like [`LengthPrefixed`] does. Unlike serializing the whole `Vec` in one go
+/// this makes it possible to append elements to already stored data without decoding it.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::{FromToBytesCodec, LengthPrefixed, SeqCodec};
+/// #
+/// let mut stored = SeqCodec::<FromToBytesCodec>::encode(&vec![1_u16, 2]).unwrap();
+///
+/// // append an element later on
+/// stored.extend(LengthPrefixed::<FromToBytesCodec>::encode(&3_u16).unwrap());
+///
+/// let decoded: Vec<u16> = SeqCodec::<FromToBytesCodec>::decode(&stored).unwrap();
+/// assert_eq!(decoded, vec![1, 2, 3]);
+/// ```
+pub struct SeqCodec<C>(C);
+
+impl<T, E> Encoder<Vec<T>> for SeqCodec<E>
+where
+    E: Encoder<T, Encoded = Vec<u8>>,
+{
+    type Error = LengthPrefixedEncodeError<E::Error>;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &Vec<T>) -> Result<Self::Encoded, Self::Error> {
+        let mut buf = Vec::new();
+        for item in val {
+            buf.extend(LengthPrefixed::<E>::encode(item)?);
+        }
+
+        Ok(buf)
+    }
+}
+
+impl<T, D> Decoder<Vec<T>> for SeqCodec<D>
+where
+    D: Decoder<T, Encoded = [u8]>,
+{
+    type Error = LengthPrefixedDecodeError<D::Error>;
+    type Encoded = [u8];
+
+    fn decode(mut val: &Self::Encoded) -> Result<Vec<T>, Self::Error> {
+        let mut items = Vec::new();
+        while !val.is_empty() {
+            let (item, consumed) = LengthPrefixed::<D>::decode_frame(val)?;
+            items.push(item);
+            val = &val[consumed..];
+        }
+
+        Ok(items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary::FromToBytesCodec;
+
+    type Codec = SeqCodec<FromToBytesCodec>;
+
+    #[test]
+    fn test_seq_codec() {
+        let empty: Vec<String> = vec![];
+        let enc = Codec::encode(&empty).unwrap();
+        assert!(enc.is_empty());
+        let dec: Vec<String> = Codec::decode(&enc).unwrap();
+        assert_eq!(dec, empty);
+
+        let single = vec![String::from("party time 🎉")];
+        let enc = Codec::encode(&single).unwrap();
+        let dec: Vec<String> = Codec::decode(&enc).unwrap();
+        assert_eq!(dec, single);
+
+        let several = vec![
+            String::from("party"),
+            String::new(),
+            String::from("time 🎉"),
+        ];
+        let enc = Codec::encode(&several).unwrap();
+        assert_eq!(enc.len(), 3 * 4 + "party".len() + "time 🎉".len());
+        let dec: Vec<String> = Codec::decode(&enc).unwrap();
+        assert_eq!(dec, several);
+    }
+
+    #[test]
+    fn test_seq_codec_truncated() {
+        let enc = Codec::encode(&vec![1_u32, 2]).unwrap();
+
+        let dec: Result<Vec<u32>, _> = Codec::decode(&enc[..enc.len() - 1]);
+        assert!(matches!(dec, Err(LengthPrefixedDecodeError::UnexpectedEnd)));
+    }
+}
@@ -0,0 +1,174 @@
+use crate::{Decoder, Encoder, KeyProvider};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use thiserror::Error;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Length of the HMAC-SHA256 tag appended by [`SignedCodec`].
+const TAG_LEN: usize = 32;
+
+/// Wraps a binary codec and appends an HMAC-SHA256 of its output that is verified on decode.
+///
+/// This makes the data tamper-proof but not confidential: the output of the inner codec is stored
+/// as is, followed by the 32 byte tag. The key is provided by `K` which implements
+/// [`KeyProvider`]. Data with an invalid tag is rejected before it reaches the inner decoder.
+///
+/// Only available with the **`signing` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder, KeyProvider};
+/// # use codee::binary::{FromToBytesCodec, SignedCodec, SignedDecodeError};
+/// #
+/// struct CookieKey;
+///
+/// impl KeyProvider for CookieKey {
+///     fn key() -> &'static [u8] {
+///         b"not a very secret key"
+///     }
+/// }
+///
+/// type Codec = SignedCodec<FromToBytesCodec, CookieKey>;
+///
+/// let mut encoded = Codec::encode(&42_u32).unwrap();
+/// assert_eq!(encoded.len(), 4 + 32);
+///
+/// let decoded: u32 = Codec::decode(&encoded).unwrap();
+/// assert_eq!(decoded, 42);
+///
+/// encoded[3] = 43;
+/// let decoded: Result<u32, _> = Codec::decode(&encoded);
+/// assert!(matches!(decoded, Err(SignedDecodeError::SignatureMismatch)));
+/// ```
+pub struct SignedCodec<C, K>(PhantomData<(C, K)>);
+
+#[derive(Error, Debug, PartialEq)]
+pub enum SignedDecodeError<Err> {
+    #[error("input is too short to contain a signature")]
+    UnexpectedEnd,
+    #[error("signature doesn't match")]
+    SignatureMismatch,
+    #[error("failed to decode: {0}")]
+    Decoder(Err),
+}
+
+fn hmac<K: KeyProvider>() -> HmacSha256 {
+    HmacSha256::new_from_slice(K::key()).expect("HMAC accepts keys of any length")
+}
+
+impl<T, E, K> Encoder<T> for SignedCodec<E, K>
+where
+    E: Encoder<T, Encoded = Vec<u8>>,
+    K: KeyProvider,
+{
+    type Error = E::Error;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        let mut buf = E::encode(val)?;
+
+        let mut mac = hmac::<K>();
+        mac.update(&buf);
+        buf.extend_from_slice(&mac.finalize().into_bytes());
+
+        Ok(buf)
+    }
+
+    fn size_hint(val: &T) -> Option<usize> {
+        E::size_hint(val).map(|len| len + TAG_LEN)
+    }
+}
+
+impl<T, D, K> Decoder<T> for SignedCodec<D, K>
+where
+    D: Decoder<T, Encoded = [u8]>,
+    K: KeyProvider,
+{
+    type Error = SignedDecodeError<D::Error>;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        let payload_len = val
+            .len()
+            .checked_sub(TAG_LEN)
+            .ok_or(SignedDecodeError::UnexpectedEnd)?;
+        let (payload, tag) = val.split_at(payload_len);
+
+        let mut mac = hmac::<K>();
+        mac.update(payload);
+        mac.verify_slice(tag)
+            .map_err(|_| SignedDecodeError::SignatureMismatch)?;
+
+        D::decode(payload).map_err(SignedDecodeError::Decoder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary::FromToBytesCodec;
+
+    struct TestKey;
+
+    impl KeyProvider for TestKey {
+        fn key() -> &'static [u8] {
+            b"test key"
+        }
+    }
+
+    struct OtherKey;
+
+    impl KeyProvider for OtherKey {
+        fn key() -> &'static [u8] {
+            b"other key"
+        }
+    }
+
+    type Codec = SignedCodec<FromToBytesCodec, TestKey>;
+
+    #[test]
+    fn test_signed_codec() {
+        let s = String::from("party time 🎉");
+
+        let enc = Codec::encode(&s).unwrap();
+        assert_eq!(&enc[..s.len()], s.as_bytes());
+        assert_eq!(enc.len(), s.len() + TAG_LEN);
+        assert_eq!(Codec::size_hint(&s), Some(enc.len()));
+
+        let dec: String = Codec::decode(&enc).unwrap();
+        assert_eq!(dec, s);
+    }
+
+    #[test]
+    fn test_signed_codec_forged() {
+        let enc = Codec::encode(&"admin=false".to_owned()).unwrap();
+
+        // payload changed without updating the tag
+        let mut forged = enc.clone();
+        forged[..11].copy_from_slice(b"admin=true!");
+        let dec: Result<String, _> = Codec::decode(&forged);
+        assert!(matches!(dec, Err(SignedDecodeError::SignatureMismatch)));
+
+        // signed with a different key
+        let forged =
+            SignedCodec::<FromToBytesCodec, OtherKey>::encode(&"admin=true".to_owned()).unwrap();
+        let dec: Result<String, _> = Codec::decode(&forged);
+        assert!(matches!(dec, Err(SignedDecodeError::SignatureMismatch)));
+
+        // tag cut off
+        let dec: Result<String, _> = Codec::decode(&enc[..TAG_LEN - 1]);
+        assert!(matches!(dec, Err(SignedDecodeError::UnexpectedEnd)));
+    }
+
+    #[test]
+    fn test_signed_codec_inner_error() {
+        // validly signed but not a u32
+        let enc = Codec::encode(&"hi".to_owned()).unwrap();
+        let dec: Result<u32, _> = Codec::decode(&enc);
+        assert!(matches!(dec, Err(SignedDecodeError::Decoder(_))));
+    }
+}
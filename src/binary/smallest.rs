@@ -0,0 +1,236 @@
+use crate::{Decoder, Encoder};
+use alloc::vec::Vec;
+use core::convert::Infallible;
+use thiserror::Error;
+
+/// Encodes with every binary codec of the tuple `C` and keeps the smallest output.
+///
+/// The output is prefixed with a one-byte discriminant, the index of the winning codec in the
+/// tuple, so decoding knows which codec to use. If several codecs produce outputs of the same
+/// length the first one wins. Codecs that fail to encode the value are skipped. If all of them
+/// fail the error of the first codec is returned.
+///
+/// Tuples of two to four codecs are supported. Since the index is stored, never reorder the
+/// codecs of a tuple once data has been stored; only append new ones.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::{BincodeSerdeCodec, MsgpackSerdeCodec, SmallestCodec};
+/// #
+/// type Codec = SmallestCodec<(BincodeSerdeCodec, MsgpackSerdeCodec)>;
+///
+/// // bincode stores the length as 8 bytes, msgpack as 1 byte
+/// let encoded = Codec::encode(&vec![1_u8, 2, 3]).unwrap();
+/// assert_eq!(encoded[0], 1);
+///
+/// let decoded: Vec<u8> = Codec::decode(&encoded).unwrap();
+/// assert_eq!(decoded, vec![1, 2, 3]);
+/// ```
+pub struct SmallestCodec<C>(C);
+
+#[derive(Error, Debug, PartialEq)]
+pub enum SmallestDecodeError<E0, E1, E2 = Infallible, E3 = Infallible> {
+    #[error("input is empty")]
+    UnexpectedEnd,
+    #[error("invalid codec discriminant {0}")]
+    InvalidDiscriminant(u8),
+    #[error("failed to decode with the first codec: {0}")]
+    First(E0),
+    #[error("failed to decode with the second codec: {0}")]
+    Second(E1),
+    #[error("failed to decode with the third codec: {0}")]
+    Third(E2),
+    #[error("failed to decode with the fourth codec: {0}")]
+    Fourth(E3),
+}
+
+/// Prefixes `payload` with `discriminant` if it is shorter than the current `smallest`.
+fn keep_smaller(smallest: &mut Option<Vec<u8>>, discriminant: u8, payload: Vec<u8>) {
+    let is_smaller = match smallest {
+        Some(smallest) => payload.len() + 1 < smallest.len(),
+        None => true,
+    };
+
+    if is_smaller {
+        let mut buf = Vec::with_capacity(1 + payload.len());
+        buf.push(discriminant);
+        buf.extend(payload);
+        *smallest = Some(buf);
+    }
+}
+
+macro_rules! impl_smallest_codec {
+    ($first:ident $(, $codec:ident: $discriminant:literal => $variant:ident)+) => {
+        impl<T, $first, $($codec),+> Encoder<T> for SmallestCodec<($first, $($codec),+)>
+        where
+            $first: Encoder<T, Encoded = Vec<u8>>,
+            $($codec: Encoder<T, Encoded = Vec<u8>>,)+
+        {
+            type Error = $first::Error;
+            type Encoded = Vec<u8>;
+
+            fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+                let mut smallest = None;
+
+                let first_err = match $first::encode(val) {
+                    Ok(payload) => {
+                        keep_smaller(&mut smallest, 0, payload);
+                        None
+                    }
+                    Err(err) => Some(err),
+                };
+
+                $(
+                    if let Ok(payload) = $codec::encode(val) {
+                        keep_smaller(&mut smallest, $discriminant, payload);
+                    }
+                )+
+
+                match (smallest, first_err) {
+                    (Some(smallest), _) => Ok(smallest),
+                    (None, Some(err)) => Err(err),
+                    (None, None) => unreachable!("the first codec either succeeds or fails"),
+                }
+            }
+        }
+
+        impl<T, $first, $($codec),+> Decoder<T> for SmallestCodec<($first, $($codec),+)>
+        where
+            $first: Decoder<T, Encoded = [u8]>,
+            $($codec: Decoder<T, Encoded = [u8]>,)+
+        {
+            type Error = SmallestDecodeError<$first::Error, $($codec::Error),+>;
+            type Encoded = [u8];
+
+            fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+                let (&discriminant, payload) = val
+                    .split_first()
+                    .ok_or(SmallestDecodeError::UnexpectedEnd)?;
+
+                match discriminant {
+                    0 => $first::decode(payload).map_err(SmallestDecodeError::First),
+                    $($discriminant => $codec::decode(payload).map_err(SmallestDecodeError::$variant),)+
+                    _ => Err(SmallestDecodeError::InvalidDiscriminant(discriminant)),
+                }
+            }
+        }
+    };
+}
+
+impl_smallest_codec!(A, B: 1 => Second);
+impl_smallest_codec!(A, B: 1 => Second, C: 2 => Third);
+impl_smallest_codec!(A, B: 1 => Second, C: 2 => Third, D: 3 => Fourth);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary::{FromToBytesCodec, FromToBytesCodecError};
+
+    /// Run-length encodes strings as pairs of count and byte
+    struct RleCodec;
+
+    impl Encoder<String> for RleCodec {
+        type Error = ();
+        type Encoded = Vec<u8>;
+
+        fn encode(val: &String) -> Result<Self::Encoded, Self::Error> {
+            let mut buf: Vec<u8> = vec![];
+            for &b in val.as_bytes() {
+                match buf.len() {
+                    len if len >= 2 && buf[len - 1] == b && buf[len - 2] < u8::MAX => {
+                        buf[len - 2] += 1
+                    }
+                    _ => buf.extend([1, b]),
+                }
+            }
+            Ok(buf)
+        }
+    }
+
+    impl Decoder<String> for RleCodec {
+        type Error = FromToBytesCodecError;
+        type Encoded = [u8];
+
+        fn decode(val: &Self::Encoded) -> Result<String, Self::Error> {
+            let bytes = val
+                .chunks(2)
+                .flat_map(|pair| core::iter::repeat_n(pair[1], pair[0] as usize))
+                .collect();
+            Ok(String::from_utf8(bytes)?)
+        }
+    }
+
+    /// Always fails to encode
+    struct FailingCodec;
+
+    impl Encoder<String> for FailingCodec {
+        type Error = &'static str;
+        type Encoded = Vec<u8>;
+
+        fn encode(_val: &String) -> Result<Self::Encoded, Self::Error> {
+            Err("failing")
+        }
+    }
+
+    impl Decoder<String> for FailingCodec {
+        type Error = &'static str;
+        type Encoded = [u8];
+
+        fn decode(_val: &Self::Encoded) -> Result<String, Self::Error> {
+            Err("failing")
+        }
+    }
+
+    #[test]
+    fn test_smallest_codec() {
+        type Codec = SmallestCodec<(FromToBytesCodec, RleCodec)>;
+
+        let repetitive = "a".repeat(100);
+        let enc = Codec::encode(&repetitive).unwrap();
+        assert_eq!(enc, vec![1, 100, b'a']);
+        let dec: String = Codec::decode(enc.as_slice()).unwrap();
+        assert_eq!(dec, repetitive);
+
+        let varied = String::from("party time 🎉");
+        let enc = Codec::encode(&varied).unwrap();
+        assert_eq!(enc[0], 0);
+        assert_eq!(&enc[1..], varied.as_bytes());
+        let dec: String = Codec::decode(enc.as_slice()).unwrap();
+        assert_eq!(dec, varied);
+    }
+
+    #[test]
+    fn test_smallest_codec_skips_failing() {
+        type Codec = SmallestCodec<(FailingCodec, FromToBytesCodec, RleCodec)>;
+
+        let enc = Codec::encode(&"aaaa".to_owned()).unwrap();
+        assert_eq!(enc, vec![2, 4, b'a']);
+        let dec: String = Codec::decode(enc.as_slice()).unwrap();
+        assert_eq!(dec, "aaaa");
+
+        let enc = Codec::encode(&"ab".to_owned()).unwrap();
+        assert_eq!(enc, vec![1, b'a', b'b']);
+
+        let enc = SmallestCodec::<(FailingCodec, FailingCodec)>::encode(&"ab".to_owned());
+        assert_eq!(enc, Err("failing"));
+    }
+
+    #[test]
+    fn test_smallest_codec_invalid_input() {
+        type Codec = SmallestCodec<(FromToBytesCodec, RleCodec, FailingCodec, FailingCodec)>;
+
+        let dec: Result<String, _> = Codec::decode(&[]);
+        assert!(matches!(dec, Err(SmallestDecodeError::UnexpectedEnd)));
+
+        let dec: Result<String, _> = Codec::decode(&[4, b'a']);
+        assert!(matches!(
+            dec,
+            Err(SmallestDecodeError::InvalidDiscriminant(4))
+        ));
+
+        let dec: Result<String, _> = Codec::decode(&[3, b'a']);
+        assert!(matches!(dec, Err(SmallestDecodeError::Fourth("failing"))));
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{Decoder, Encoder};
+use speedy::{LittleEndian, Readable, Writable};
+
+/// A binary codec that uses [`speedy`] to encode data.
+///
+/// speedy is a very fast binary format without serde that comes with its own `Readable` and
+/// `Writable` derives. Data is stored in little-endian byte order.
+///
+/// Only available with the **`speedy` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::SpeedyCodec;
+/// # use speedy::{Readable, Writable};
+/// #
+/// #[derive(Readable, Writable, PartialEq, Debug)]
+/// struct MyState {
+///     chicken_count: u32,
+///     farm_name: String,
+/// }
+///
+/// let original_value = MyState {
+///     chicken_count: 10,
+///     farm_name: "My Farm".to_owned(),
+/// };
+///
+/// let encoded = SpeedyCodec::encode(&original_value).unwrap();
+/// let decoded: MyState = SpeedyCodec::decode(&encoded).unwrap();
+///
+/// assert_eq!(decoded, original_value);
+/// ```
+pub struct SpeedyCodec;
+
+impl<T: Writable<LittleEndian>> Encoder<T> for SpeedyCodec {
+    type Error = speedy::Error;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        val.write_to_vec_with_ctx(LittleEndian::default())
+    }
+}
+
+impl<T> Decoder<T> for SpeedyCodec
+where
+    T: for<'a> Readable<'a, LittleEndian>,
+{
+    type Error = speedy::Error;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        T::read_from_buffer_with_ctx(LittleEndian::default(), val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_speedy_codec() {
+        #[derive(Clone, Debug, PartialEq, Readable, Writable)]
+        struct Test {
+            s: String,
+            i: i32,
+            points: Vec<(f32, f32)>,
+        }
+        let t = Test {
+            s: String::from("party time 🎉"),
+            i: 42,
+            points: vec![(0.5, -1.0), (2.0, 3.5)],
+        };
+        let enc = SpeedyCodec::encode(&t).unwrap();
+        let dec: Test = SpeedyCodec::decode(&enc).unwrap();
+        assert_eq!(dec, t);
+
+        let dec: Result<Test, _> = SpeedyCodec::decode(&enc[..enc.len() - 1]);
+        assert!(dec.is_err());
+    }
+}
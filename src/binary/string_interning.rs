@@ -0,0 +1,296 @@
+use crate::{Decoder, Encoder};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::{HashMap, HashSet};
+use thiserror::Error;
+
+/// Wraps a binary codec and deduplicates repeated strings before encoding.
+///
+/// The value is first converted into a [`serde_json::Value`] tree. Every string (map keys
+/// included) that occurs more than once is put into a string table once and all of its
+/// occurrences are replaced by a short reference `"\0<index>"` into that table. Strings that
+/// start with `\0` themselves are escaped by another `\0`. The string table together with the
+/// transformed tree (see [`Interned`]) is then encoded with the inner codec `C`.
+///
+/// This can dramatically reduce the size of data with many repeated tags, names or enum variants.
+/// Since the tree is decoded as a [`serde_json::Value`] the inner codec has to be a
+/// self-describing format like msgpack.
+///
+/// Only available with the **`interning` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::{MsgpackSerdeCodec, StringInterningCodec};
+/// #
+/// let original_value = vec!["production".to_owned(); 100];
+///
+/// let interned = StringInterningCodec::<MsgpackSerdeCodec>::encode(&original_value).unwrap();
+/// let plain = MsgpackSerdeCodec::encode(&original_value).unwrap();
+/// assert!(interned.len() < plain.len() / 2);
+///
+/// let decoded: Vec<String> = StringInterningCodec::<MsgpackSerdeCodec>::decode(&interned).unwrap();
+/// assert_eq!(decoded, original_value);
+/// ```
+pub struct StringInterningCodec<C>(C);
+
+/// The string table together with the value tree that references it. This is what the inner codec
+/// of [`StringInterningCodec`] encodes and decodes.
+pub type Interned = (Vec<String>, Value);
+
+/// Marks a reference into the string table
+const REF: char = '\0';
+
+#[derive(Error, Debug)]
+pub enum StringInterningEncodeError<Err> {
+    #[error("failed to serialize: {0}")]
+    Serialize(serde_json::Error),
+    #[error("failed to encode: {0}")]
+    Encoder(Err),
+}
+
+#[derive(Error, Debug)]
+pub enum StringInterningDecodeError<Err> {
+    #[error("failed to decode: {0}")]
+    Decoder(Err),
+    #[error("invalid string reference {0:?}")]
+    InvalidReference(String),
+    #[error("failed to deserialize: {0}")]
+    Deserialize(serde_json::Error),
+}
+
+impl<T, E> Encoder<T> for StringInterningCodec<E>
+where
+    T: Serialize,
+    E: Encoder<Interned, Encoded = Vec<u8>>,
+{
+    type Error = StringInterningEncodeError<E::Error>;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        let value = serde_json::to_value(val).map_err(StringInterningEncodeError::Serialize)?;
+
+        let mut counts = HashMap::new();
+        count_strings(&value, &mut counts);
+
+        let repeated = counts
+            .into_iter()
+            .filter(|(_, count)| *count > 1)
+            .map(|(s, _)| s.to_owned())
+            .collect();
+
+        let mut interner = Interner {
+            repeated,
+            indices: HashMap::new(),
+            strings: Vec::new(),
+        };
+        let value = interner.intern(value);
+
+        E::encode(&(interner.strings, value)).map_err(StringInterningEncodeError::Encoder)
+    }
+}
+
+impl<T, D> Decoder<T> for StringInterningCodec<D>
+where
+    for<'de> T: Deserialize<'de>,
+    D: Decoder<Interned, Encoded = [u8]>,
+{
+    type Error = StringInterningDecodeError<D::Error>;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        let (strings, value) = D::decode(val).map_err(StringInterningDecodeError::Decoder)?;
+        let value = resolve(value, &strings)?;
+
+        serde_json::from_value(value).map_err(StringInterningDecodeError::Deserialize)
+    }
+}
+
+fn count_strings<'a>(value: &'a Value, counts: &mut HashMap<&'a str, usize>) {
+    match value {
+        Value::String(s) => *counts.entry(s.as_str()).or_default() += 1,
+        Value::Array(items) => items.iter().for_each(|item| count_strings(item, counts)),
+        Value::Object(map) => {
+            for (key, item) in map {
+                *counts.entry(key.as_str()).or_default() += 1;
+                count_strings(item, counts);
+            }
+        }
+        Value::Null | Value::Bool(_) | Value::Number(_) => {}
+    }
+}
+
+struct Interner {
+    repeated: HashSet<String>,
+    indices: HashMap<String, usize>,
+    strings: Vec<String>,
+}
+
+impl Interner {
+    fn intern(&mut self, value: Value) -> Value {
+        match value {
+            Value::String(s) => Value::String(self.intern_str(s)),
+            Value::Array(items) => {
+                Value::Array(items.into_iter().map(|item| self.intern(item)).collect())
+            }
+            Value::Object(map) => Value::Object(
+                map.into_iter()
+                    .map(|(key, item)| (self.intern_str(key), self.intern(item)))
+                    .collect(),
+            ),
+            value => value,
+        }
+    }
+
+    fn intern_str(&mut self, s: String) -> String {
+        if !self.repeated.contains(&s) {
+            return if s.starts_with(REF) {
+                format!("{REF}{s}")
+            } else {
+                s
+            };
+        }
+
+        let index = match self.indices.get(&s) {
+            Some(&index) => index,
+            None => {
+                let index = self.strings.len();
+                self.indices.insert(s.clone(), index);
+                self.strings.push(s);
+                index
+            }
+        };
+
+        format!("{REF}{index}")
+    }
+}
+
+fn resolve<Err>(
+    value: Value,
+    strings: &[String],
+) -> Result<Value, StringInterningDecodeError<Err>> {
+    Ok(match value {
+        Value::String(s) => Value::String(resolve_str(s, strings)?),
+        Value::Array(items) => Value::Array(
+            items
+                .into_iter()
+                .map(|item| resolve(item, strings))
+                .collect::<Result<_, _>>()?,
+        ),
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .map(|(key, item)| Ok((resolve_str(key, strings)?, resolve(item, strings)?)))
+                .collect::<Result<Map<_, _>, _>>()?,
+        ),
+        value => value,
+    })
+}
+
+fn resolve_str<Err>(
+    s: String,
+    strings: &[String],
+) -> Result<String, StringInterningDecodeError<Err>> {
+    let Some(rest) = s.strip_prefix(REF) else {
+        return Ok(s);
+    };
+
+    if rest.starts_with(REF) {
+        return Ok(rest.to_owned());
+    }
+
+    rest.parse::<usize>()
+        .ok()
+        .and_then(|index| strings.get(index))
+        .cloned()
+        .ok_or(StringInterningDecodeError::InvalidReference(s))
+}
+
+#[cfg(all(test, feature = "msgpack_serde"))]
+mod tests {
+    use super::*;
+    use crate::binary::MsgpackSerdeCodec;
+
+    type Codec = StringInterningCodec<MsgpackSerdeCodec>;
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    struct Event {
+        service: String,
+        environment: String,
+        tags: Vec<String>,
+        count: i64,
+        ratio: f64,
+        note: Option<String>,
+    }
+
+    fn events() -> Vec<Event> {
+        (0..100)
+            .map(|i| Event {
+                service: "checkout-api".to_owned(),
+                environment: if i % 3 == 0 { "production" } else { "staging" }.to_owned(),
+                tags: vec![
+                    "region:eu-west-1".to_owned(),
+                    "team:payments".to_owned(),
+                    "tier:backend".to_owned(),
+                ],
+                count: i - 50,
+                ratio: i as f64 / 7.0,
+                note: (i == 42).then(|| "party time 🎉".to_owned()),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_string_interning_codec() {
+        let events = events();
+
+        let enc = Codec::encode(&events).unwrap();
+        let dec: Vec<Event> = Codec::decode(&enc).unwrap();
+        assert_eq!(dec, events);
+    }
+
+    #[test]
+    fn test_string_interning_codec_size() {
+        let events = events();
+
+        let interned = Codec::encode(&events).unwrap();
+        let plain = MsgpackSerdeCodec::encode(&events).unwrap();
+
+        assert!(
+            interned.len() < plain.len() * 2 / 3,
+            "interned: {}, plain: {}",
+            interned.len(),
+            plain.len()
+        );
+    }
+
+    #[test]
+    fn test_string_interning_codec_escapes() {
+        let v = vec![
+            "\0".to_owned(),
+            "\u{0}0".to_owned(),
+            "\0\0".to_owned(),
+            "a".to_owned(),
+            "a".to_owned(),
+        ];
+
+        let enc = Codec::encode(&v).unwrap();
+        let dec: Vec<String> = Codec::decode(&enc).unwrap();
+        assert_eq!(dec, v);
+    }
+
+    #[test]
+    fn test_string_interning_codec_invalid_reference() {
+        let interned: Interned = (
+            vec!["a".to_owned()],
+            serde_json::json!(["\u{0}0", "\u{0}1"]),
+        );
+        let enc = MsgpackSerdeCodec::encode(&interned).unwrap();
+
+        let dec: Result<Vec<String>, _> = Codec::decode(&enc);
+        assert!(matches!(
+            dec,
+            Err(StringInterningDecodeError::InvalidReference(s)) if s == "\u{0}1"
+        ));
+    }
+}
@@ -0,0 +1,201 @@
+use super::length_prefixed::split_frame;
+use crate::Decoder;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use thiserror::Error;
+
+/// Implement this for an enum of all the message types that can occur in a tagged stream.
+/// It dispatches the payload of every entry to the codec that is registered for its type byte.
+pub trait TaggedMessage: Sized {
+    type Error;
+
+    /// Decode the `payload` of an entry with the type byte `tag`.
+    fn decode_tagged(tag: u8, payload: &[u8]) -> Result<Self, Self::Error>;
+}
+
+/// A binary decoder for a stream of heterogeneous messages where each entry is laid out as
+/// `[type_byte][u32 big-endian len][payload]`.
+///
+/// The message types are defined by implementing [`TaggedMessage`] for an enum. Use
+/// [`TaggedStreamDecoder::iter`] to lazily decode the entries one by one or the [`Decoder`]
+/// implementation to decode all of them into a `Vec`.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Decoder, Encoder};
+/// # use codee::binary::{FromToBytesCodec, FromToBytesCodecError, TaggedMessage, TaggedStreamDecoder};
+/// #
+/// #[derive(Debug, PartialEq)]
+/// enum Message {
+///     Counter(u32),
+///     Name(String),
+/// }
+///
+/// #[derive(Debug)]
+/// enum MessageError {
+///     UnknownTag(u8),
+///     Payload(FromToBytesCodecError),
+/// }
+///
+/// impl TaggedMessage for Message {
+///     type Error = MessageError;
+///
+///     fn decode_tagged(tag: u8, payload: &[u8]) -> Result<Self, Self::Error> {
+///         match tag {
+///             0 => FromToBytesCodec::decode(payload).map(Message::Counter),
+///             1 => FromToBytesCodec::decode(payload).map(Message::Name),
+///             _ => return Err(MessageError::UnknownTag(tag)),
+///         }
+///         .map_err(MessageError::Payload)
+///     }
+/// }
+///
+/// let stream = [0, 0, 0, 0, 4, 0, 0, 0, 42, 1, 0, 0, 0, 2, b'h', b'i'];
+///
+/// let messages: Vec<Message> = TaggedStreamDecoder::decode(&stream).unwrap();
+///
+/// assert_eq!(messages, vec![Message::Counter(42), Message::Name("hi".to_owned())]);
+/// ```
+pub struct TaggedStreamDecoder;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum TaggedStreamDecodeError<Err> {
+    #[error("unexpected end of stream")]
+    UnexpectedEnd,
+    #[error("failed to decode: {0}")]
+    Decoder(Err),
+}
+
+impl TaggedStreamDecoder {
+    /// Returns an iterator that decodes the entries of `val` one by one. The iterator stops
+    /// after the first error.
+    pub fn iter<T: TaggedMessage>(val: &[u8]) -> TaggedStreamIter<'_, T> {
+        TaggedStreamIter {
+            remaining: val,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Decoder<Vec<T>> for TaggedStreamDecoder
+where
+    T: TaggedMessage,
+{
+    type Error = TaggedStreamDecodeError<T::Error>;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<Vec<T>, Self::Error> {
+        Self::iter(val).collect()
+    }
+}
+
+/// Iterator over the decoded entries of a tagged stream. Created by [`TaggedStreamDecoder::iter`].
+pub struct TaggedStreamIter<'a, T> {
+    remaining: &'a [u8],
+    _marker: PhantomData<T>,
+}
+
+impl<T: TaggedMessage> Iterator for TaggedStreamIter<'_, T> {
+    type Item = Result<T, TaggedStreamDecodeError<T::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+
+        let entry = read_entry(self.remaining);
+
+        match entry {
+            Ok((tag, payload, rest)) => {
+                self.remaining = rest;
+                Some(T::decode_tagged(tag, payload).map_err(TaggedStreamDecodeError::Decoder))
+            }
+            Err(err) => {
+                self.remaining = &[];
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+/// Type byte, payload and the rest of the stream
+type Entry<'a> = (u8, &'a [u8], &'a [u8]);
+
+fn read_entry<Err>(val: &[u8]) -> Result<Entry<'_>, TaggedStreamDecodeError<Err>> {
+    let (&tag, rest) = val
+        .split_first()
+        .ok_or(TaggedStreamDecodeError::UnexpectedEnd)?;
+    let (payload, rest) = split_frame(rest).ok_or(TaggedStreamDecodeError::UnexpectedEnd)?;
+
+    Ok((tag, payload, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary::FromToBytesCodec;
+    use crate::Encoder;
+
+    #[derive(Debug, PartialEq)]
+    enum Message {
+        Counter(u32),
+        Name(String),
+    }
+
+    #[derive(Debug)]
+    enum MessageError {
+        UnknownTag(u8),
+        Payload,
+    }
+
+    impl TaggedMessage for Message {
+        type Error = MessageError;
+
+        fn decode_tagged(tag: u8, payload: &[u8]) -> Result<Self, Self::Error> {
+            match tag {
+                0 => FromToBytesCodec::decode(payload).map(Message::Counter),
+                1 => FromToBytesCodec::decode(payload).map(Message::Name),
+                _ => return Err(MessageError::UnknownTag(tag)),
+            }
+            .map_err(|_| MessageError::Payload)
+        }
+    }
+
+    fn push_entry(buf: &mut Vec<u8>, tag: u8, payload: &[u8]) {
+        buf.push(tag);
+        buf.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+        buf.extend_from_slice(payload);
+    }
+
+    #[test]
+    fn test_tagged_stream_decoder() {
+        let mut buf = vec![];
+        push_entry(&mut buf, 0, &FromToBytesCodec::encode(&42_u32).unwrap());
+        push_entry(
+            &mut buf,
+            1,
+            &FromToBytesCodec::encode(&"party time 🎉".to_owned()).unwrap(),
+        );
+
+        let mut iter = TaggedStreamDecoder::iter::<Message>(&buf);
+        assert!(matches!(iter.next(), Some(Ok(Message::Counter(42)))));
+        assert!(matches!(iter.next(), Some(Ok(Message::Name(s))) if s == "party time 🎉"));
+        assert!(iter.next().is_none());
+
+        let truncated: Result<Vec<Message>, _> = TaggedStreamDecoder::decode(&buf[..buf.len() - 1]);
+        assert!(matches!(
+            truncated,
+            Err(TaggedStreamDecodeError::UnexpectedEnd)
+        ));
+
+        push_entry(&mut buf, 7, &[]);
+        let unknown: Result<Vec<Message>, _> = TaggedStreamDecoder::decode(&buf);
+        assert!(matches!(
+            unknown,
+            Err(TaggedStreamDecodeError::Decoder(MessageError::UnknownTag(
+                7
+            )))
+        ));
+    }
+}
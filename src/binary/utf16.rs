@@ -0,0 +1,124 @@
+use crate::{Decoder, Encoder};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::Infallible;
+use thiserror::Error;
+
+/// Binary codec that encodes a `String` as raw UTF-16 in big-endian byte order without a BOM or
+/// a length prefix.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::Utf16BeStringCodec;
+/// #
+/// let encoded = Utf16BeStringCodec::encode(&"hi".to_owned()).unwrap();
+/// assert_eq!(encoded, vec![0, b'h', 0, b'i']);
+///
+/// let decoded: String = Utf16BeStringCodec::decode(&encoded).unwrap();
+/// assert_eq!(decoded, "hi");
+/// ```
+pub struct Utf16BeStringCodec;
+
+/// Binary codec that encodes a `String` as raw UTF-16 in little-endian byte order without a BOM
+/// or a length prefix.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::Utf16LeStringCodec;
+/// #
+/// let encoded = Utf16LeStringCodec::encode(&"hi".to_owned()).unwrap();
+/// assert_eq!(encoded, vec![b'h', 0, b'i', 0]);
+///
+/// let decoded: String = Utf16LeStringCodec::decode(&encoded).unwrap();
+/// assert_eq!(decoded, "hi");
+/// ```
+pub struct Utf16LeStringCodec;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum Utf16DecodeError {
+    #[error("odd number of bytes ({0}) for UTF-16")]
+    OddLength(usize),
+    #[error("invalid UTF-16 code unit sequence")]
+    InvalidUtf16,
+}
+
+macro_rules! impl_utf16_codec {
+    ($codec:ty, $to_bytes:ident, $from_bytes:ident) => {
+        impl Encoder<String> for $codec {
+            type Error = Infallible;
+            type Encoded = Vec<u8>;
+
+            fn encode(val: &String) -> Result<Self::Encoded, Self::Error> {
+                Ok(val.encode_utf16().flat_map(u16::$to_bytes).collect())
+            }
+        }
+
+        impl Decoder<String> for $codec {
+            type Error = Utf16DecodeError;
+            type Encoded = [u8];
+
+            fn decode(val: &Self::Encoded) -> Result<String, Self::Error> {
+                if val.len() % 2 != 0 {
+                    return Err(Utf16DecodeError::OddLength(val.len()));
+                }
+
+                let units = val
+                    .chunks_exact(2)
+                    .map(|unit| u16::$from_bytes([unit[0], unit[1]]));
+
+                char::decode_utf16(units)
+                    .collect::<Result<String, _>>()
+                    .map_err(|_| Utf16DecodeError::InvalidUtf16)
+            }
+        }
+    };
+}
+
+impl_utf16_codec!(Utf16BeStringCodec, to_be_bytes, from_be_bytes);
+impl_utf16_codec!(Utf16LeStringCodec, to_le_bytes, from_le_bytes);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_utf16_be_string_codec() {
+        let s = String::from("party time 🎉");
+
+        let enc = Utf16BeStringCodec::encode(&s).unwrap();
+        // 🎉 is the surrogate pair D83C DF89
+        assert_eq!(enc[enc.len() - 4..], [0xd8, 0x3c, 0xdf, 0x89]);
+        assert_eq!(Utf16BeStringCodec::decode(&enc), Ok(s));
+    }
+
+    #[test]
+    fn test_utf16_le_string_codec() {
+        let s = String::from("party time 🎉");
+
+        let enc = Utf16LeStringCodec::encode(&s).unwrap();
+        assert_eq!(enc[enc.len() - 4..], [0x3c, 0xd8, 0x89, 0xdf]);
+        assert_eq!(Utf16LeStringCodec::decode(&enc), Ok(s));
+    }
+
+    #[test]
+    fn test_utf16_string_codec_invalid() {
+        assert_eq!(
+            Utf16BeStringCodec::decode(&[0, b'h', 0]),
+            Err(Utf16DecodeError::OddLength(3))
+        );
+
+        // unpaired high surrogate
+        assert_eq!(
+            Utf16BeStringCodec::decode(&[0xd8, 0x3c, 0, b'h']),
+            Err(Utf16DecodeError::InvalidUtf16)
+        );
+        assert_eq!(
+            Utf16LeStringCodec::decode(&[0x3c, 0xd8]),
+            Err(Utf16DecodeError::InvalidUtf16)
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{Decoder, Encoder};
+use alloc::vec::Vec;
+use uuid::Uuid;
+
+/// A codec that encodes a [`Uuid`] as its 16 bytes in big-endian byte order.
+///
+/// Decoding fails if the input isn't exactly 16 bytes long.
+///
+/// Only available with the **`uuid` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::UuidBytesCodec;
+/// # use uuid::Uuid;
+/// #
+/// let original_value = Uuid::from_u128(0x67e5504410b1426f9247bb680e5fe0c8);
+///
+/// let encoded = UuidBytesCodec::encode(&original_value).unwrap();
+/// assert_eq!(encoded.len(), 16);
+///
+/// let decoded = UuidBytesCodec::decode(&encoded).unwrap();
+/// assert_eq!(decoded, original_value);
+/// ```
+pub struct UuidBytesCodec;
+
+impl Encoder<Uuid> for UuidBytesCodec {
+    type Error = ();
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &Uuid) -> Result<Self::Encoded, Self::Error> {
+        Ok(val.as_bytes().to_vec())
+    }
+
+    fn size_hint(_val: &Uuid) -> Option<usize> {
+        Some(16)
+    }
+}
+
+impl Decoder<Uuid> for UuidBytesCodec {
+    type Error = uuid::Error;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<Uuid, Self::Error> {
+        Uuid::from_slice(val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uuid_bytes_codec() {
+        let id = Uuid::from_u128(0x67e5504410b1426f9247bb680e5fe0c8);
+
+        let enc = UuidBytesCodec::encode(&id).unwrap();
+        assert_eq!(
+            enc,
+            vec![
+                0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f, 0x92, 0x47, 0xbb, 0x68, 0x0e, 0x5f,
+                0xe0, 0xc8
+            ]
+        );
+        assert_eq!(UuidBytesCodec::decode(&enc), Ok(id));
+
+        assert!(UuidBytesCodec::decode(&enc[..15]).is_err());
+        assert!(UuidBytesCodec::decode(&[0; 17]).is_err());
+    }
+}
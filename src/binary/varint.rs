@@ -0,0 +1,231 @@
+use crate::{Decoder, Encoder};
+use alloc::vec::Vec;
+use core::convert::Infallible;
+use thiserror::Error;
+
+/// Binary codec for primitive integers that stores them as variable-length LEB128 integers.
+///
+/// Every byte stores 7 bits of the value, least significant group first, and the highest bit
+/// signals whether another byte follows. Small values take a single byte, large values take up
+/// to one byte more than their fixed-size representation. Signed integers are zigzag encoded
+/// first so that small negative values are small as well.
+///
+/// Decoding rejects truncated input, non-minimal (overlong) encodings, values that don't fit into
+/// the type and trailing bytes.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::VarintCodec;
+/// #
+/// let encoded = VarintCodec::encode(&300_u64).unwrap();
+/// assert_eq!(encoded, vec![0xac, 0x02]);
+///
+/// let decoded: u64 = VarintCodec::decode(encoded.as_slice()).unwrap();
+/// assert_eq!(decoded, 300);
+///
+/// let encoded = VarintCodec::encode(&-1_i64).unwrap();
+/// assert_eq!(encoded, vec![0x01]);
+/// ```
+pub struct VarintCodec;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum VarintDecodeError {
+    #[error("unexpected end of input")]
+    UnexpectedEnd,
+    #[error("varint is not minimally encoded")]
+    Overlong,
+    #[error("varint doesn't fit into the target type")]
+    Overflow,
+    #[error("{0} trailing bytes after the varint")]
+    TrailingBytes(usize),
+}
+
+fn encode_varint(mut val: u128) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(varint_len(val));
+    loop {
+        let byte = (val & 0x7f) as u8;
+        val >>= 7;
+        if val == 0 {
+            buf.push(byte);
+            return buf;
+        }
+        buf.push(byte | 0x80);
+    }
+}
+
+fn varint_len(val: u128) -> usize {
+    let bits = (128 - val.leading_zeros()).max(1);
+    bits.div_ceil(7) as usize
+}
+
+/// Decodes a varint that has to fit into `bits` bits and has to span the whole `val`.
+fn decode_varint(val: &[u8], bits: u32) -> Result<u128, VarintDecodeError> {
+    let max_len = bits.div_ceil(7) as usize;
+    let mut result = 0_u128;
+
+    for (i, &byte) in val.iter().enumerate() {
+        if i >= max_len {
+            return Err(VarintDecodeError::Overlong);
+        }
+
+        let remaining_bits = bits - 7 * i as u32;
+        let group = byte & 0x7f;
+        if remaining_bits < 7 && group >> remaining_bits != 0 {
+            return Err(VarintDecodeError::Overflow);
+        }
+        result |= (group as u128) << (7 * i);
+
+        if byte & 0x80 == 0 {
+            if i > 0 && byte == 0 {
+                return Err(VarintDecodeError::Overlong);
+            }
+            if i + 1 < val.len() {
+                return Err(VarintDecodeError::TrailingBytes(val.len() - i - 1));
+            }
+            return Ok(result);
+        }
+    }
+
+    Err(VarintDecodeError::UnexpectedEnd)
+}
+
+macro_rules! impl_varint_codec_for_unsigned {
+    ($($num:ty),*) => {
+        $(
+            impl Encoder<$num> for VarintCodec {
+                type Error = Infallible;
+                type Encoded = Vec<u8>;
+
+                fn encode(val: &$num) -> Result<Self::Encoded, Self::Error> {
+                    Ok(encode_varint(*val as u128))
+                }
+
+                fn size_hint(val: &$num) -> Option<usize> {
+                    Some(varint_len(*val as u128))
+                }
+            }
+
+            impl Decoder<$num> for VarintCodec {
+                type Error = VarintDecodeError;
+                type Encoded = [u8];
+
+                fn decode(val: &Self::Encoded) -> Result<$num, Self::Error> {
+                    decode_varint(val, <$num>::BITS).map(|val| val as $num)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_varint_codec_for_signed {
+    ($($num:ty),*) => {
+        $(
+            impl Encoder<$num> for VarintCodec {
+                type Error = Infallible;
+                type Encoded = Vec<u8>;
+
+                fn encode(val: &$num) -> Result<Self::Encoded, Self::Error> {
+                    Ok(encode_varint(zigzag(*val as i128)))
+                }
+
+                fn size_hint(val: &$num) -> Option<usize> {
+                    Some(varint_len(zigzag(*val as i128)))
+                }
+            }
+
+            impl Decoder<$num> for VarintCodec {
+                type Error = VarintDecodeError;
+                type Encoded = [u8];
+
+                fn decode(val: &Self::Encoded) -> Result<$num, Self::Error> {
+                    decode_varint(val, <$num>::BITS).map(|val| unzigzag(val) as $num)
+                }
+            }
+        )*
+    };
+}
+
+fn zigzag(val: i128) -> u128 {
+    ((val << 1) ^ (val >> 127)) as u128
+}
+
+fn unzigzag(val: u128) -> i128 {
+    (val >> 1) as i128 ^ -((val & 1) as i128)
+}
+
+impl_varint_codec_for_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_varint_codec_for_signed!(i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_varint_codec_unsigned() {
+        assert_eq!(VarintCodec::encode(&0_u64).unwrap(), vec![0]);
+        assert_eq!(VarintCodec::encode(&1_u64).unwrap(), vec![1]);
+        assert_eq!(VarintCodec::encode(&127_u64).unwrap(), vec![0x7f]);
+        assert_eq!(VarintCodec::encode(&128_u64).unwrap(), vec![0x80, 0x01]);
+
+        let enc = VarintCodec::encode(&u64::MAX).unwrap();
+        assert_eq!(enc.len(), 10);
+        assert_eq!(VarintCodec::size_hint(&u64::MAX), Some(10));
+
+        for val in [0, 1, 127, 128, 300, 1 << 40, u64::MAX] {
+            let enc = VarintCodec::encode(&val).unwrap();
+            let dec: u64 = VarintCodec::decode(enc.as_slice()).unwrap();
+            assert_eq!(dec, val);
+        }
+
+        let enc = VarintCodec::encode(&u128::MAX).unwrap();
+        let dec: u128 = VarintCodec::decode(enc.as_slice()).unwrap();
+        assert_eq!(dec, u128::MAX);
+    }
+
+    #[test]
+    fn test_varint_codec_signed() {
+        assert_eq!(VarintCodec::encode(&0_i64).unwrap(), vec![0]);
+        assert_eq!(VarintCodec::encode(&-1_i64).unwrap(), vec![1]);
+        assert_eq!(VarintCodec::encode(&1_i64).unwrap(), vec![2]);
+        assert_eq!(VarintCodec::encode(&-64_i64).unwrap(), vec![0x7f]);
+
+        for val in [0, 1, -1, 63, -64, -65, i64::MIN, i64::MAX] {
+            let enc = VarintCodec::encode(&val).unwrap();
+            let dec: i64 = VarintCodec::decode(enc.as_slice()).unwrap();
+            assert_eq!(dec, val);
+        }
+
+        for val in [i8::MIN, -1, 0, i8::MAX] {
+            let enc = VarintCodec::encode(&val).unwrap();
+            let dec: i8 = VarintCodec::decode(enc.as_slice()).unwrap();
+            assert_eq!(dec, val);
+        }
+    }
+
+    #[test]
+    fn test_varint_codec_invalid() {
+        let dec: Result<u64, _> = VarintCodec::decode(&[][..]);
+        assert_eq!(dec, Err(VarintDecodeError::UnexpectedEnd));
+
+        let enc = VarintCodec::encode(&u64::MAX).unwrap();
+        let dec: Result<u64, _> = VarintCodec::decode(&enc[..enc.len() - 1]);
+        assert_eq!(dec, Err(VarintDecodeError::UnexpectedEnd));
+
+        // 1 encoded with a redundant zero group
+        let dec: Result<u64, _> = VarintCodec::decode(&[0x81, 0x00][..]);
+        assert_eq!(dec, Err(VarintDecodeError::Overlong));
+
+        // 11 bytes are more than any u64 needs
+        let dec: Result<u64, _> = VarintCodec::decode(&[0x80; 11][..]);
+        assert_eq!(dec, Err(VarintDecodeError::Overlong));
+
+        let enc = VarintCodec::encode(&256_u16).unwrap();
+        let dec: Result<u8, _> = VarintCodec::decode(enc.as_slice());
+        assert_eq!(dec, Err(VarintDecodeError::Overflow));
+
+        let dec: Result<u64, _> = VarintCodec::decode(&[0x01, 0x02][..]);
+        assert_eq!(dec, Err(VarintDecodeError::TrailingBytes(1)));
+    }
+}
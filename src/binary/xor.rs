@@ -0,0 +1,85 @@
+use crate::{Decoder, Encoder};
+use alloc::vec::Vec;
+
+/// Wraps a binary codec and XORs every byte of its output with `KEY`.
+///
+/// This is **not encryption**. It only obscures stored values so they can't be read or edited
+/// trivially, for example to discourage casual tampering with a game save. Use
+/// [`SignedCodec`](crate::binary::SignedCodec) if you need to detect tampering.
+///
+/// It is also the simplest possible adapter: a cheap, pure transformation of the bytes of the
+/// inner codec.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::binary::{FromToBytesCodec, XorCodec};
+/// #
+/// type Codec = XorCodec<FromToBytesCodec, 0x5a>;
+///
+/// let encoded = Codec::encode(&"hi".to_owned()).unwrap();
+/// assert_eq!(encoded, vec![b'h' ^ 0x5a, b'i' ^ 0x5a]);
+///
+/// let decoded: String = Codec::decode(&encoded).unwrap();
+/// assert_eq!(decoded, "hi");
+/// ```
+pub struct XorCodec<C, const KEY: u8>(C);
+
+impl<T, E, const KEY: u8> Encoder<T> for XorCodec<E, KEY>
+where
+    E: Encoder<T, Encoded = Vec<u8>>,
+{
+    type Error = E::Error;
+    type Encoded = Vec<u8>;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        let mut buf = E::encode(val)?;
+        buf.iter_mut().for_each(|b| *b ^= KEY);
+
+        Ok(buf)
+    }
+
+    fn size_hint(val: &T) -> Option<usize> {
+        E::size_hint(val)
+    }
+}
+
+impl<T, D, const KEY: u8> Decoder<T> for XorCodec<D, KEY>
+where
+    D: Decoder<T, Encoded = [u8]>,
+{
+    type Error = D::Error;
+    type Encoded = [u8];
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        let buf = val.iter().map(|b| b ^ KEY).collect::<Vec<_>>();
+        D::decode(&buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary::FromToBytesCodec;
+
+    #[test]
+    fn test_xor_codec() {
+        type Codec = XorCodec<FromToBytesCodec, 0xa5>;
+
+        let s = String::from("party time 🎉");
+
+        let enc = Codec::encode(&s).unwrap();
+        assert_eq!(enc.len(), s.len());
+        assert!(enc.iter().zip(s.as_bytes()).all(|(e, r)| e != r));
+
+        let dec: String = Codec::decode(enc.as_slice()).unwrap();
+        assert_eq!(dec, s);
+    }
+
+    #[test]
+    fn test_xor_codec_zero_key() {
+        let enc = XorCodec::<FromToBytesCodec, 0>::encode(&42_u32).unwrap();
+        assert_eq!(enc, FromToBytesCodec::encode(&42_u32).unwrap());
+    }
+}
@@ -0,0 +1,124 @@
+use crate::{Decoder, Encoder};
+use bitflags::Flags;
+
+/// Wraps a codec `C` for integers to create a codec for flag sets generated with
+/// [`bitflags!`](bitflags::bitflags) that stores them as their underlying integer.
+///
+/// Encoding encodes [`Flags::bits`] with `C`. Decoding uses [`Flags::from_bits_retain`] so bits
+/// that don't correspond to a defined flag are kept as is. Use
+/// [`FromToStringCodec`](crate::string::FromToStringCodec) for a string codec or
+/// [`FromToBytesCodec`](crate::binary::FromToBytesCodec) for a binary codec.
+///
+/// Only available with the **`bitflags` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{BitflagsCodec, Decoder, Encoder};
+/// # use codee::binary::FromToBytesCodec;
+/// # use codee::string::FromToStringCodec;
+/// # use bitflags::bitflags;
+/// #
+/// bitflags! {
+///     #[derive(Debug, PartialEq)]
+///     struct Permissions: u8 {
+///         const READ = 0b001;
+///         const WRITE = 0b010;
+///         const EXECUTE = 0b100;
+///     }
+/// }
+///
+/// let permissions = Permissions::READ | Permissions::WRITE;
+///
+/// let encoded = BitflagsCodec::<FromToStringCodec>::encode(&permissions).unwrap();
+/// assert_eq!(encoded, "3");
+/// let decoded: Permissions = BitflagsCodec::<FromToStringCodec>::decode(encoded.as_str()).unwrap();
+/// assert_eq!(decoded, permissions);
+///
+/// let encoded = BitflagsCodec::<FromToBytesCodec>::encode(&permissions).unwrap();
+/// assert_eq!(encoded, vec![3]);
+/// let decoded: Permissions = BitflagsCodec::<FromToBytesCodec>::decode(encoded.as_slice()).unwrap();
+/// assert_eq!(decoded, permissions);
+/// ```
+pub struct BitflagsCodec<C>(C);
+
+impl<T, E> Encoder<T> for BitflagsCodec<E>
+where
+    T: Flags,
+    E: Encoder<T::Bits>,
+{
+    type Error = E::Error;
+    type Encoded = E::Encoded;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        E::encode(&val.bits())
+    }
+
+    fn size_hint(val: &T) -> Option<usize> {
+        E::size_hint(&val.bits())
+    }
+}
+
+impl<T, D> Decoder<T> for BitflagsCodec<D>
+where
+    T: Flags,
+    D: Decoder<T::Bits>,
+{
+    type Error = D::Error;
+    type Encoded = D::Encoded;
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        D::decode(val).map(T::from_bits_retain)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary::FromToBytesCodec;
+    use crate::string::FromToStringCodec;
+
+    bitflags::bitflags! {
+        #[derive(Debug, PartialEq)]
+        struct Flags: u32 {
+            const A = 1;
+            const B = 1 << 1;
+            const C = 1 << 16;
+        }
+    }
+
+    #[test]
+    fn test_bitflags_string_codec() {
+        type Codec = BitflagsCodec<FromToStringCodec>;
+
+        let flags = Flags::A | Flags::C;
+        let enc = Codec::encode(&flags).unwrap();
+        assert_eq!(enc, "65537");
+        let dec: Flags = Codec::decode(enc.as_str()).unwrap();
+        assert_eq!(dec, flags);
+
+        let enc = Codec::encode(&Flags::empty()).unwrap();
+        assert_eq!(enc, "0");
+        let dec: Flags = Codec::decode(enc.as_str()).unwrap();
+        assert!(dec.is_empty());
+
+        let dec: Flags = Codec::decode("8").unwrap();
+        assert_eq!(dec.bits(), 8);
+    }
+
+    #[test]
+    fn test_bitflags_binary_codec() {
+        type Codec = BitflagsCodec<FromToBytesCodec>;
+
+        let flags = Flags::A | Flags::B | Flags::C;
+        let enc = Codec::encode(&flags).unwrap();
+        assert_eq!(enc, vec![0, 1, 0, 3]);
+        let dec: Flags = Codec::decode(enc.as_slice()).unwrap();
+        assert_eq!(dec, flags);
+
+        let enc = Codec::encode(&Flags::empty()).unwrap();
+        assert_eq!(enc, vec![0; 4]);
+        let dec: Flags = Codec::decode(enc.as_slice()).unwrap();
+        assert!(dec.is_empty());
+    }
+}
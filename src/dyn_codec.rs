@@ -0,0 +1,178 @@
+use crate::{Decoder, Encoder};
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::marker::PhantomData;
+
+/// Type erased error of [`DynStringCodec`] and [`DynBinaryCodec`].
+///
+/// It can be used with `?` in functions that return `Box<dyn Error>` or `anyhow::Result` and can
+/// be sent across threads. It displays the [`Debug`] output of the wrapped codec's error.
+pub type DynCodecError = Box<dyn Error + Send + Sync>;
+
+/// Keeps the [`Debug`] output of a codec error so that codecs whose errors only implement
+/// [`Debug`] can be used with [`DynCodec`] as well.
+struct DebugError(String);
+
+impl Debug for DebugError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Display for DebugError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Error for DebugError {}
+
+pub(crate) fn dyn_codec_error(err: impl Debug) -> DynCodecError {
+    Box::new(DebugError(format!("{err:?}")))
+}
+
+/// Object safe version of a string codec. This makes it possible to choose a codec at runtime
+/// and store it as `Box<dyn DynStringCodec<T>>`.
+///
+/// Any string codec can be turned into this with [`DynCodec`].
+pub trait DynStringCodec<T> {
+    fn encode(&self, val: &T) -> Result<String, DynCodecError>;
+
+    fn decode(&self, val: &str) -> Result<T, DynCodecError>;
+}
+
+/// Object safe version of a binary codec. This makes it possible to choose a codec at runtime
+/// and store it as `Box<dyn DynBinaryCodec<T>>`.
+///
+/// Any binary codec can be turned into this with [`DynCodec`].
+pub trait DynBinaryCodec<T> {
+    fn encode(&self, val: &T) -> Result<Vec<u8>, DynCodecError>;
+
+    fn decode(&self, val: &[u8]) -> Result<T, DynCodecError>;
+}
+
+/// Turns the codec `C` into an instance that implements [`DynStringCodec`] or [`DynBinaryCodec`].
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{DynCodec, DynBinaryCodec};
+/// # use codee::binary::{BincodeSerdeCodec, MsgpackSerdeCodec};
+/// #
+/// fn codec_from_config(name: &str) -> Box<dyn DynBinaryCodec<Vec<String>>> {
+///     match name {
+///         "msgpack" => DynCodec::<MsgpackSerdeCodec>::boxed(),
+///         _ => DynCodec::<BincodeSerdeCodec>::boxed(),
+///     }
+/// }
+///
+/// let codec = codec_from_config("msgpack");
+///
+/// let original_value = vec!["hello".to_owned(), "world".to_owned()];
+/// let encoded = codec.encode(&original_value).unwrap();
+/// let decoded = codec.decode(&encoded).unwrap();
+///
+/// assert_eq!(decoded, original_value);
+/// ```
+pub struct DynCodec<C>(PhantomData<C>);
+
+impl<C> DynCodec<C> {
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+
+    /// Shortcut for `Box::new(DynCodec::<C>::new())`
+    pub fn boxed() -> Box<Self> {
+        Box::new(Self::new())
+    }
+}
+
+impl<C> Default for DynCodec<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, C> DynStringCodec<T> for DynCodec<C>
+where
+    C: Encoder<T, Encoded = String> + Decoder<T, Encoded = str>,
+    <C as Encoder<T>>::Error: Debug + 'static,
+    <C as Decoder<T>>::Error: Debug + 'static,
+{
+    fn encode(&self, val: &T) -> Result<String, DynCodecError> {
+        C::encode(val).map_err(dyn_codec_error)
+    }
+
+    fn decode(&self, val: &str) -> Result<T, DynCodecError> {
+        C::decode(val).map_err(dyn_codec_error)
+    }
+}
+
+impl<T, C> DynBinaryCodec<T> for DynCodec<C>
+where
+    C: Encoder<T, Encoded = Vec<u8>> + Decoder<T, Encoded = [u8]>,
+    <C as Encoder<T>>::Error: Debug + 'static,
+    <C as Decoder<T>>::Error: Debug + 'static,
+{
+    fn encode(&self, val: &T) -> Result<Vec<u8>, DynCodecError> {
+        C::encode(val).map_err(dyn_codec_error)
+    }
+
+    fn decode(&self, val: &[u8]) -> Result<T, DynCodecError> {
+        C::decode(val).map_err(dyn_codec_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dyn_string_codec() {
+        use crate::string::FromToStringCodec;
+
+        let codec: Box<dyn DynStringCodec<i32>> = DynCodec::<FromToStringCodec>::boxed();
+
+        let enc = codec.encode(&42).unwrap();
+        assert_eq!(enc, "42");
+        assert_eq!(codec.decode(&enc).unwrap(), 42);
+
+        let err = codec.decode("not a number").unwrap_err();
+        assert_eq!(err.to_string(), "ParseIntError { kind: InvalidDigit }");
+    }
+
+    #[cfg(all(feature = "bincode_serde", feature = "msgpack_serde"))]
+    #[test]
+    fn test_dyn_binary_codec() {
+        use crate::binary::{BincodeSerdeCodec, MsgpackSerdeCodec};
+
+        #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Test {
+            s: String,
+            i: i32,
+        }
+        let t = Test {
+            s: String::from("party time 🎉"),
+            i: 42,
+        };
+
+        let codecs: Vec<Box<dyn DynBinaryCodec<Test>>> = vec![
+            DynCodec::<BincodeSerdeCodec>::boxed(),
+            DynCodec::<MsgpackSerdeCodec>::boxed(),
+        ];
+
+        let encoded = codecs
+            .iter()
+            .map(|codec| codec.encode(&t).unwrap())
+            .collect::<Vec<_>>();
+        assert_ne!(encoded[0], encoded[1]);
+
+        for (codec, enc) in codecs.iter().zip(encoded) {
+            assert_eq!(codec.decode(&enc).unwrap(), t);
+        }
+    }
+}
@@ -7,3 +7,91 @@ pub enum CodecError<E, D> {
     #[error("failed to decode: {0}")]
     Decode(D),
 }
+
+impl<E, D> CodecError<E, D> {
+    /// Returns `true` if encoding failed.
+    pub fn is_encode(&self) -> bool {
+        matches!(self, Self::Encode(_))
+    }
+
+    /// Returns `true` if decoding failed.
+    pub fn is_decode(&self) -> bool {
+        matches!(self, Self::Decode(_))
+    }
+
+    /// Returns the encoding error or `None` if decoding failed.
+    pub fn encode_err(self) -> Option<E> {
+        match self {
+            Self::Encode(err) => Some(err),
+            Self::Decode(_) => None,
+        }
+    }
+
+    /// Returns the decoding error or `None` if encoding failed.
+    pub fn decode_err(self) -> Option<D> {
+        match self {
+            Self::Encode(_) => None,
+            Self::Decode(err) => Some(err),
+        }
+    }
+
+    /// Transforms the encoding error with `f` and leaves a decoding error untouched.
+    pub fn map_encode<F, O>(self, f: O) -> CodecError<F, D>
+    where
+        O: FnOnce(E) -> F,
+    {
+        match self {
+            Self::Encode(err) => CodecError::Encode(f(err)),
+            Self::Decode(err) => CodecError::Decode(err),
+        }
+    }
+
+    /// Transforms the decoding error with `f` and leaves an encoding error untouched.
+    pub fn map_decode<F, O>(self, f: O) -> CodecError<E, F>
+    where
+        O: FnOnce(D) -> F,
+    {
+        match self {
+            Self::Encode(err) => CodecError::Encode(err),
+            Self::Decode(err) => CodecError::Decode(f(err)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Error = CodecError<u8, &'static str>;
+
+    #[test]
+    fn test_codec_error_inspection() {
+        let err: Error = CodecError::Encode(42);
+        assert!(err.is_encode());
+        assert!(!err.is_decode());
+        assert_eq!(err.encode_err(), Some(42));
+
+        let err: Error = CodecError::Decode("nope");
+        assert!(!err.is_encode());
+        assert!(err.is_decode());
+        assert_eq!(err.decode_err(), Some("nope"));
+
+        assert_eq!(Error::Encode(42).decode_err(), None);
+        assert_eq!(Error::Decode("nope").encode_err(), None);
+    }
+
+    #[test]
+    fn test_codec_error_map() {
+        let err = Error::Encode(42).map_encode(u32::from);
+        assert!(matches!(err, CodecError::Encode(42_u32)));
+
+        let err = Error::Decode("nope").map_encode(u32::from);
+        assert!(matches!(err, CodecError::Decode("nope")));
+
+        let err = Error::Decode("nope").map_decode(str::len);
+        assert!(matches!(err, CodecError::Decode(4)));
+
+        let err = Error::Encode(42).map_decode(str::len);
+        assert!(matches!(err, CodecError::Encode(42)));
+    }
+}
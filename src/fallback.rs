@@ -0,0 +1,116 @@
+use crate::{Decoder, Encoder};
+use thiserror::Error;
+
+/// Combines two codecs that share the same encoded type. Decoding tries `A` first and falls
+/// back to `B` if `A` fails. Encoding always uses `A`.
+///
+/// This is useful when migrating from one storage format to another: old data written with `B`
+/// can still be read while all new data is written with `A`.
+///
+/// To chain more than two codecs, nest them like `FallbackDecoder<A, FallbackDecoder<B, C>>`.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder, FallbackDecoder};
+/// # use codee::binary::{BincodeSerdeCodec, MsgpackSerdeCodec};
+/// # use serde::{Serialize, Deserialize};
+/// #
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct MyState {
+///     chicken_count: u32,
+///     farm_name: String,
+/// }
+///
+/// let original_value = MyState {
+///     chicken_count: 10,
+///     farm_name: "My Farm".to_owned(),
+/// };
+///
+/// // Old data was stored with bincode
+/// let legacy = BincodeSerdeCodec::encode(&original_value).unwrap();
+///
+/// // New data is stored with msgpack but old data can still be read
+/// let decoded: MyState = FallbackDecoder::<MsgpackSerdeCodec, BincodeSerdeCodec>::decode(&legacy).unwrap();
+///
+/// assert_eq!(decoded, original_value);
+/// ```
+pub struct FallbackDecoder<A, B>(A, B);
+
+#[derive(Error, Debug, PartialEq)]
+#[error("all decoders failed: {first}; {second}")]
+pub struct FallbackDecodeError<EA, EB> {
+    /// The error of the preferred decoder `A`
+    pub first: EA,
+    /// The error of the fallback decoder `B`
+    pub second: EB,
+}
+
+impl<T, A, B> Encoder<T> for FallbackDecoder<A, B>
+where
+    A: Encoder<T>,
+    B: 'static,
+{
+    type Error = A::Error;
+    type Encoded = A::Encoded;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        A::encode(val)
+    }
+}
+
+impl<T, A, B> Decoder<T> for FallbackDecoder<A, B>
+where
+    A: Decoder<T>,
+    B: Decoder<T, Encoded = A::Encoded>,
+{
+    type Error = FallbackDecodeError<A::Error, B::Error>;
+    type Encoded = A::Encoded;
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        A::decode(val)
+            .or_else(|first| B::decode(val).map_err(|second| FallbackDecodeError { first, second }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(feature = "bincode_serde", feature = "msgpack_serde"))]
+    #[test]
+    fn test_fallback_decoder() {
+        use crate::binary::{BincodeSerdeCodec, MsgpackSerdeCodec};
+
+        #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Test {
+            s: String,
+            i: i32,
+        }
+        let t = Test {
+            s: String::from("party time 🎉"),
+            i: 42,
+        };
+
+        type Codec = FallbackDecoder<MsgpackSerdeCodec, BincodeSerdeCodec>;
+
+        let legacy = BincodeSerdeCodec::encode(&t).unwrap();
+        let dec: Test = Codec::decode(&legacy).unwrap();
+        assert_eq!(dec, t);
+
+        let enc = Codec::encode(&t).unwrap();
+        assert_eq!(enc, MsgpackSerdeCodec::encode(&t).unwrap());
+        let dec: Test = Codec::decode(&enc).unwrap();
+        assert_eq!(dec, t);
+    }
+
+    #[test]
+    fn test_fallback_decoder_all_fail() {
+        use crate::string::FromToStringCodec;
+
+        type Codec = FallbackDecoder<FromToStringCodec, FromToStringCodec>;
+
+        let res: Result<i32, _> = Codec::decode("not a number");
+        assert!(res.is_err());
+    }
+}
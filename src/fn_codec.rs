@@ -0,0 +1,72 @@
+/// Creates an ad-hoc codec from two closures without writing the struct and the two `impl` blocks
+/// by hand. This is handy in tests and prototypes.
+///
+/// Since codecs are types, the closures can't capture anything. They need fully annotated
+/// parameter and return types.
+///
+/// ## Example
+///
+/// ```
+/// use codee::{fn_codec, Encoder, Decoder};
+///
+/// fn_codec! {
+///     /// Encodes an `i32` as a hex string
+///     pub struct HexCodec for i32 {
+///         encode: |val: &i32| -> Result<String, ()> { Ok(format!("{val:x}")) },
+///         decode: |val: &str| -> Result<i32, std::num::ParseIntError> { i32::from_str_radix(val, 16) },
+///     }
+/// }
+///
+/// let encoded = HexCodec::encode(&255).unwrap();
+/// assert_eq!(encoded, "ff");
+/// assert_eq!(HexCodec::decode(&encoded), Ok(255));
+/// ```
+#[macro_export]
+macro_rules! fn_codec {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident for $t:ty {
+            encode: |$enc_val:ident: &$enc_t:ty| -> Result<$encoded:ty, $enc_err:ty> $enc_body:block,
+            decode: |$dec_val:ident: &$decoded:ty| -> Result<$dec_t:ty, $dec_err:ty> $dec_body:block $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name;
+
+        impl $crate::Encoder<$t> for $name {
+            type Error = $enc_err;
+            type Encoded = $encoded;
+
+            fn encode($enc_val: &$enc_t) -> Result<Self::Encoded, Self::Error> $enc_body
+        }
+
+        impl $crate::Decoder<$t> for $name {
+            type Error = $dec_err;
+            type Encoded = $decoded;
+
+            fn decode($dec_val: &Self::Encoded) -> Result<$dec_t, Self::Error> $dec_body
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Decoder, Encoder};
+
+    fn_codec! {
+        struct LeBytesCodec for u32 {
+            encode: |val: &u32| -> Result<Vec<u8>, ()> { Ok(val.to_le_bytes().to_vec()) },
+            decode: |val: &[u8]| -> Result<u32, core::array::TryFromSliceError> {
+                Ok(u32::from_le_bytes(val.try_into()?))
+            },
+        }
+    }
+
+    #[test]
+    fn test_fn_codec() {
+        let enc = LeBytesCodec::encode(&42).unwrap();
+        assert_eq!(enc, vec![42, 0, 0, 0]);
+        assert_eq!(LeBytesCodec::decode(&enc).unwrap(), 42);
+        assert!(LeBytesCodec::decode(&enc[..3]).is_err());
+    }
+}
@@ -1,4 +1,6 @@
-use crate::{Decoder, Encoder};
+use crate::{CodecError, Decoder, Encoder};
+use alloc::string::String;
+use alloc::vec::Vec;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -9,6 +11,34 @@ pub enum HybridCoderError<E> {
     Coder(#[from] E),
 }
 
+impl<E> HybridCoderError<E> {
+    /// Returns the error of the underlying codec or `None` if the operation isn't implemented.
+    pub fn into_inner(self) -> Option<E> {
+        match self {
+            Self::Coder(err) => Some(err),
+            Self::NotImplemented(_) => None,
+        }
+    }
+
+    /// Converts an error of [`HybridEncoder`] into [`CodecError::Encode`]. If the operation isn't
+    /// implemented the message is returned as the error instead.
+    pub fn try_into_encode_error<D>(self) -> Result<CodecError<E, D>, &'static str> {
+        match self {
+            Self::Coder(err) => Ok(CodecError::Encode(err)),
+            Self::NotImplemented(msg) => Err(msg),
+        }
+    }
+
+    /// Converts an error of [`HybridDecoder`] into [`CodecError::Decode`]. If the operation isn't
+    /// implemented the message is returned as the error instead.
+    pub fn try_into_decode_error<Enc>(self) -> Result<CodecError<Enc, E>, &'static str> {
+        match self {
+            Self::Coder(err) => Ok(CodecError::Decode(err)),
+            Self::NotImplemented(msg) => Err(msg),
+        }
+    }
+}
+
 pub trait HybridDecoder<T, E: ?Sized> {
     type Error;
 
@@ -108,3 +138,102 @@ where
         Ok(E::encode(val)?)
     }
 }
+
+/// Implemented for the `Encoded` types of encoders to tell binary and string codecs apart.
+/// Used by [`codec_is_binary`].
+pub trait EncodedKind {
+    const IS_BINARY: bool;
+}
+
+impl EncodedKind for Vec<u8> {
+    const IS_BINARY: bool = true;
+}
+
+impl EncodedKind for String {
+    const IS_BINARY: bool = false;
+}
+
+#[cfg(feature = "bytes")]
+impl EncodedKind for bytes::Bytes {
+    const IS_BINARY: bool = true;
+}
+
+/// Returns `true` if the codec `C` encodes `T` into bytes and `false` if it encodes it into a
+/// string.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::codec_is_binary;
+/// # use codee::binary::FromToBytesCodec;
+/// # use codee::string::FromToStringCodec;
+/// #
+/// assert!(codec_is_binary::<FromToBytesCodec, u32>());
+/// assert!(!codec_is_binary::<FromToStringCodec, u32>());
+/// ```
+pub fn codec_is_binary<C, T>() -> bool
+where
+    C: Encoder<T>,
+    C::Encoded: EncodedKind,
+{
+    C::Encoded::IS_BINARY
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary::FromToBytesCodec;
+    use crate::string::FromToStringCodec;
+
+    #[test]
+    fn test_hybrid_coder_error_coder() {
+        let err = <FromToStringCodec as HybridDecoder<i32, str>>::decode_str("x").unwrap_err();
+        assert!(err.into_inner().is_some());
+
+        let err = <FromToStringCodec as HybridDecoder<i32, str>>::decode_str("x").unwrap_err();
+        let err: CodecError<(), _> = err.try_into_decode_error().unwrap();
+        assert!(matches!(err, CodecError::Decode(_)));
+
+        let err = HybridCoderError::Coder(42);
+        let err: CodecError<_, ()> = err.try_into_encode_error().unwrap();
+        assert!(matches!(err, CodecError::Encode(42)));
+    }
+
+    #[test]
+    fn test_hybrid_coder_error_not_implemented() {
+        let err = <FromToBytesCodec as HybridDecoder<i32, [u8]>>::decode_str("42").unwrap_err();
+        assert!(matches!(err, HybridCoderError::NotImplemented(_)));
+        assert!(err.into_inner().is_none());
+
+        let err = <FromToBytesCodec as HybridEncoder<i32, Vec<u8>>>::encode_str(&42).unwrap_err();
+        let msg = err.try_into_encode_error::<()>().unwrap_err();
+        assert_eq!(
+            msg,
+            "You're trying to encode into a string. This codec is binary."
+        );
+
+        let err = <FromToBytesCodec as HybridDecoder<i32, [u8]>>::decode_str("42").unwrap_err();
+        assert!(err.try_into_decode_error::<()>().is_err());
+    }
+
+    #[test]
+    fn test_codec_is_binary() {
+        assert!(codec_is_binary::<FromToBytesCodec, String>());
+        assert!(!codec_is_binary::<FromToStringCodec, String>());
+    }
+
+    #[cfg(all(feature = "bincode_serde", feature = "json_serde"))]
+    #[test]
+    fn test_codec_is_binary_serde() {
+        use crate::binary::BincodeSerdeCodec;
+        use crate::string::JsonSerdeCodec;
+
+        #[derive(serde::Serialize)]
+        struct Test {
+            s: String,
+        }
+
+        assert!(codec_is_binary::<BincodeSerdeCodec, Test>());
+        assert!(!codec_is_binary::<JsonSerdeCodec, Test>());
+    }
+}
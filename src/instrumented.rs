@@ -0,0 +1,178 @@
+use crate::{Decoder, Encoder};
+use std::time::{Duration, Instant};
+
+/// Receives the timings measured by [`InstrumentedCodec`].
+///
+/// Codecs are stateless so the hook is injected by a type parameter that implements this trait.
+/// Both methods do nothing by default. `codec` is the type name of the wrapped codec which makes it
+/// easy to compare several codecs that report to the same hook.
+pub trait TimingHook: 'static {
+    /// Called after every encode, whether it succeeded or not.
+    fn on_encode(codec: &'static str, duration: Duration) {
+        let _ = (codec, duration);
+    }
+
+    /// Called after every decode, whether it succeeded or not.
+    fn on_decode(codec: &'static str, duration: Duration) {
+        let _ = (codec, duration);
+    }
+}
+
+/// Wraps a codec `C` and reports how long every encode and decode takes to the [`TimingHook`] `H`.
+///
+/// The encoded data is the same as with `C` alone. This is useful to find out which codec is
+/// the bottleneck under real load without changing any call sites.
+///
+/// Time is measured with [`std::time::Instant`]. On `wasm32-unknown-unknown` it compiles but
+/// panics when called, so this codec isn't available on that target, i.e. in the browser.
+///
+/// Only available with the **`std` feature** enabled and not on `wasm32-unknown-unknown`.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Decoder, Encoder, InstrumentedCodec, TimingHook};
+/// # use codee::string::FromToStringCodec;
+/// # use std::time::Duration;
+/// #
+/// struct LogTimings;
+///
+/// impl TimingHook for LogTimings {
+///     fn on_encode(codec: &'static str, duration: Duration) {
+///         println!("{codec} encoded in {duration:?}");
+///     }
+///
+///     fn on_decode(codec: &'static str, duration: Duration) {
+///         println!("{codec} decoded in {duration:?}");
+///     }
+/// }
+///
+/// type Codec = InstrumentedCodec<FromToStringCodec, LogTimings>;
+///
+/// let encoded = Codec::encode(&42).unwrap();
+/// assert_eq!(encoded, "42");
+///
+/// let decoded: i32 = Codec::decode(&encoded).unwrap();
+/// assert_eq!(decoded, 42);
+/// ```
+pub struct InstrumentedCodec<C, H>(C, H);
+
+impl<T, C, H> Encoder<T> for InstrumentedCodec<C, H>
+where
+    C: Encoder<T>,
+    H: TimingHook,
+{
+    type Error = C::Error;
+    type Encoded = C::Encoded;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        let start = Instant::now();
+        let result = C::encode(val);
+        H::on_encode(core::any::type_name::<C>(), start.elapsed());
+
+        result
+    }
+
+    fn size_hint(val: &T) -> Option<usize> {
+        C::size_hint(val)
+    }
+}
+
+impl<T, C, H> Decoder<T> for InstrumentedCodec<C, H>
+where
+    C: Decoder<T>,
+    H: TimingHook,
+{
+    type Error = C::Error;
+    type Encoded = C::Encoded;
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        let start = Instant::now();
+        let result = C::decode(val);
+        H::on_decode(core::any::type_name::<C>(), start.elapsed());
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::string::FromToStringCodec;
+    use std::cell::RefCell;
+
+    thread_local! {
+        static TIMINGS: RefCell<Vec<(&'static str, &'static str, Duration)>> =
+            const { RefCell::new(Vec::new()) };
+    }
+
+    struct RecordTimings;
+
+    impl TimingHook for RecordTimings {
+        fn on_encode(codec: &'static str, duration: Duration) {
+            TIMINGS.with_borrow_mut(|timings| timings.push(("encode", codec, duration)));
+        }
+
+        fn on_decode(codec: &'static str, duration: Duration) {
+            TIMINGS.with_borrow_mut(|timings| timings.push(("decode", codec, duration)));
+        }
+    }
+
+    /// Takes at least a millisecond for everything
+    struct SlowCodec;
+
+    impl Encoder<u32> for SlowCodec {
+        type Error = ();
+        type Encoded = String;
+
+        fn encode(val: &u32) -> Result<Self::Encoded, Self::Error> {
+            std::thread::sleep(Duration::from_millis(1));
+            Ok(val.to_string())
+        }
+    }
+
+    impl Decoder<u32> for SlowCodec {
+        type Error = ();
+        type Encoded = str;
+
+        fn decode(val: &Self::Encoded) -> Result<u32, Self::Error> {
+            std::thread::sleep(Duration::from_millis(1));
+            val.parse().map_err(|_| ())
+        }
+    }
+
+    #[test]
+    fn test_instrumented_codec() {
+        type Codec = InstrumentedCodec<SlowCodec, RecordTimings>;
+
+        let enc = Codec::encode(&42).unwrap();
+        assert_eq!(enc, "42");
+        let dec: u32 = Codec::decode(enc.as_str()).unwrap();
+        assert_eq!(dec, 42);
+
+        let dec: Result<u32, _> = Codec::decode("not a number");
+        assert_eq!(dec, Err(()));
+
+        let timings = TIMINGS.take();
+        let kinds: Vec<_> = timings.iter().map(|(kind, _, _)| *kind).collect();
+        assert_eq!(kinds, ["encode", "decode", "decode"]);
+
+        for (_, codec, duration) in timings {
+            assert!(codec.ends_with("SlowCodec"));
+            assert!(duration >= Duration::from_millis(1));
+        }
+    }
+
+    #[test]
+    fn test_instrumented_codec_default_hook() {
+        struct IgnoreTimings;
+        impl TimingHook for IgnoreTimings {}
+
+        type Codec = InstrumentedCodec<FromToStringCodec, IgnoreTimings>;
+
+        let enc = Codec::encode(&42).unwrap();
+        let dec: u32 = Codec::decode(enc.as_str()).unwrap();
+        assert_eq!(dec, 42);
+        assert!(TIMINGS.with_borrow(Vec::is_empty));
+    }
+}
@@ -0,0 +1,31 @@
+/// Provides the secret key for adapters like [`SignedCodec`](crate::binary::SignedCodec) and
+/// [`ChaChaPolyCodec`](crate::binary::ChaChaPolyCodec).
+///
+/// Codecs are stateless so the key can't be passed in when encoding or decoding. Instead it is
+/// injected by a type parameter that implements this trait. Keys that are only known at runtime
+/// can be loaded once into a `static`, for example with `std::sync::OnceLock`.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::KeyProvider;
+/// # use std::sync::OnceLock;
+/// #
+/// struct CookieKey;
+///
+/// impl KeyProvider for CookieKey {
+///     fn key() -> &'static [u8] {
+///         static KEY: OnceLock<Vec<u8>> = OnceLock::new();
+///
+///         KEY.get_or_init(|| {
+///             std::env::var("COOKIE_KEY")
+///                 .expect("COOKIE_KEY must be set")
+///                 .into_bytes()
+///         })
+///     }
+/// }
+/// ```
+pub trait KeyProvider: 'static {
+    /// Returns the secret key.
+    fn key() -> &'static [u8];
+}
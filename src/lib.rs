@@ -32,6 +32,10 @@
 //! store_value::<MyStruct, JsonSerdeCodec>(MyStruct { field: 42 });
 //! ```
 //!
+//! The bound `Encoder<T, Encoded = String> + Decoder<T, Encoded = str>` can be written shorter with
+//! the trait alias [`StringCodec<T>`]. There is also [`BinaryCodec<T>`] for binary codecs and
+//! [`Codec<T>`] for codecs with any encoded type.
+//!
 //! ## Available Codecs
 //!
 //! There are two types of codecs: One that encodes as binary data (`Vec[u8]`) in the module [`binary`] and another type that encodes as
@@ -47,20 +51,56 @@
 //!
 //! #### Adapters
 //!
+//! - [`string::Ascii85`] —
+//!   Wraps a binary codec and makes it a string codec by representing the binary data as an Ascii85 string.
+//!   Optionally with Adobe `<~ ~>` delimiters.
+//! - [`string::Base16384`] —
+//!   Wraps a binary codec and makes it a string codec by representing the binary data as a base16384 string
+//!   of CJK characters.
 //! - [`string::Base64`] —
 //!   Wraps a binary codec and makes it a string codec by representing the binary data as a base64 string.
 //! - [`string::OptionCodec`] —
 //!   Wraps a string codec that encodes `T` to create a codec that encodes `Option<T>`.
+//! - [`string::PercentEncoded`] —
+//!   Wraps a string codec and percent-encodes its output so it can be used in URL paths or queries.
+//! - [`string::RangeCodec`] —
+//!   Wraps a string codec that encodes `T` to create a codec that encodes `Range<T>` as `start..end`.
+//! - [`string::ResultCodec`] —
+//!   Wraps two string codecs that encode `T` and `E` to create a codec that encodes `Result<T, E>`.
+//! - [`string::SeparatedCodec`] —
+//!   Wraps a string codec that encodes `T` to create a codec that encodes `Vec<T>` joined by a separator.
+//! - [`string::StructuredOrStringCodec`] —
+//!   Wraps a string codec and falls back to the raw string if decoding fails. Useful for mixed-content fields.
+//! - [`string::Z85`] —
+//!   Wraps a binary codec and makes it a string codec by representing the binary data as a Z85 string.
 //!
 //! ### Binary Codecs
 //!
 //! Please have a look at the module [`binary`](crate::binary).
 //!
+//! ### Other Adapters
+//!
+//! - [`BitflagsCodec`] —
+//!   Encodes flag sets generated with `bitflags!` as their underlying integer.
+//! - [`FallbackDecoder`] —
+//!   Decodes with one codec and falls back to another one on failure. Useful for migrating storage formats.
+//! - [`InstrumentedCodec`] —
+//!   Reports how long every encode and decode of a wrapped codec takes. Useful for profiling.
+//! - [`SmartPointerCodec`] —
+//!   Wraps a codec that encodes `T` to create a codec that encodes `Box<T>`, `Rc<T>` and `Arc<T>`.
+//! - [`Via`] —
+//!   Encodes a type by converting it to and from a DTO that is encoded with another codec.
+//!
 //! ## Custom Codecs
 //!
 //! If you don't find a suitable codec for your needs, you can implement your own; it's straightforward!
 //! If you want to create a string codec, you can look at [`string::JsonSerdeCodec`] as a starting point.
 //! In case it's a binary codec, have a look at [`binary::BincodeSerdeCodec`].
+//! To write an adapter that wraps another codec, [`binary::XorCodec`] is the simplest example.
+//!
+//! For quick one-off codecs there is also the macro [`fn_codec!`] that creates a codec from two closures.
+//! To store a C-style enum with [`string::FromToStringCodec`] the macro [`from_to_string_codec!`]
+//! implements `Display` and `FromStr` from a list of variants and their string representations.
 //!
 //! ## Versioning
 //!
@@ -81,14 +121,24 @@
 //!   two separate key-value fields in the browser rather than a single field. If a field is missing,
 //!   then the value intentionally would fall back to the default without interfering with the other
 //!   field.
+//!   [`string::MultiFieldCodec`] implements this pattern for you.
 //!
-//! - The `ProstCodec` uses [Protocol buffers](https://protobuf.dev/overview/)
+//! - The `ProstCodec` and `ProtobufCodec` use [Protocol buffers](https://protobuf.dev/overview/)
 //!   designed to solve the problem of long-term storage. It provides semantics for versioning that
 //!   are not present in JSON or other formats.
 //!
+//! - The `PotCodec` uses [pot](https://github.com/khonsulabs/pot), a self-describing binary format
+//!   that stores field names and thus tolerates added, removed and reordered fields.
+//!
+//! - The `MusliDescriptiveCodec` uses musli's self-describing format which can skip unknown fields.
+//!   The more compact `MusliStorageCodec` only tolerates added fields marked with `#[musli(default)]`.
+//!
 //! - The codecs that use serde under the hood can rely on serde or by
 //!   providing their own manual version handling. See the next sections for more details.
 //!
+//! If falling back to the default value is good enough for data in an old or unknown format,
+//! [`DecoderExt::decode_or_default`] saves you from handling the decode error yourself.
+//!
 //! ### Rely on `serde`
 //!
 //! A simple way to avoid complex versioning is to rely on serde's [field attributes](https://serde.rs/field-attrs.html)
@@ -108,6 +158,7 @@
 //! use serde::{Deserialize, Serialize};
 //! use serde_json::json;
 //! use codee::{Encoder, Decoder};
+//! use codee::string::JsonSerdeCodec;
 //!
 //! #[derive(Serialize, Deserialize, Clone, Default, PartialEq)]
 //! pub struct MyState {
@@ -132,7 +183,7 @@
 //!     type Encoded = str;
 //!
 //!     fn decode(stored_value: &Self::Encoded) -> Result<MyState, Self::Error> {
-//!         let mut val: serde_json::Value = serde_json::from_str(stored_value)?;
+//!         let mut val = JsonSerdeCodec::decode_value(stored_value)?;
 //!         // add "greeting": "Hello" to the object if it's missing
 //!         if let Some(obj) = val.as_object_mut() {
 //!             if !obj.contains_key("greeting") {
@@ -148,24 +199,88 @@
 //! // Then use it just like any other codec.
 //! ```
 //!
+//! If the transformation always succeeds, [`string::JsonSerdeCodec::decode_transformed`] does
+//! the parsing and deserializing for you.
+//!
+//! ## Choosing Codecs at Runtime
+//!
+//! The traits [`Encoder`] and [`Decoder`] are not object safe. If you want to choose a codec at runtime,
+//! wrap it in [`DynCodec`] which implements the object safe traits [`DynStringCodec`] and [`DynBinaryCodec`].
+//!
+//! ## Async
+//!
+//! With the **`async` feature** enabled there are the traits [`AsyncEncoder`] and [`AsyncDecoder`].
+//! Any synchronous codec can be used with them by wrapping it in `AsyncCodec`. With the
+//! **`tokio` feature** there is also `SpawnBlockingCodec` that runs the codec on tokio's blocking
+//! thread pool.
+//!
+//! ## `no_std`
+//!
+//! The crate can be used without `std` (but with `alloc`) by disabling the default `std` feature.
+//! The traits and the codecs that don't require a feature flag are available then, as well as
+//! the ones of the features `async`, `base16384`, `bitflags`, `bytes`, `chacha`, `datetime`,
+//! `percent`, `postcard_serde`, `serde_helpers`, `signing` and `uuid`. All other feature flags
+//! enable `std` automatically.
+//!
+//! ```toml
+//! codee = { version = "...", default-features = false, features = ["postcard_serde"] }
+//! ```
+//!
 //! ## Hybrid Codecs
 //!
 //! In case you want to write code that can be used with both, binary and string codecs, there are the
-//! [`HybridDecoder`], [`HybridEncoder`] and [`IsBinary`] traits that are implemented automatically
-//! for all the codecs.
+//! [`HybridDecoder`] and [`HybridEncoder`] traits that are implemented automatically for all the
+//! codecs. To check at runtime whether a codec is binary or a string codec use [`codec_is_binary`].
 //!
 //! To see them in action, you can have a look at [`leptos_use::use_websocket`](https://github.com/Synphonyte/leptos-use/blob/main/src/use_websocket.rs).
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "async")]
+mod async_codec;
 pub mod binary;
+#[cfg(feature = "bitflags")]
+mod bitflags;
+mod dyn_codec;
 mod error;
+mod fallback;
+mod fn_codec;
 mod hybrid;
+#[cfg(all(
+    feature = "std",
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
+mod instrumented;
+#[cfg(any(feature = "signing", feature = "chacha"))]
+mod key_provider;
+#[cfg(feature = "serde_helpers")]
+pub mod serde_helpers;
 #[cfg(feature = "serde_lite")]
 mod serde_lite;
+mod smart_pointer;
 pub mod string;
 mod traits;
+mod via;
 
+#[cfg(feature = "async")]
+pub use async_codec::*;
+#[cfg(feature = "bitflags")]
+pub use bitflags::*;
+pub use dyn_codec::*;
 pub use error::*;
+pub use fallback::*;
 pub use hybrid::*;
+#[cfg(all(
+    feature = "std",
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
+pub use instrumented::*;
+#[cfg(any(feature = "signing", feature = "chacha"))]
+pub use key_provider::*;
 #[cfg(feature = "serde_lite")]
 pub use serde_lite::*;
+pub use smart_pointer::*;
 pub use traits::*;
+pub use via::*;
@@ -0,0 +1,131 @@
+//! Helper modules to use with `#[serde(with = "...")]` on fields of types that serde has no
+//! suitable representation for.
+//!
+//! Only available with the **`serde_helpers` feature** enabled.
+
+/// Serializes a [`Duration`](core::time::Duration) as an integer number of milliseconds.
+///
+/// Sub-millisecond precision is truncated. Durations longer than `u64::MAX` milliseconds fail to
+/// serialize.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::string::JsonSerdeCodec;
+/// # use serde::{Deserialize, Serialize};
+/// # use std::time::Duration;
+/// #
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct Timeout {
+///     #[serde(with = "codee::serde_helpers::duration_millis")]
+///     after: Duration,
+/// }
+///
+/// let timeout = Timeout { after: Duration::from_millis(1500) };
+///
+/// let encoded = JsonSerdeCodec::encode(&timeout).unwrap();
+/// assert_eq!(encoded, r#"{"after":1500}"#);
+///
+/// let decoded: Timeout = JsonSerdeCodec::decode(&encoded).unwrap();
+/// assert_eq!(decoded, timeout);
+/// ```
+pub mod duration_millis {
+    use core::time::Duration;
+    use serde::{ser, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(val: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        let millis = u64::try_from(val.as_millis())
+            .map_err(|_| ser::Error::custom("duration is too long for u64 milliseconds"))?;
+
+        serializer.serialize_u64(millis)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_millis)
+    }
+}
+
+/// Serializes a [`Duration`](core::time::Duration) as an integer number of seconds.
+///
+/// Sub-second precision is truncated.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::string::JsonSerdeCodec;
+/// # use serde::{Deserialize, Serialize};
+/// # use std::time::Duration;
+/// #
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct Session {
+///     #[serde(with = "codee::serde_helpers::duration_secs")]
+///     max_age: Duration,
+/// }
+///
+/// let encoded = JsonSerdeCodec::encode(&Session { max_age: Duration::from_secs(3600) }).unwrap();
+/// assert_eq!(encoded, r#"{"max_age":3600}"#);
+/// ```
+pub mod duration_secs {
+    use core::time::Duration;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(val: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(val.as_secs())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_secs)
+    }
+}
+
+#[cfg(all(test, feature = "json_serde"))]
+mod tests {
+    use crate::string::JsonSerdeCodec;
+    use crate::{Decoder, Encoder};
+    use core::time::Duration;
+
+    #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Test {
+        #[serde(with = "super::duration_millis")]
+        millis: Duration,
+        #[serde(with = "super::duration_secs")]
+        secs: Duration,
+    }
+
+    #[test]
+    fn test_duration_helpers() {
+        let t = Test {
+            millis: Duration::from_millis(1234),
+            secs: Duration::from_secs(56),
+        };
+
+        let enc = JsonSerdeCodec::encode(&t).unwrap();
+        assert_eq!(enc, r#"{"millis":1234,"secs":56}"#);
+
+        let dec: Test = JsonSerdeCodec::decode(&enc).unwrap();
+        assert_eq!(dec, t);
+    }
+
+    #[test]
+    fn test_duration_helpers_truncate() {
+        let t = Test {
+            millis: Duration::from_micros(1_500_999),
+            secs: Duration::from_millis(56_999),
+        };
+
+        let enc = JsonSerdeCodec::encode(&t).unwrap();
+        assert_eq!(enc, r#"{"millis":1500,"secs":56}"#);
+    }
+
+    #[test]
+    fn test_duration_millis_too_long() {
+        let t = Test {
+            millis: Duration::MAX,
+            secs: Duration::ZERO,
+        };
+
+        assert!(JsonSerdeCodec::encode(&t).is_err());
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{Decoder, Encoder};
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::sync::Arc;
+
+/// Wraps a codec that encodes `T` to create a codec that encodes `Box<T>`, `Rc<T>` and `Arc<T>`.
+///
+/// Encoding dereferences the pointer and decoding wraps the decoded value in a new pointer.
+/// A blanket implementation for every codec isn't possible because it would conflict with the
+/// generic implementations of codecs like [`string::JsonSerdeCodec`](crate::string::JsonSerdeCodec).
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder, SmartPointerCodec};
+/// # use codee::string::FromToStringCodec;
+/// # use std::sync::Arc;
+/// #
+/// let original_value = Arc::new(42);
+///
+/// let encoded = SmartPointerCodec::<FromToStringCodec>::encode(&original_value).unwrap();
+/// let decoded: Arc<i32> = SmartPointerCodec::<FromToStringCodec>::decode(encoded.as_str()).unwrap();
+///
+/// assert_eq!(encoded, "42");
+/// assert_eq!(decoded, original_value);
+/// ```
+pub struct SmartPointerCodec<C>(C);
+
+macro_rules! impl_smart_pointer_codec {
+    ($($ptr:ident),*) => {
+        $(
+            impl<T, E> Encoder<$ptr<T>> for SmartPointerCodec<E>
+            where
+                E: Encoder<T>,
+            {
+                type Error = E::Error;
+                type Encoded = E::Encoded;
+
+                fn encode(val: &$ptr<T>) -> Result<Self::Encoded, Self::Error> {
+                    E::encode(val)
+                }
+
+                fn size_hint(val: &$ptr<T>) -> Option<usize> {
+                    E::size_hint(val)
+                }
+            }
+
+            impl<T, D> Decoder<$ptr<T>> for SmartPointerCodec<D>
+            where
+                D: Decoder<T>,
+            {
+                type Error = D::Error;
+                type Encoded = D::Encoded;
+
+                fn decode(val: &Self::Encoded) -> Result<$ptr<T>, Self::Error> {
+                    D::decode(val).map($ptr::new)
+                }
+            }
+        )*
+    };
+}
+
+impl_smart_pointer_codec!(Box, Rc, Arc);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary::FromToBytesCodec;
+
+    #[test]
+    fn test_smart_pointer_codec() {
+        type Codec = SmartPointerCodec<FromToBytesCodec>;
+
+        let enc = Codec::encode(&Box::new(42_u32)).unwrap();
+        assert_eq!(enc, vec![0, 0, 0, 42]);
+        let dec: Box<u32> = Codec::decode(&enc[..]).unwrap();
+        assert_eq!(*dec, 42);
+
+        let enc = Codec::encode(&Rc::new(42_u32)).unwrap();
+        let dec: Rc<u32> = Codec::decode(&enc[..]).unwrap();
+        assert_eq!(*dec, 42);
+    }
+
+    #[cfg(feature = "json_serde")]
+    #[test]
+    fn test_smart_pointer_codec_arc_json() {
+        use crate::string::JsonSerdeCodec;
+
+        #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Test {
+            s: String,
+            i: i32,
+        }
+        let t = Arc::new(Test {
+            s: String::from("party time 🎉"),
+            i: 42,
+        });
+
+        let enc = SmartPointerCodec::<JsonSerdeCodec>::encode(&t).unwrap();
+        assert_eq!(enc, JsonSerdeCodec::encode(&*t).unwrap());
+
+        let dec: Arc<Test> = SmartPointerCodec::<JsonSerdeCodec>::decode(enc.as_str()).unwrap();
+        assert_eq!(dec, t);
+    }
+}
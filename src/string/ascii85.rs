@@ -0,0 +1,224 @@
+use crate::{Decoder, Encoder};
+use alloc::string::String;
+use alloc::vec::Vec;
+use thiserror::Error;
+
+/// Wraps a binary codec and make it a string codec by representing the binary data as an
+/// [Ascii85](https://en.wikipedia.org/wiki/Ascii85) string. Ascii85 is more compact than base64.
+///
+/// Groups of four zero bytes are abbreviated as `z`. With `DELIMITED` set to `true` the output is
+/// wrapped in the Adobe delimiters `<~ ... ~>` that some PDF tooling requires. These delimiters
+/// are then also required and stripped when decoding. Whitespace is ignored when decoding.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::string::Ascii85;
+/// # use codee::binary::FromToBytesCodec;
+/// #
+/// let encoded = Ascii85::<FromToBytesCodec>::encode(&"hello".to_owned()).unwrap();
+/// assert_eq!(encoded, "BOu!rDZ");
+///
+/// let encoded = Ascii85::<FromToBytesCodec, true>::encode(&"hello".to_owned()).unwrap();
+/// assert_eq!(encoded, "<~BOu!rDZ~>");
+///
+/// let decoded: String = Ascii85::<FromToBytesCodec, true>::decode(&encoded).unwrap();
+/// assert_eq!(decoded, "hello");
+/// ```
+pub struct Ascii85<C, const DELIMITED: bool = false>(C);
+
+#[derive(Error, Debug, PartialEq)]
+pub enum Ascii85DecodeError<Err> {
+    #[error("missing <~ ~> delimiters")]
+    MissingDelimiters,
+    #[error("invalid ascii85 character {0:?}")]
+    InvalidCharacter(char),
+    #[error("invalid length of the last ascii85 group")]
+    InvalidLength,
+    #[error("ascii85 group exceeds 32 bits")]
+    Overflow,
+    #[error("failed to decode: {0}")]
+    Decoder(Err),
+}
+
+impl<T, E, const DELIMITED: bool> Encoder<T> for Ascii85<E, DELIMITED>
+where
+    E: Encoder<T, Encoded = Vec<u8>>,
+{
+    type Error = E::Error;
+    type Encoded = String;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        let buf = E::encode(val)?;
+
+        let mut result = String::with_capacity(buf.len().div_ceil(4) * 5 + 4);
+        if DELIMITED {
+            result.push_str("<~");
+        }
+
+        for chunk in buf.chunks(4) {
+            let mut group = [0; 4];
+            group[..chunk.len()].copy_from_slice(chunk);
+            let mut n = u32::from_be_bytes(group);
+
+            if n == 0 && chunk.len() == 4 {
+                result.push('z');
+                continue;
+            }
+
+            let mut digits = [0; 5];
+            for digit in digits.iter_mut().rev() {
+                *digit = b'!' + (n % 85) as u8;
+                n /= 85;
+            }
+            result.extend(digits[..chunk.len() + 1].iter().map(|&d| d as char));
+        }
+
+        if DELIMITED {
+            result.push_str("~>");
+        }
+
+        Ok(result)
+    }
+}
+
+impl<T, D, const DELIMITED: bool> Decoder<T> for Ascii85<D, DELIMITED>
+where
+    D: Decoder<T, Encoded = [u8]>,
+{
+    type Error = Ascii85DecodeError<D::Error>;
+    type Encoded = str;
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        let val = if DELIMITED {
+            val.trim()
+                .strip_prefix("<~")
+                .and_then(|val| val.strip_suffix("~>"))
+                .ok_or(Ascii85DecodeError::MissingDelimiters)?
+        } else {
+            val
+        };
+
+        let buf = decode_ascii85(val)?;
+
+        D::decode(&buf).map_err(Ascii85DecodeError::Decoder)
+    }
+}
+
+fn decode_ascii85<Err>(val: &str) -> Result<Vec<u8>, Ascii85DecodeError<Err>> {
+    let mut buf = Vec::with_capacity(val.len() / 5 * 4 + 4);
+
+    let mut group = [0_u8; 5];
+    let mut len = 0;
+
+    for c in val.chars().filter(|c| !c.is_ascii_whitespace()) {
+        match c {
+            'z' if len == 0 => buf.extend_from_slice(&[0; 4]),
+            '!'..='u' => {
+                group[len] = c as u8 - b'!';
+                len += 1;
+
+                if len == 5 {
+                    buf.extend_from_slice(&group_to_bytes(&group)?);
+                    len = 0;
+                }
+            }
+            _ => return Err(Ascii85DecodeError::InvalidCharacter(c)),
+        }
+    }
+
+    match len {
+        0 => {}
+        1 => return Err(Ascii85DecodeError::InvalidLength),
+        _ => {
+            // pad with the highest digit `u` so the truncated bytes round correctly
+            group[len..].fill(84);
+            buf.extend_from_slice(&group_to_bytes(&group)?[..len - 1]);
+        }
+    }
+
+    Ok(buf)
+}
+
+fn group_to_bytes<Err>(group: &[u8; 5]) -> Result<[u8; 4], Ascii85DecodeError<Err>> {
+    let n = group
+        .iter()
+        .fold(0_u64, |acc, &digit| acc * 85 + digit as u64);
+
+    u32::try_from(n)
+        .map(u32::to_be_bytes)
+        .map_err(|_| Ascii85DecodeError::Overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary::FromToBytesCodec;
+
+    #[test]
+    fn test_ascii85_codec() {
+        for len in 0..=9 {
+            let s = "abcdefghi"[..len].to_owned();
+
+            let enc = Ascii85::<FromToBytesCodec>::encode(&s).unwrap();
+            assert_eq!(
+                enc.len(),
+                len / 4 * 5 + if len % 4 > 0 { len % 4 + 1 } else { 0 }
+            );
+
+            let dec: String = Ascii85::<FromToBytesCodec>::decode(&enc).unwrap();
+            assert_eq!(dec, s);
+        }
+
+        let enc = Ascii85::<FromToBytesCodec>::encode(&0_u64).unwrap();
+        assert_eq!(enc, "zz");
+        let dec: u64 = Ascii85::<FromToBytesCodec>::decode(&enc).unwrap();
+        assert_eq!(dec, 0);
+
+        let enc = Ascii85::<FromToBytesCodec>::encode(&"Man is d".to_owned()).unwrap();
+        assert_eq!(enc, "9jqo^BlbD-");
+
+        let dec: String = Ascii85::<FromToBytesCodec>::decode("9jqo^\n BlbD-").unwrap();
+        assert_eq!(dec, "Man is d");
+    }
+
+    #[test]
+    fn test_ascii85_codec_delimited() {
+        let s = "party time 🎉".to_owned();
+
+        let enc = Ascii85::<FromToBytesCodec, true>::encode(&s).unwrap();
+        assert!(enc.starts_with("<~") && enc.ends_with("~>"));
+        assert_eq!(
+            &enc[2..enc.len() - 2],
+            Ascii85::<FromToBytesCodec>::encode(&s).unwrap()
+        );
+
+        let dec: String = Ascii85::<FromToBytesCodec, true>::decode(&enc).unwrap();
+        assert_eq!(dec, s);
+
+        let dec: Result<String, _> = Ascii85::<FromToBytesCodec, true>::decode(&enc[2..]);
+        assert!(matches!(dec, Err(Ascii85DecodeError::MissingDelimiters)));
+    }
+
+    #[test]
+    fn test_ascii85_codec_invalid() {
+        let dec: Result<String, _> = Ascii85::<FromToBytesCodec>::decode("9jqo^B");
+        assert!(matches!(dec, Err(Ascii85DecodeError::InvalidLength)));
+
+        let dec: Result<String, _> = Ascii85::<FromToBytesCodec>::decode("9jqo~");
+        assert!(matches!(
+            dec,
+            Err(Ascii85DecodeError::InvalidCharacter('~'))
+        ));
+
+        let dec: Result<String, _> = Ascii85::<FromToBytesCodec>::decode("9jzqo");
+        assert!(matches!(
+            dec,
+            Err(Ascii85DecodeError::InvalidCharacter('z'))
+        ));
+
+        let dec: Result<String, _> = Ascii85::<FromToBytesCodec>::decode("uuuuu");
+        assert!(matches!(dec, Err(Ascii85DecodeError::Overflow)));
+    }
+}
@@ -0,0 +1,195 @@
+use crate::{Decoder, Encoder};
+use alloc::string::String;
+use alloc::vec::Vec;
+use thiserror::Error;
+
+/// Wraps a binary codec and make it a string codec by representing the binary data as a
+/// [base16384](https://github.com/fumiama/base16384) string.
+///
+/// Every character is a CJK ideograph from `U+4E00` to `U+8DFF` that carries 14 bits, so seven
+/// bytes are stored in four characters. This is useful where the length is limited by the number
+/// of characters instead of bytes. In UTF-8 every character takes three bytes though, which makes
+/// the output larger than base64 in terms of bytes.
+///
+/// If the length of the data is not a multiple of seven, the last character is a marker from
+/// `U+3D01` to `U+3D06` that stores the number of remaining bytes.
+///
+/// Only available with the **`base16384` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::string::Base16384;
+/// # use codee::binary::FromToBytesCodec;
+/// #
+/// let encoded = Base16384::<FromToBytesCodec>::encode(&"hello, world".to_owned()).unwrap();
+/// assert_eq!(encoded.chars().count(), 8);
+///
+/// let decoded: String = Base16384::<FromToBytesCodec>::decode(&encoded).unwrap();
+/// assert_eq!(decoded, "hello, world");
+/// ```
+pub struct Base16384<C>(C);
+
+#[derive(Error, Debug, PartialEq)]
+pub enum Base16384DecodeError<Err> {
+    #[error("invalid base16384 character {0:?}")]
+    InvalidCharacter(char),
+    #[error("invalid number of base16384 characters")]
+    InvalidLength,
+    #[error("failed to decode: {0}")]
+    Decoder(Err),
+}
+
+const FIRST_CHAR: u32 = 0x4E00;
+const LAST_CHAR: u32 = FIRST_CHAR + 0x3FFF;
+const MARKER: u32 = 0x3D00;
+
+/// Number of characters needed for `len` bytes.
+fn char_count(len: usize) -> usize {
+    (len * 8).div_ceil(14)
+}
+
+impl<T, E> Encoder<T> for Base16384<E>
+where
+    E: Encoder<T, Encoded = Vec<u8>>,
+{
+    type Error = E::Error;
+    type Encoded = String;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        let buf = E::encode(val)?;
+
+        let mut result = String::with_capacity((char_count(buf.len()) + 1) * 3);
+        let mut acc: u32 = 0;
+        let mut bits = 0;
+
+        let mut push = |value: u32| {
+            result.push(char::from_u32(FIRST_CHAR + value).expect("is in the CJK range"));
+        };
+
+        for &byte in &buf {
+            acc = (acc << 8) | byte as u32;
+            bits += 8;
+
+            if bits >= 14 {
+                bits -= 14;
+                push((acc >> bits) & 0x3FFF);
+            }
+        }
+
+        if bits > 0 {
+            push((acc << (14 - bits)) & 0x3FFF);
+        }
+
+        let remainder = buf.len() % 7;
+        if remainder > 0 {
+            result.push(char::from_u32(MARKER + remainder as u32).expect("is a valid char"));
+        }
+
+        Ok(result)
+    }
+}
+
+impl<T, D> Decoder<T> for Base16384<D>
+where
+    D: Decoder<T, Encoded = [u8]>,
+{
+    type Error = Base16384DecodeError<D::Error>;
+    type Encoded = str;
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        let (data, remainder) = match val.chars().next_back() {
+            Some(c) if (MARKER + 1..MARKER + 7).contains(&(c as u32)) => (
+                &val[..val.len() - c.len_utf8()],
+                (c as u32 - MARKER) as usize,
+            ),
+            _ => (val, 0),
+        };
+
+        let data_chars = data.chars().count();
+        let full_chars = data_chars
+            .checked_sub(char_count(remainder))
+            .filter(|full_chars| full_chars % 4 == 0)
+            .ok_or(Base16384DecodeError::InvalidLength)?;
+        let len = full_chars / 4 * 7 + remainder;
+
+        let mut buf = Vec::with_capacity(len);
+        let mut acc: u32 = 0;
+        let mut bits = 0;
+
+        for c in data.chars() {
+            if !(FIRST_CHAR..=LAST_CHAR).contains(&(c as u32)) {
+                return Err(Base16384DecodeError::InvalidCharacter(c));
+            }
+
+            acc = (acc << 14) | (c as u32 - FIRST_CHAR);
+            bits += 14;
+
+            while bits >= 8 && buf.len() < len {
+                bits -= 8;
+                buf.push((acc >> bits) as u8);
+            }
+        }
+
+        D::decode(&buf).map_err(Base16384DecodeError::Decoder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary::FromToBytesCodec;
+
+    type Codec = Base16384<FromToBytesCodec>;
+
+    #[test]
+    fn test_base16384_codec() {
+        for len in 0..30 {
+            let s = "party time 🎉".repeat(3);
+            if !s.is_char_boundary(len) {
+                continue;
+            }
+            let s = s[..len].to_owned();
+
+            let enc = Codec::encode(&s).unwrap();
+            let dec: String = Codec::decode(&enc).unwrap();
+            assert_eq!(dec, s);
+        }
+    }
+
+    #[test]
+    fn test_base16384_codec_format() {
+        assert_eq!(Codec::encode(&String::new()).unwrap(), "");
+        assert_eq!(Codec::encode(&"\0".to_owned()).unwrap(), "\u{4E00}\u{3D01}");
+        assert_eq!(
+            Codec::encode(&u64::MAX).unwrap(),
+            "\u{8DFF}\u{8DFF}\u{8DFF}\u{8DFF}\u{8DC0}\u{3D01}"
+        );
+    }
+
+    #[test]
+    fn test_base16384_codec_fewer_chars_than_base64() {
+        let s = "party time 🎉".repeat(10);
+
+        let enc = Codec::encode(&s).unwrap();
+        let base64_len = s.len().div_ceil(3) * 4;
+        assert!(enc.chars().count() * 2 < base64_len);
+    }
+
+    #[test]
+    fn test_base16384_codec_invalid() {
+        let dec: Result<String, _> = Codec::decode("\u{4E00}a\u{4E00}\u{4E00}");
+        assert!(matches!(
+            dec,
+            Err(Base16384DecodeError::InvalidCharacter('a'))
+        ));
+
+        let dec: Result<String, _> = Codec::decode("\u{4E00}\u{4E00}");
+        assert!(matches!(dec, Err(Base16384DecodeError::InvalidLength)));
+
+        // marker for 6 remaining bytes needs 4 characters
+        let dec: Result<String, _> = Codec::decode("\u{4E00}\u{3D06}");
+        assert!(matches!(dec, Err(Base16384DecodeError::InvalidLength)));
+    }
+}
@@ -35,6 +35,28 @@ use thiserror::Error;
 /// ```
 pub struct Base64<C>(C);
 
+/// A string codec that encodes values as compact MessagePack and represents the bytes as a base64
+/// string. This is a shortcut for `Base64<MsgpackSerdeCodec>`.
+///
+/// Useful to put a compact payload into a JSON string field of an API that only accepts text.
+///
+/// Only available with the **`base64` and `msgpack_serde` features** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::string::MsgpackBase64Codec;
+/// #
+/// let encoded: String = MsgpackBase64Codec::encode(&vec![1, 2, 3]).unwrap();
+/// assert_eq!(encoded, "kwECAw==");
+///
+/// let decoded: Vec<u8> = MsgpackBase64Codec::decode(&encoded).unwrap();
+/// assert_eq!(decoded, vec![1, 2, 3]);
+/// ```
+#[cfg(feature = "msgpack_serde")]
+pub type MsgpackBase64Codec = Base64<crate::binary::MsgpackSerdeCodec>;
+
 #[derive(Error, Debug, PartialEq)]
 pub enum Base64DecodeError<Err> {
     #[error("failed to decode base64: {0}")]
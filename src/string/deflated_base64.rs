@@ -0,0 +1,232 @@
+use crate::{Decoder, Encoder};
+use base64::Engine;
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+use flate2::Compression;
+use std::io::{Read, Write};
+use std::string::FromUtf8Error;
+use thiserror::Error;
+
+/// Wraps a string codec, compresses its output with raw deflate and represents the result as a
+/// base64 string.
+///
+/// This is useful to store long or repetitive text compactly in a single string field. For short
+/// values the base64 overhead can make the result longer than the uncompressed string.
+///
+/// Only available with the **`deflate` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::string::{DeflatedBase64, FromToStringCodec};
+/// #
+/// let original_value = "la".repeat(100);
+///
+/// let encoded = DeflatedBase64::<FromToStringCodec>::encode(&original_value).unwrap();
+/// assert!(encoded.len() < original_value.len());
+///
+/// let decoded: String = DeflatedBase64::<FromToStringCodec>::decode(&encoded).unwrap();
+/// assert_eq!(decoded, original_value);
+/// ```
+pub struct DeflatedBase64<C>(C);
+
+#[derive(Error, Debug)]
+pub enum DeflatedBase64EncodeError<Err> {
+    #[error("failed to encode: {0}")]
+    Encoder(Err),
+    #[error("failed to compress: {0}")]
+    Compress(#[from] std::io::Error),
+}
+
+#[derive(Error, Debug)]
+pub enum DeflatedBase64DecodeError<Err> {
+    #[error("failed to decode base64: {0}")]
+    DecodeBase64(#[from] base64::DecodeError),
+    #[error("failed to decompress: {0}")]
+    Decompress(#[from] std::io::Error),
+    #[error("decompressed data is not valid UTF-8: {0}")]
+    InvalidUtf8(#[from] FromUtf8Error),
+    #[error("failed to decode: {0}")]
+    Decoder(Err),
+}
+
+impl<T, E> Encoder<T> for DeflatedBase64<E>
+where
+    E: Encoder<T, Encoded = String>,
+{
+    type Error = DeflatedBase64EncodeError<E::Error>;
+    type Encoded = String;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        let text = E::encode(val).map_err(DeflatedBase64EncodeError::Encoder)?;
+        deflate_base64(&text, &base64::engine::general_purpose::STANDARD)
+    }
+}
+
+impl<T, D> Decoder<T> for DeflatedBase64<D>
+where
+    D: Decoder<T, Encoded = str>,
+{
+    type Error = DeflatedBase64DecodeError<D::Error>;
+    type Encoded = str;
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        let text = inflate_base64(val, &base64::engine::general_purpose::STANDARD)?;
+        D::decode(&text).map_err(DeflatedBase64DecodeError::Decoder)
+    }
+}
+
+/// Like [`DeflatedBase64`] but uses the URL-safe base64 alphabet without padding
+/// ([RFC 4648 §5](https://datatracker.ietf.org/doc/html/rfc4648#section-5)).
+///
+/// The output only contains `A-Z`, `a-z`, `0-9`, `-` and `_`, so it can be put into a URL query
+/// string or path without any escaping. This is a common pattern to share state like the content
+/// of an editor in a link.
+///
+/// Only available with the **`deflate` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::string::{UrlDeflateCodec, FromToStringCodec};
+/// #
+/// let original_value = "la?".repeat(100);
+///
+/// let encoded = UrlDeflateCodec::<FromToStringCodec>::encode(&original_value).unwrap();
+/// assert!(encoded.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+///
+/// let decoded: String = UrlDeflateCodec::<FromToStringCodec>::decode(&encoded).unwrap();
+/// assert_eq!(decoded, original_value);
+/// ```
+pub struct UrlDeflateCodec<C>(C);
+
+impl<T, E> Encoder<T> for UrlDeflateCodec<E>
+where
+    E: Encoder<T, Encoded = String>,
+{
+    type Error = DeflatedBase64EncodeError<E::Error>;
+    type Encoded = String;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        let text = E::encode(val).map_err(DeflatedBase64EncodeError::Encoder)?;
+        deflate_base64(&text, &base64::engine::general_purpose::URL_SAFE_NO_PAD)
+    }
+}
+
+impl<T, D> Decoder<T> for UrlDeflateCodec<D>
+where
+    D: Decoder<T, Encoded = str>,
+{
+    type Error = DeflatedBase64DecodeError<D::Error>;
+    type Encoded = str;
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        let text = inflate_base64(val, &base64::engine::general_purpose::URL_SAFE_NO_PAD)?;
+        D::decode(&text).map_err(DeflatedBase64DecodeError::Decoder)
+    }
+}
+
+fn deflate_base64<Err>(
+    text: &str,
+    engine: &impl Engine,
+) -> Result<String, DeflatedBase64EncodeError<Err>> {
+    let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(text.as_bytes())?;
+    let deflated = encoder.finish()?;
+
+    Ok(engine.encode(deflated))
+}
+
+fn inflate_base64<Err>(
+    val: &str,
+    engine: &impl Engine,
+) -> Result<String, DeflatedBase64DecodeError<Err>> {
+    let deflated = engine.decode(val)?;
+
+    let mut text = Vec::new();
+    DeflateDecoder::new(deflated.as_slice()).read_to_end(&mut text)?;
+
+    Ok(String::from_utf8(text)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::string::FromToStringCodec;
+
+    #[cfg(feature = "json_serde")]
+    #[test]
+    fn test_deflated_base64_json_codec() {
+        use crate::string::JsonSerdeCodec;
+
+        #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Test {
+            s: String,
+            list: Vec<i32>,
+        }
+        let t = Test {
+            s: String::from("party time 🎉").repeat(50),
+            list: vec![42; 100],
+        };
+
+        let enc = DeflatedBase64::<JsonSerdeCodec>::encode(&t).unwrap();
+        assert!(enc.len() * 4 < JsonSerdeCodec::encode(&t).unwrap().len());
+
+        let dec: Test = DeflatedBase64::<JsonSerdeCodec>::decode(&enc).unwrap();
+        assert_eq!(dec, t);
+    }
+
+    #[test]
+    fn test_deflated_base64_codec_errors() {
+        type Codec = DeflatedBase64<FromToStringCodec>;
+
+        let dec: Result<i32, _> = Codec::decode("not base64!");
+        assert!(matches!(
+            dec,
+            Err(DeflatedBase64DecodeError::DecodeBase64(_))
+        ));
+
+        let enc = Codec::encode(&"not a number".to_owned()).unwrap();
+        let dec: Result<i32, _> = Codec::decode(&enc);
+        assert!(matches!(dec, Err(DeflatedBase64DecodeError::Decoder(_))));
+
+        // valid deflate stream of the bytes [0xff, 0xfe]
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&[0xff, 0xfe]).unwrap();
+        let invalid_utf8 =
+            base64::engine::general_purpose::STANDARD.encode(encoder.finish().unwrap());
+        let dec: Result<String, _> = Codec::decode(&invalid_utf8);
+        assert!(matches!(
+            dec,
+            Err(DeflatedBase64DecodeError::InvalidUtf8(_))
+        ));
+    }
+
+    #[cfg(feature = "json_serde")]
+    #[test]
+    fn test_url_deflate_json_codec() {
+        use crate::string::JsonSerdeCodec;
+
+        #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct EditorState {
+            code: String,
+            cursor: (u32, u32),
+        }
+        let state = EditorState {
+            code: String::from("fn main() {\n    println!(\"party time 🎉 ?&=/+\");\n}\n")
+                .repeat(20),
+            cursor: (3, 14),
+        };
+
+        let enc = UrlDeflateCodec::<JsonSerdeCodec>::encode(&state).unwrap();
+        assert!(enc
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+        assert!(enc.len() < JsonSerdeCodec::encode(&state).unwrap().len());
+
+        let dec: EditorState = UrlDeflateCodec::<JsonSerdeCodec>::decode(&enc).unwrap();
+        assert_eq!(dec, state);
+    }
+}
@@ -0,0 +1,112 @@
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use thiserror::Error;
+
+/// Error returned by the [`FromStr`](std::str::FromStr) implementation generated by
+/// [`from_to_string_codec!`](crate::from_to_string_codec) when the string doesn't match any variant.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("unknown variant `{0}`")]
+pub struct UnknownVariantError(pub String);
+
+impl From<&str> for UnknownVariantError {
+    fn from(val: &str) -> Self {
+        Self(val.to_owned())
+    }
+}
+
+/// Implements [`Display`](std::fmt::Display) and [`FromStr`](std::str::FromStr) for a C-style
+/// enum from a list of variants and their string representations. This makes the enum usable
+/// with [`FromToStringCodec`](crate::string::FromToStringCodec) without depending on serde.
+///
+/// Decoding a string that isn't in the list returns an [`UnknownVariantError`].
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{from_to_string_codec, Encoder, Decoder};
+/// # use codee::string::{FromToStringCodec, UnknownVariantError};
+/// #
+/// #[derive(Debug, PartialEq)]
+/// enum Theme {
+///     Light,
+///     Dark,
+/// }
+///
+/// from_to_string_codec! {
+///     Theme {
+///         Light => "light",
+///         Dark => "dark",
+///     }
+/// }
+///
+/// assert_eq!(FromToStringCodec::encode(&Theme::Dark), Ok("dark".to_owned()));
+/// assert_eq!(FromToStringCodec::decode("light"), Ok(Theme::Light));
+///
+/// let decoded: Result<Theme, _> = FromToStringCodec::decode("blue");
+/// assert_eq!(decoded, Err(UnknownVariantError("blue".to_owned())));
+/// ```
+#[macro_export]
+macro_rules! from_to_string_codec {
+    (
+        $t:ty {
+            $($variant:ident => $s:literal),* $(,)?
+        }
+    ) => {
+        impl ::core::fmt::Display for $t {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str(match self {
+                    $(Self::$variant => $s,)*
+                })
+            }
+        }
+
+        impl ::core::str::FromStr for $t {
+            type Err = $crate::string::UnknownVariantError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $($s => Ok(Self::$variant),)*
+                    _ => Err(s.into()),
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::string::FromToStringCodec;
+    use crate::{Decoder, Encoder};
+
+    #[derive(Debug, PartialEq)]
+    enum Fruit {
+        Apple,
+        Banana,
+        Cherry,
+    }
+
+    from_to_string_codec! {
+        Fruit {
+            Apple => "apple",
+            Banana => "banana",
+            Cherry => "cherry",
+        }
+    }
+
+    #[test]
+    fn test_from_to_string_codec_macro() {
+        for fruit in [Fruit::Apple, Fruit::Banana, Fruit::Cherry] {
+            let enc = FromToStringCodec::encode(&fruit).unwrap();
+            let dec: Fruit = FromToStringCodec::decode(&enc).unwrap();
+            assert_eq!(dec, fruit);
+        }
+        assert_eq!(
+            FromToStringCodec::encode(&Fruit::Banana),
+            Ok("banana".to_owned())
+        );
+
+        let dec: Result<Fruit, _> = FromToStringCodec::decode("durian");
+        assert_eq!(dec, Err(UnknownVariantError("durian".to_owned())));
+    }
+}
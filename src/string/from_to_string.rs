@@ -1,5 +1,6 @@
 use crate::{Decoder, Encoder};
-use std::str::FromStr;
+use alloc::string::{String, ToString};
+use core::str::FromStr;
 
 /// A string codec that relies on [`FromStr`] and [`ToString`]. It can encode anything that
 /// implements [`ToString`] and decode anything that implements [`FromStr`].
@@ -19,8 +20,26 @@ use std::str::FromStr;
 /// #    view! { }
 /// # }
 /// ```
+///
+/// Decoding is strict: surrounding whitespace is passed on to [`FromStr`] which makes most
+/// types like integers fail to parse. Use [`TrimmedFromToStringCodec`] to accept it.
 pub struct FromToStringCodec;
 
+/// Like [`FromToStringCodec`] but trims ASCII whitespace from both ends before decoding.
+///
+/// Useful for values typed in by users where a trailing newline or space is common.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::string::TrimmedFromToStringCodec;
+/// #
+/// let decoded: i32 = TrimmedFromToStringCodec::decode(" 42\n").unwrap();
+/// assert_eq!(decoded, 42);
+/// ```
+pub struct TrimmedFromToStringCodec;
+
 impl<T: ToString> Encoder<T> for FromToStringCodec {
     type Error = ();
     type Encoded = String;
@@ -39,6 +58,24 @@ impl<T: FromStr> Decoder<T> for FromToStringCodec {
     }
 }
 
+impl<T: ToString> Encoder<T> for TrimmedFromToStringCodec {
+    type Error = ();
+    type Encoded = String;
+
+    fn encode(val: &T) -> Result<String, Self::Error> {
+        FromToStringCodec::encode(val)
+    }
+}
+
+impl<T: FromStr> Decoder<T> for TrimmedFromToStringCodec {
+    type Error = T::Err;
+    type Encoded = str;
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        T::from_str(val.trim_matches(|c: char| c.is_ascii_whitespace()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -49,4 +86,28 @@ mod tests {
         assert_eq!(FromToStringCodec::encode(&s), Ok(s.clone()));
         assert_eq!(FromToStringCodec::decode(&s), Ok(s));
     }
+
+    #[test]
+    fn test_string_codec_whitespace() {
+        let dec: Result<i32, _> = FromToStringCodec::decode("42");
+        assert_eq!(dec, Ok(42));
+        let dec: Result<i32, _> = FromToStringCodec::decode(" 42 ");
+        assert!(dec.is_err());
+        let dec: Result<i32, _> = FromToStringCodec::decode("42\n");
+        assert!(dec.is_err());
+    }
+
+    #[test]
+    fn test_trimmed_string_codec() {
+        let dec: Result<i32, _> = TrimmedFromToStringCodec::decode("42");
+        assert_eq!(dec, Ok(42));
+        let dec: Result<i32, _> = TrimmedFromToStringCodec::decode(" 42 ");
+        assert_eq!(dec, Ok(42));
+        let dec: Result<i32, _> = TrimmedFromToStringCodec::decode("\t42\r\n");
+        assert_eq!(dec, Ok(42));
+        let dec: Result<i32, _> = TrimmedFromToStringCodec::decode("4 2");
+        assert!(dec.is_err());
+
+        assert_eq!(TrimmedFromToStringCodec::encode(&42), Ok("42".to_owned()));
+    }
 }
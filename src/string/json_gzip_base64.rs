@@ -0,0 +1,112 @@
+use crate::{Decoder, Encoder};
+use base64::Engine;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+use thiserror::Error;
+
+/// A codec that encodes values as JSON with [`serde_json`], compresses the JSON with gzip and
+/// represents the result as a base64 string.
+///
+/// This is a common pattern to store data compactly in a text field.
+///
+/// Only available with the **`json_serde`, `gzip` and `base64` features** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::string::JsonGzipBase64Codec;
+/// # use serde::{Deserialize, Serialize};
+/// #
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct MyState {
+///     chicken_count: u32,
+///     farm_name: String,
+/// }
+///
+/// let original_value = MyState {
+///     chicken_count: 10,
+///     farm_name: "My Farm".to_owned(),
+/// };
+///
+/// let encoded: String = JsonGzipBase64Codec::encode(&original_value).unwrap();
+/// let decoded: MyState = JsonGzipBase64Codec::decode(&encoded).unwrap();
+///
+/// assert_eq!(decoded, original_value);
+/// ```
+pub struct JsonGzipBase64Codec;
+
+#[derive(Error, Debug)]
+pub enum JsonGzipBase64Error {
+    #[error("failed to (de)serialize JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("failed to (de)compress gzip: {0}")]
+    Gzip(#[from] std::io::Error),
+    #[error("failed to decode base64: {0}")]
+    DecodeBase64(#[from] base64::DecodeError),
+}
+
+impl<T: Serialize> Encoder<T> for JsonGzipBase64Codec {
+    type Error = JsonGzipBase64Error;
+    type Encoded = String;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        let json = serde_json::to_vec(val)?;
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&json)?;
+        let gzip = encoder.finish()?;
+
+        Ok(base64::engine::general_purpose::STANDARD.encode(gzip))
+    }
+}
+
+impl<T> Decoder<T> for JsonGzipBase64Codec
+where
+    for<'de> T: Deserialize<'de>,
+{
+    type Error = JsonGzipBase64Error;
+    type Encoded = str;
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        let gzip = base64::engine::general_purpose::STANDARD.decode(val)?;
+
+        let mut json = Vec::new();
+        GzDecoder::new(gzip.as_slice()).read_to_end(&mut json)?;
+
+        Ok(serde_json::from_slice(&json)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::string::JsonSerdeCodec;
+
+    #[test]
+    fn test_json_gzip_base64_codec() {
+        #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+        struct Test {
+            s: String,
+            i: i32,
+        }
+        let t = Test {
+            s: String::from("party time 🎉").repeat(10),
+            i: 42,
+        };
+        let enc = JsonGzipBase64Codec::encode(&t).unwrap();
+        assert!(enc
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '/' | '=')));
+        assert!(base64::engine::general_purpose::STANDARD
+            .decode(&enc)
+            .is_ok());
+        assert!(enc.len() < JsonSerdeCodec::encode(&t).unwrap().len());
+
+        let dec: Test = JsonGzipBase64Codec::decode(&enc).unwrap();
+        assert_eq!(dec, t);
+    }
+}
@@ -0,0 +1,137 @@
+use crate::{Decoder, Encoder};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// A codec that encodes a `Vec<T>` as [JSON Lines](https://jsonlines.org/) (also known as NDJSON)
+/// with [`serde_json`]. Every element is written as a JSON value on its own line.
+///
+/// Every line, including the last one, is terminated by `\n`. When decoding, both `\n` and `\r\n`
+/// are accepted and trailing empty lines are skipped.
+///
+/// Only available with the **`json_serde` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::string::JsonLinesCodec;
+/// # use serde::{Deserialize, Serialize};
+/// #
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct LogEntry {
+///     level: String,
+///     msg: String,
+/// }
+///
+/// let original_value = vec![
+///     LogEntry { level: "info".to_owned(), msg: "started".to_owned() },
+///     LogEntry { level: "warn".to_owned(), msg: "disk almost full".to_owned() },
+/// ];
+///
+/// let encoded = JsonLinesCodec::encode(&original_value).unwrap();
+/// assert_eq!(
+///     encoded,
+///     "{\"level\":\"info\",\"msg\":\"started\"}\n{\"level\":\"warn\",\"msg\":\"disk almost full\"}\n"
+/// );
+///
+/// let decoded: Vec<LogEntry> = JsonLinesCodec::decode(&encoded).unwrap();
+/// assert_eq!(decoded, original_value);
+/// ```
+pub struct JsonLinesCodec;
+
+#[derive(Error, Debug)]
+#[error("failed to decode line {line}: {source}")]
+pub struct JsonLinesDecodeError {
+    /// Number of the line that failed to decode, starting at 1.
+    pub line: usize,
+    pub source: serde_json::Error,
+}
+
+impl<T: Serialize> Encoder<Vec<T>> for JsonLinesCodec {
+    type Error = serde_json::Error;
+    type Encoded = String;
+
+    fn encode(val: &Vec<T>) -> Result<Self::Encoded, Self::Error> {
+        let mut encoded = String::new();
+
+        for item in val {
+            // serde_json never emits newlines in compact mode
+            encoded.push_str(&serde_json::to_string(item)?);
+            encoded.push('\n');
+        }
+
+        Ok(encoded)
+    }
+}
+
+impl<T> Decoder<Vec<T>> for JsonLinesCodec
+where
+    for<'de> T: Deserialize<'de>,
+{
+    type Error = JsonLinesDecodeError;
+    type Encoded = str;
+
+    fn decode(val: &Self::Encoded) -> Result<Vec<T>, Self::Error> {
+        val.trim_end_matches(['\r', '\n'])
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                serde_json::from_str(line).map_err(|source| JsonLinesDecodeError {
+                    line: index + 1,
+                    source,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    struct Test {
+        s: String,
+        i: i32,
+    }
+
+    #[test]
+    fn test_json_lines_empty() {
+        let empty: Vec<Test> = vec![];
+
+        let enc = JsonLinesCodec::encode(&empty).unwrap();
+        assert_eq!(enc, "");
+
+        let dec: Vec<Test> = JsonLinesCodec::decode(&enc).unwrap();
+        assert_eq!(dec, empty);
+
+        let dec: Vec<Test> = JsonLinesCodec::decode("\n\r\n").unwrap();
+        assert_eq!(dec, empty);
+    }
+
+    #[test]
+    fn test_json_lines_single_record() {
+        let v = vec![Test {
+            s: String::from("party time 🎉\nwith a newline"),
+            i: 42,
+        }];
+
+        let enc = JsonLinesCodec::encode(&v).unwrap();
+        assert_eq!(enc.lines().count(), 1);
+        assert!(enc.ends_with('\n'));
+
+        let dec: Vec<Test> = JsonLinesCodec::decode(&enc).unwrap();
+        assert_eq!(dec, v);
+
+        let dec: Vec<Test> = JsonLinesCodec::decode(enc.trim_end()).unwrap();
+        assert_eq!(dec, v);
+    }
+
+    #[test]
+    fn test_json_lines_malformed_line() {
+        let enc = "{\"s\":\"a\",\"i\":1}\r\n{\"s\":\"b\",\"i\":\n{\"s\":\"c\",\"i\":3}\n\n";
+
+        let dec: Result<Vec<Test>, _> = JsonLinesCodec::decode(enc);
+        assert!(matches!(dec, Err(JsonLinesDecodeError { line: 2, .. })));
+    }
+}
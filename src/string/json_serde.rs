@@ -1,5 +1,6 @@
-use crate::{Decoder, Encoder};
+use crate::{BorrowDecoder, Decoder, Encoder};
 use serde::{Deserialize, Serialize};
+use serde_json::value::RawValue;
 
 /// A codec for encoding JSON messages that relies on [`serde_json`].
 ///
@@ -29,7 +30,99 @@ use serde::{Deserialize, Serialize};
 /// ```
 pub struct JsonSerdeCodec;
 
-impl<T: Serialize> Encoder<T> for JsonSerdeCodec {
+impl JsonSerdeCodec {
+    /// Encodes a [`serde_json::Value`]. Unlike [`Encoder::encode`] this can't fail.
+    pub fn encode_value(val: &serde_json::Value) -> String {
+        val.to_string()
+    }
+
+    /// Decodes the JSON into a generic [`serde_json::Value`] without a concrete type. This is
+    /// the first step of manual version handling: transform the value and then turn it into
+    /// your type with [`serde_json::from_value`] or use [`JsonSerdeCodec::decode_transformed`].
+    pub fn decode_value(val: &str) -> Result<serde_json::Value, serde_json::Error> {
+        serde_json::from_str(val)
+    }
+
+    /// Decodes the JSON into a [`serde_json::Value`], lets `transform` modify it and then
+    /// deserializes the result into `T`. This is useful to migrate data stored by older versions
+    /// of your app.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use codee::string::JsonSerdeCodec;
+    /// # use serde::Deserialize;
+    /// # use serde_json::json;
+    /// #
+    /// #[derive(Deserialize, PartialEq, Debug)]
+    /// struct MyState {
+    ///     hello: String,
+    ///     // This field was added in a later version
+    ///     greeting: String,
+    /// }
+    ///
+    /// let decoded: MyState = JsonSerdeCodec::decode_transformed(r#"{"hello":"World"}"#, |val| {
+    ///     if let Some(obj) = val.as_object_mut() {
+    ///         obj.entry("greeting").or_insert(json!("Hello"));
+    ///     }
+    /// })
+    /// .unwrap();
+    ///
+    /// assert_eq!(decoded, MyState { hello: "World".to_owned(), greeting: "Hello".to_owned() });
+    /// ```
+    pub fn decode_transformed<T>(
+        val: &str,
+        transform: impl FnOnce(&mut serde_json::Value),
+    ) -> Result<T, serde_json::Error>
+    where
+        for<'de> T: Deserialize<'de>,
+    {
+        let mut value = Self::decode_value(val)?;
+        transform(&mut value);
+        serde_json::from_value(value)
+    }
+
+    /// Decodes a [`RawValue`] into `T`. Fields typed `Box<RawValue>` (or `&RawValue` with
+    /// [`BorrowDecoder`]) are kept as unparsed JSON when the outer type is decoded. This
+    /// way only the parts that are actually needed are deserialized, and only when they're needed.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use codee::{Decoder, Encoder};
+    /// # use codee::string::JsonSerdeCodec;
+    /// # use serde::{Deserialize, Serialize};
+    /// # use serde_json::value::RawValue;
+    /// #
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Document {
+    ///     title: String,
+    ///     body: Box<RawValue>,
+    /// }
+    ///
+    /// #[derive(Deserialize, PartialEq, Debug)]
+    /// struct Body {
+    ///     words: u32,
+    /// }
+    ///
+    /// let json = r#"{"title":"Hello","body":{"words":2,"text":"Hello World"}}"#;
+    /// let document: Document = JsonSerdeCodec::decode(json).unwrap();
+    /// assert_eq!(document.body.get(), r#"{"words":2,"text":"Hello World"}"#);
+    ///
+    /// let body: Body = JsonSerdeCodec::decode_raw(&document.body).unwrap();
+    /// assert_eq!(body, Body { words: 2 });
+    ///
+    /// // the raw value is encoded again exactly as it was
+    /// assert_eq!(JsonSerdeCodec::encode(&document).unwrap(), json);
+    /// ```
+    pub fn decode_raw<'de, T: Deserialize<'de>>(
+        val: &'de RawValue,
+    ) -> Result<T, serde_json::Error> {
+        serde_json::from_str(val.get())
+    }
+}
+
+impl<T: Serialize + ?Sized> Encoder<T> for JsonSerdeCodec {
     type Error = serde_json::Error;
     type Encoded = String;
 
@@ -50,6 +143,15 @@ where
     }
 }
 
+impl<'de, T: Deserialize<'de>> BorrowDecoder<'de, T> for JsonSerdeCodec {
+    type Error = serde_json::Error;
+    type Encoded = str;
+
+    fn decode_borrowed(val: &'de Self::Encoded) -> Result<T, Self::Error> {
+        serde_json::from_str(val)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -69,4 +171,140 @@ mod tests {
         let dec: Test = JsonSerdeCodec::decode(&enc).unwrap();
         assert_eq!(dec, t);
     }
+
+    #[test]
+    fn test_json_codec_slice() {
+        #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+        struct Test {
+            s: String,
+            i: i32,
+        }
+        let items = vec![
+            Test {
+                s: String::from("party time 🎉"),
+                i: 42,
+            },
+            Test {
+                s: String::from("after party"),
+                i: -1,
+            },
+        ];
+        let slice: &[Test] = &items;
+
+        let enc = JsonSerdeCodec::encode(slice).unwrap();
+        assert_eq!(enc, JsonSerdeCodec::encode(&items).unwrap());
+        assert_eq!(JsonSerdeCodec::encode(&slice).unwrap(), enc);
+
+        let dec: Vec<Test> = JsonSerdeCodec::decode(&enc).unwrap();
+        assert_eq!(dec, items);
+
+        let enc = JsonSerdeCodec::encode("party time 🎉").unwrap();
+        assert_eq!(enc, r#""party time 🎉""#);
+    }
+
+    #[test]
+    fn test_json_codec_raw_value() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Point {
+            x: i32,
+            y: i32,
+        }
+
+        #[derive(Serialize, Deserialize)]
+        struct Outer {
+            name: String,
+            points: Box<RawValue>,
+            extra: Box<RawValue>,
+        }
+
+        let json =
+            r#"{"name":"path","points":[{"x":1,"y":2},{"x":-3,"y":4}],"extra":{"not":["needed"]}}"#;
+        let outer: Outer = JsonSerdeCodec::decode(json).unwrap();
+        assert_eq!(outer.name, "path");
+        assert_eq!(outer.points.get(), r#"[{"x":1,"y":2},{"x":-3,"y":4}]"#);
+
+        let points: Vec<Point> = JsonSerdeCodec::decode_raw(&outer.points).unwrap();
+        assert_eq!(points, vec![Point { x: 1, y: 2 }, Point { x: -3, y: 4 }]);
+
+        let res: Result<Point, _> = JsonSerdeCodec::decode_raw(&outer.extra);
+        assert!(res.is_err());
+
+        assert_eq!(JsonSerdeCodec::encode(&outer).unwrap(), json);
+        assert_eq!(
+            JsonSerdeCodec::encode(&*outer.extra).unwrap(),
+            r#"{"not":["needed"]}"#
+        );
+
+        #[derive(Deserialize)]
+        struct BorrowedOuter<'a> {
+            #[serde(borrow)]
+            points: &'a RawValue,
+        }
+
+        let outer: BorrowedOuter = JsonSerdeCodec::decode_borrowed(json).unwrap();
+        let points: Vec<Point> = JsonSerdeCodec::decode_raw(outer.points).unwrap();
+        assert_eq!(points.len(), 2);
+    }
+
+    #[test]
+    fn test_json_codec_borrowed() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Test<'a> {
+            s: &'a str,
+            i: i32,
+        }
+
+        let enc = String::from(r#"{"s":"party time 🎉","i":42}"#);
+        let dec: Test = JsonSerdeCodec::decode_borrowed(&enc).unwrap();
+        assert_eq!(
+            dec,
+            Test {
+                s: "party time 🎉",
+                i: 42
+            }
+        );
+        // the field points into the input buffer
+        assert!(enc.as_bytes().as_ptr_range().contains(&dec.s.as_ptr()));
+
+        // borrowing isn't possible for strings that contain escapes
+        let res: Result<Test, _> = JsonSerdeCodec::decode_borrowed(r#"{"s":"\n","i":1}"#);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_json_codec_value_helpers() {
+        let value = serde_json::json!({"s": "party time 🎉", "list": [1, 2.5, null]});
+
+        let enc = JsonSerdeCodec::encode_value(&value);
+        assert_eq!(enc, JsonSerdeCodec::encode(&value).unwrap());
+        assert_eq!(JsonSerdeCodec::decode_value(&enc).unwrap(), value);
+
+        assert!(JsonSerdeCodec::decode_value("{").is_err());
+    }
+
+    #[test]
+    fn test_json_codec_decode_transformed() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Test {
+            s: String,
+            i: i32,
+        }
+
+        // field `i` was called `n` in an older version
+        let dec: Test = JsonSerdeCodec::decode_transformed(r#"{"s":"a","n":42}"#, |val| {
+            if let Some(obj) = val.as_object_mut() {
+                if let Some(n) = obj.remove("n") {
+                    obj.insert("i".to_owned(), n);
+                }
+            }
+        })
+        .unwrap();
+        assert_eq!(
+            dec,
+            Test {
+                s: "a".to_owned(),
+                i: 42
+            }
+        );
+    }
 }
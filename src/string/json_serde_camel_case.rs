@@ -0,0 +1,177 @@
+use crate::string::json_serde_stable_map::sorted_object;
+use crate::{Decoder, Encoder};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+/// A codec for encoding JSON messages that relies on [`serde_json`] and converts all object keys
+/// from snake_case to camelCase when encoding and back when decoding.
+///
+/// This way plain Rust structs with snake_case fields can talk to an API that uses camelCase
+/// without annotating every struct with `#[serde(rename_all = "camelCase")]`.
+///
+/// Please note that the conversion is applied to all object keys including the keys of maps
+/// like `HashMap<String, _>`. Map keys that contain uppercase letters don't round-trip: `"URL"`
+/// is decoded as `"_u_r_l"`. The keys of encoded objects are sorted alphabetically.
+///
+/// Only available with the **`json_serde` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::string::CamelCaseJsonCodec;
+/// # use serde::{Deserialize, Serialize};
+/// #
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct User {
+///     user_id: u32,
+///     display_name: String,
+/// }
+///
+/// let user = User { user_id: 1, display_name: "Ferris".to_owned() };
+///
+/// let encoded = CamelCaseJsonCodec::encode(&user).unwrap();
+/// assert_eq!(encoded, r#"{"displayName":"Ferris","userId":1}"#);
+///
+/// let decoded: User = CamelCaseJsonCodec::decode(&encoded).unwrap();
+/// assert_eq!(decoded, user);
+/// ```
+pub struct CamelCaseJsonCodec;
+
+impl<T: Serialize> Encoder<T> for CamelCaseJsonCodec {
+    type Error = serde_json::Error;
+    type Encoded = String;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        let value = serde_json::to_value(val)?;
+        serde_json::to_string(&convert_keys(value, &snake_to_camel))
+    }
+}
+
+impl<T: DeserializeOwned> Decoder<T> for CamelCaseJsonCodec {
+    type Error = serde_json::Error;
+    type Encoded = str;
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        let value: Value = serde_json::from_str(val)?;
+        serde_json::from_value(convert_keys(value, &camel_to_snake))
+    }
+}
+
+fn convert_keys(value: Value, convert: &impl Fn(&str) -> String) -> Value {
+    match value {
+        Value::Object(map) => sorted_object(
+            map.into_iter()
+                .map(|(key, value)| (convert(&key), convert_keys(value, convert))),
+        ),
+        Value::Array(values) => Value::Array(
+            values
+                .into_iter()
+                .map(|value| convert_keys(value, convert))
+                .collect(),
+        ),
+        value => value,
+    }
+}
+
+/// Removes every underscore that is followed by a lowercase letter (and isn't at the start)
+/// and turns that letter into uppercase. Other underscores are kept so the conversion can be
+/// reversed by [`camel_to_snake`].
+fn snake_to_camel(key: &str) -> String {
+    let mut result = String::with_capacity(key.len());
+    let mut chars = key.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match chars.peek() {
+            Some(next) if c == '_' && !result.is_empty() && next.is_ascii_lowercase() => {
+                result.push(next.to_ascii_uppercase());
+                chars.next();
+            }
+            _ => result.push(c),
+        }
+    }
+
+    result
+}
+
+fn camel_to_snake(key: &str) -> String {
+    let mut result = String::with_capacity(key.len() + 4);
+
+    for c in key.chars() {
+        if c.is_ascii_uppercase() {
+            result.push('_');
+            result.push(c.to_ascii_lowercase());
+        } else {
+            result.push(c);
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::collections::BTreeMap;
+    use serde::Deserialize;
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    struct Test {
+        some_string: String,
+        nested_list: Vec<Inner>,
+        field_1: i32,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    struct Inner {
+        inner_value: bool,
+    }
+
+    #[test]
+    fn test_camel_case_json_codec() {
+        let t = Test {
+            some_string: String::from("party time 🎉"),
+            nested_list: vec![Inner { inner_value: true }],
+            field_1: 42,
+        };
+
+        let enc = CamelCaseJsonCodec::encode(&t).unwrap();
+        assert_eq!(
+            enc,
+            r#"{"field_1":42,"nestedList":[{"innerValue":true}],"someString":"party time 🎉"}"#
+        );
+
+        let dec: Test = CamelCaseJsonCodec::decode(&enc).unwrap();
+        assert_eq!(dec, t);
+    }
+
+    #[test]
+    fn test_camel_case_json_codec_decode_api_payload() {
+        let payload = r#"{"field_1":1,"nestedList":[{"innerValue":false}],"someString":"hi"}"#;
+
+        let dec: Test = CamelCaseJsonCodec::decode(payload).unwrap();
+        assert_eq!(
+            dec,
+            Test {
+                some_string: String::from("hi"),
+                nested_list: vec![Inner { inner_value: false }],
+                field_1: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_camel_case_json_codec_map_keys() {
+        let map = BTreeMap::from([("snake_key".to_owned(), 1), ("URL".to_owned(), 2)]);
+
+        let enc = CamelCaseJsonCodec::encode(&map).unwrap();
+        assert_eq!(enc, r#"{"URL":2,"snakeKey":1}"#);
+
+        // map keys are converted as well and uppercase letters don't survive the round trip
+        let dec: BTreeMap<String, i32> = CamelCaseJsonCodec::decode(&enc).unwrap();
+        assert_eq!(
+            dec,
+            BTreeMap::from([("_u_r_l".to_owned(), 2), ("snake_key".to_owned(), 1)])
+        );
+    }
+}
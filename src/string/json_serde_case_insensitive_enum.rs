@@ -0,0 +1,178 @@
+use crate::string::json_value_deserializer::{HookedValue, ValueHook};
+use crate::{Decoder, Encoder};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+/// A JSON codec like [`JsonSerdeCodec`](super::JsonSerdeCodec) that matches enum variants
+/// ignoring ASCII case when decoding.
+///
+/// Wherever an externally tagged enum (serde's default representation) is expected, the variant
+/// string (or the key of a variant with data) is replaced by the variant name that is equal
+/// ignoring ASCII case before deserializing. This means `"Active"`, `"active"` and `"ACTIVE"` all
+/// decode into the same variant, regardless of `#[serde(rename_all = "...")]`. All other strings
+/// are left alone. Encoding produces the variant names as usual.
+///
+/// Internally tagged (`#[serde(tag = "...")]`), adjacently tagged and untagged enums are not
+/// supported. serde buffers their content without telling the deserializer that an enum is
+/// expected, so their variants still have to match exactly.
+///
+/// The variant names of every enum have to be distinct when ignoring case. Otherwise the first
+/// matching variant is picked.
+///
+/// Only available with the **`json_serde` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::string::CaseInsensitiveEnumJsonCodec;
+/// # use serde::{Deserialize, Serialize};
+/// #
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// #[serde(rename_all = "lowercase")]
+/// enum Status {
+///     Active,
+///     Inactive,
+/// }
+///
+/// let decoded: Vec<Status> = CaseInsensitiveEnumJsonCodec::decode(r#"["ACTIVE","Inactive"]"#).unwrap();
+/// assert_eq!(decoded, vec![Status::Active, Status::Inactive]);
+///
+/// let encoded = CaseInsensitiveEnumJsonCodec::encode(&decoded).unwrap();
+/// assert_eq!(encoded, r#"["active","inactive"]"#);
+/// ```
+pub struct CaseInsensitiveEnumJsonCodec;
+
+impl<T: Serialize> Encoder<T> for CaseInsensitiveEnumJsonCodec {
+    type Error = serde_json::Error;
+    type Encoded = String;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        serde_json::to_string(val)
+    }
+}
+
+impl<T: DeserializeOwned> Decoder<T> for CaseInsensitiveEnumJsonCodec {
+    type Error = serde_json::Error;
+    type Encoded = str;
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        let value: Value = serde_json::from_str(val)?;
+        T::deserialize(HookedValue::<CaseInsensitiveEnumHook>::new(value))
+    }
+}
+
+/// Replaces variant names with the variant that is equal ignoring ASCII case.
+struct CaseInsensitiveEnumHook;
+
+impl ValueHook for CaseInsensitiveEnumHook {
+    /// Returns the variant that is equal to `variant` ignoring ASCII case. Falls back to
+    /// `variant` itself so that serde reports an unknown variant.
+    fn enum_variant(variant: String, variants: &'static [&'static str]) -> String {
+        variants
+            .iter()
+            .find(|v| v.eq_ignore_ascii_case(&variant))
+            .map(|v| v.to_string())
+            .unwrap_or(variant)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::collections::BTreeMap;
+    use serde::Deserialize;
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    enum Status {
+        Active,
+        Inactive,
+        Suspended { days: u32 },
+    }
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    struct Test {
+        status: Status,
+        name: String,
+        history: Vec<Option<Status>>,
+    }
+
+    #[test]
+    fn test_case_insensitive_enum_json_codec() {
+        for s in ["Active", "active", "ACTIVE", "aCtIvE"] {
+            let payload = format!(r#"{{"status":"{s}","name":"{s}","history":["{s}",null]}}"#);
+
+            let dec: Test = CaseInsensitiveEnumJsonCodec::decode(&payload).unwrap();
+            assert_eq!(
+                dec,
+                Test {
+                    status: Status::Active,
+                    name: s.to_owned(),
+                    history: vec![Some(Status::Active), None],
+                }
+            );
+
+            let enc = CaseInsensitiveEnumJsonCodec::encode(&dec).unwrap();
+            assert_eq!(
+                enc,
+                format!(r#"{{"status":"Active","name":"{s}","history":["Active",null]}}"#)
+            );
+        }
+    }
+
+    #[test]
+    fn test_case_insensitive_enum_json_codec_variant_with_data() {
+        let dec: Status =
+            CaseInsensitiveEnumJsonCodec::decode(r#"{"SUSPENDED":{"days":3}}"#).unwrap();
+        assert_eq!(dec, Status::Suspended { days: 3 });
+    }
+
+    #[test]
+    fn test_case_insensitive_enum_json_codec_unknown_variant() {
+        let dec: Result<Status, _> = CaseInsensitiveEnumJsonCodec::decode(r#""Deleted""#);
+        let err = dec.unwrap_err();
+        assert!(err.to_string().contains("unknown variant `Deleted`"));
+    }
+
+    #[test]
+    fn test_case_insensitive_enum_json_codec_internally_tagged() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        #[serde(tag = "type")]
+        enum Shape {
+            Circle { radius: u32 },
+        }
+
+        let dec: Shape =
+            CaseInsensitiveEnumJsonCodec::decode(r#"{"type":"Circle","radius":2}"#).unwrap();
+        assert_eq!(dec, Shape::Circle { radius: 2 });
+
+        // only externally tagged enums are matched ignoring case
+        let dec: Result<Shape, _> =
+            CaseInsensitiveEnumJsonCodec::decode(r#"{"type":"circle","radius":2}"#);
+        assert!(dec
+            .unwrap_err()
+            .to_string()
+            .contains("unknown variant `circle`"));
+    }
+
+    #[test]
+    fn test_case_insensitive_enum_json_codec_map_keys() {
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+        enum Color {
+            Red,
+            Green,
+        }
+
+        let dec: BTreeMap<u32, Status> =
+            CaseInsensitiveEnumJsonCodec::decode(r#"{"1":"active","2":"INACTIVE"}"#).unwrap();
+        assert_eq!(
+            dec,
+            BTreeMap::from([(1, Status::Active), (2, Status::Inactive)])
+        );
+
+        let dec: BTreeMap<Color, u32> =
+            CaseInsensitiveEnumJsonCodec::decode(r#"{"red":1,"GREEN":2}"#).unwrap();
+        assert_eq!(dec, BTreeMap::from([(Color::Red, 1), (Color::Green, 2)]));
+    }
+}
@@ -0,0 +1,149 @@
+use crate::string::json_value_deserializer::{HookedValue, ValueHook};
+use crate::{Decoder, Encoder};
+use serde::de::{DeserializeOwned, Error, Unexpected, Visitor};
+use serde::{Deserializer, Serialize};
+use serde_json::Value;
+
+/// A JSON codec like [`JsonSerdeCodec`](super::JsonSerdeCodec) that accepts booleans encoded as
+/// strings when decoding.
+///
+/// Wherever a `bool` is expected the following strings are accepted in addition to the JSON
+/// booleans `true` and `false` (ignoring ASCII case):
+///
+/// - truthy: `"true"`, `"1"`, `"yes"`
+/// - falsy: `"false"`, `"0"`, `"no"`
+///
+/// Any other string results in an error. Strings are left alone wherever something other than a
+/// `bool` is expected. Encoding always produces canonical JSON booleans.
+///
+/// Only available with the **`json_serde` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::string::LenientBoolJsonCodec;
+/// # use serde::{Deserialize, Serialize};
+/// #
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Flags {
+///     enabled: bool,
+///     visible: bool,
+/// }
+///
+/// let decoded: Flags = LenientBoolJsonCodec::decode(r#"{"enabled":"yes","visible":"0"}"#).unwrap();
+/// assert_eq!(decoded, Flags { enabled: true, visible: false });
+///
+/// let encoded = LenientBoolJsonCodec::encode(&decoded).unwrap();
+/// assert_eq!(encoded, r#"{"enabled":true,"visible":false}"#);
+/// ```
+pub struct LenientBoolJsonCodec;
+
+impl<T: Serialize> Encoder<T> for LenientBoolJsonCodec {
+    type Error = serde_json::Error;
+    type Encoded = String;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        serde_json::to_string(val)
+    }
+}
+
+impl<T: DeserializeOwned> Decoder<T> for LenientBoolJsonCodec {
+    type Error = serde_json::Error;
+    type Encoded = str;
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        let value: Value = serde_json::from_str(val)?;
+        T::deserialize(HookedValue::<LenientBoolHook>::new(value))
+    }
+}
+
+fn str_to_bool(val: &str) -> Option<bool> {
+    match val.to_ascii_lowercase().as_str() {
+        "true" | "1" | "yes" => Some(true),
+        "false" | "0" | "no" => Some(false),
+        _ => None,
+    }
+}
+
+/// Accepts the truthy and falsy strings wherever a `bool` is expected.
+struct LenientBoolHook;
+
+impl ValueHook for LenientBoolHook {
+    fn deserialize_bool<'de, V: Visitor<'de>>(
+        value: Value,
+        visitor: V,
+    ) -> Result<V::Value, serde_json::Error> {
+        match &value {
+            Value::String(s) => match str_to_bool(s) {
+                Some(b) => visitor.visit_bool(b),
+                None => Err(Error::invalid_value(
+                    Unexpected::Str(s),
+                    &"a boolean or one of \"true\", \"false\", \"1\", \"0\", \"yes\", \"no\"",
+                )),
+            },
+            _ => HookedValue::<Self>::new(value).deserialize_any(visitor),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::collections::BTreeMap;
+    use serde::Deserialize;
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    struct Test {
+        flag: bool,
+        s: String,
+        list: Vec<Option<bool>>,
+    }
+
+    #[test]
+    fn test_lenient_bool_json_codec() {
+        for (s, expected) in [
+            ("true", true),
+            ("1", true),
+            ("yes", true),
+            ("YES", true),
+            ("false", false),
+            ("0", false),
+            ("no", false),
+            ("False", false),
+        ] {
+            let payload = format!(r#"{{"flag":"{s}","s":"{s}","list":["{s}",null,true]}}"#);
+
+            let dec: Test = LenientBoolJsonCodec::decode(&payload).unwrap();
+            assert_eq!(
+                dec,
+                Test {
+                    flag: expected,
+                    s: s.to_owned(),
+                    list: vec![Some(expected), None, Some(true)],
+                }
+            );
+
+            let enc = LenientBoolJsonCodec::encode(&dec).unwrap();
+            assert_eq!(
+                enc,
+                format!(r#"{{"flag":{expected},"s":"{s}","list":[{expected},null,true]}}"#)
+            );
+        }
+    }
+
+    #[test]
+    fn test_lenient_bool_json_codec_unrecognized() {
+        let dec: Result<Test, _> =
+            LenientBoolJsonCodec::decode(r#"{"flag":"maybe","s":"","list":[]}"#);
+        let err = dec.unwrap_err();
+        assert!(err.to_string().contains("invalid value: string \"maybe\""));
+    }
+
+    #[test]
+    fn test_lenient_bool_json_codec_integer_keys() {
+        let dec: BTreeMap<u32, bool> =
+            LenientBoolJsonCodec::decode(r#"{"1":"yes","2":false}"#).unwrap();
+        assert_eq!(dec, BTreeMap::from([(1, true), (2, false)]));
+    }
+}
@@ -0,0 +1,432 @@
+use crate::string::json_value_deserializer::{HookedValue, ValueHook};
+use crate::{Decoder, Encoder};
+use serde::de::{DeserializeOwned, Visitor};
+use serde::ser::{
+    SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+    SerializeTupleStruct, SerializeTupleVariant,
+};
+use serde::{Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+/// A JSON codec like [`JsonSerdeCodec`](super::JsonSerdeCodec) that round-trips non-finite
+/// floats losslessly.
+///
+/// Plain JSON has no representation for `NaN` or infinity so `serde_json` writes them as `null`.
+/// This codec instead encodes them as the sentinel strings `"NaN"`, `"Infinity"` and
+/// `"-Infinity"` and turns these strings back into the actual float values when decoding into a
+/// float.
+///
+/// Only available with the **`json_serde` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::string::NanSafeJsonSerdeCodec;
+/// # use serde::{Deserialize, Serialize};
+/// #
+/// #[derive(Serialize, Deserialize, Debug)]
+/// struct Measurement {
+///     value: f64,
+///     error: f64,
+/// }
+///
+/// let m = Measurement { value: f64::NAN, error: f64::INFINITY };
+///
+/// let encoded = NanSafeJsonSerdeCodec::encode(&m).unwrap();
+/// assert_eq!(encoded, r#"{"value":"NaN","error":"Infinity"}"#);
+///
+/// let decoded: Measurement = NanSafeJsonSerdeCodec::decode(&encoded).unwrap();
+/// assert!(decoded.value.is_nan());
+/// assert_eq!(decoded.error, f64::INFINITY);
+/// ```
+pub struct NanSafeJsonSerdeCodec;
+
+impl<T: Serialize> Encoder<T> for NanSafeJsonSerdeCodec {
+    type Error = serde_json::Error;
+    type Encoded = String;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        let mut buf = Vec::new();
+        val.serialize(NanSafeSerializer(&mut serde_json::Serializer::new(
+            &mut buf,
+        )))?;
+        String::from_utf8(buf).map_err(serde::ser::Error::custom)
+    }
+}
+
+impl<T: DeserializeOwned> Decoder<T> for NanSafeJsonSerdeCodec {
+    type Error = serde_json::Error;
+    type Encoded = str;
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        let value: Value = serde_json::from_str(val)?;
+        T::deserialize(HookedValue::<NanSafeHook>::new(value))
+    }
+}
+
+fn float_to_sentinel(val: f64) -> Option<&'static str> {
+    if val.is_nan() {
+        Some("NaN")
+    } else if val == f64::INFINITY {
+        Some("Infinity")
+    } else if val == f64::NEG_INFINITY {
+        Some("-Infinity")
+    } else {
+        None
+    }
+}
+
+fn sentinel_to_float(val: &str) -> Option<f64> {
+    match val {
+        "NaN" => Some(f64::NAN),
+        "Infinity" => Some(f64::INFINITY),
+        "-Infinity" => Some(f64::NEG_INFINITY),
+        _ => None,
+    }
+}
+
+/// Serializes the wrapped value with [`NanSafeSerializer`].
+struct NanSafe<'a, T: ?Sized>(&'a T);
+
+impl<T: Serialize + ?Sized> Serialize for NanSafe<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(NanSafeSerializer(serializer))
+    }
+}
+
+/// Forwards everything to the wrapped serializer except non-finite floats which are serialized
+/// as sentinel strings.
+struct NanSafeSerializer<S>(S);
+
+macro_rules! forward_serialize {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method(self, v: $ty) -> Result<Self::Ok, Self::Error> {
+                self.0.$method(v)
+            }
+        )*
+    };
+}
+
+impl<S: Serializer> Serializer for NanSafeSerializer<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+    type SerializeSeq = NanSafeCompound<S::SerializeSeq>;
+    type SerializeTuple = NanSafeCompound<S::SerializeTuple>;
+    type SerializeTupleStruct = NanSafeCompound<S::SerializeTupleStruct>;
+    type SerializeTupleVariant = NanSafeCompound<S::SerializeTupleVariant>;
+    type SerializeMap = NanSafeCompound<S::SerializeMap>;
+    type SerializeStruct = NanSafeCompound<S::SerializeStruct>;
+    type SerializeStructVariant = NanSafeCompound<S::SerializeStructVariant>;
+
+    forward_serialize!(
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_i128(i128),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_u128(u128),
+        serialize_char(char),
+        serialize_str(&str),
+        serialize_bytes(&[u8]),
+        serialize_unit_struct(&'static str),
+    );
+
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
+        match float_to_sentinel(v as f64) {
+            Some(sentinel) => self.0.serialize_str(sentinel),
+            None => self.0.serialize_f32(v),
+        }
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> {
+        match float_to_sentinel(v) {
+            Some(sentinel) => self.0.serialize_str(sentinel),
+            None => self.0.serialize_f64(v),
+        }
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_none()
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_some(&NanSafe(value))
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_unit_variant(name, variant_index, variant)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_newtype_struct(name, &NanSafe(value))
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.0
+            .serialize_newtype_variant(name, variant_index, variant, &NanSafe(value))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        self.0.serialize_seq(len).map(NanSafeCompound)
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        self.0.serialize_tuple(len).map(NanSafeCompound)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        self.0
+            .serialize_tuple_struct(name, len)
+            .map(NanSafeCompound)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        self.0
+            .serialize_tuple_variant(name, variant_index, variant, len)
+            .map(NanSafeCompound)
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        self.0.serialize_map(len).map(NanSafeCompound)
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        self.0.serialize_struct(name, len).map(NanSafeCompound)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        self.0
+            .serialize_struct_variant(name, variant_index, variant, len)
+            .map(NanSafeCompound)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.0.is_human_readable()
+    }
+}
+
+/// Wraps the compound serializers of [`NanSafeSerializer`] so that nested values are
+/// serialized with [`NanSafeSerializer`] as well.
+struct NanSafeCompound<C>(C);
+
+impl<C: SerializeSeq> SerializeSeq for NanSafeCompound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.0.serialize_element(&NanSafe(value))
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.0.end()
+    }
+}
+
+impl<C: SerializeTuple> SerializeTuple for NanSafeCompound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.0.serialize_element(&NanSafe(value))
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.0.end()
+    }
+}
+
+impl<C: SerializeTupleStruct> SerializeTupleStruct for NanSafeCompound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.0.serialize_field(&NanSafe(value))
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.0.end()
+    }
+}
+
+impl<C: SerializeTupleVariant> SerializeTupleVariant for NanSafeCompound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.0.serialize_field(&NanSafe(value))
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.0.end()
+    }
+}
+
+impl<C: SerializeMap> SerializeMap for NanSafeCompound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Self::Error> {
+        self.0.serialize_key(key)
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.0.serialize_value(&NanSafe(value))
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.0.end()
+    }
+}
+
+impl<C: SerializeStruct> SerializeStruct for NanSafeCompound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.0.serialize_field(key, &NanSafe(value))
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.0.end()
+    }
+}
+
+impl<C: SerializeStructVariant> SerializeStructVariant for NanSafeCompound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.0.serialize_field(key, &NanSafe(value))
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.0.end()
+    }
+}
+
+/// Accepts the sentinel strings wherever a float is expected.
+struct NanSafeHook;
+
+impl ValueHook for NanSafeHook {
+    fn deserialize_float<'de, V: Visitor<'de>>(
+        value: Value,
+        visitor: V,
+    ) -> Result<V::Value, serde_json::Error> {
+        match &value {
+            Value::String(s) => match sentinel_to_float(s) {
+                Some(f) => visitor.visit_f64(f),
+                None => HookedValue::<Self>::new(value).deserialize_any(visitor),
+            },
+            _ => HookedValue::<Self>::new(value).deserialize_any(visitor),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::collections::BTreeMap;
+    use serde::Deserialize;
+
+    #[test]
+    fn test_nan_safe_json_codec() {
+        #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+        struct Test {
+            nan: f64,
+            inf: f64,
+            neg_inf: f32,
+            finite: f64,
+            s: String,
+            list: Vec<Option<f64>>,
+        }
+        let t = Test {
+            nan: f64::NAN,
+            inf: f64::INFINITY,
+            neg_inf: f32::NEG_INFINITY,
+            finite: 1.5,
+            s: String::from("NaN"),
+            list: vec![Some(f64::NAN), None, Some(2.0)],
+        };
+        let enc = NanSafeJsonSerdeCodec::encode(&t).unwrap();
+        assert_eq!(
+            enc,
+            r#"{"nan":"NaN","inf":"Infinity","neg_inf":"-Infinity","finite":1.5,"s":"NaN","list":["NaN",null,2.0]}"#
+        );
+
+        let dec: Test = NanSafeJsonSerdeCodec::decode(&enc).unwrap();
+        assert!(dec.nan.is_nan());
+        assert_eq!(dec.inf, f64::INFINITY);
+        assert!(dec.inf.is_infinite() && dec.inf.is_sign_positive());
+        assert_eq!(dec.neg_inf, f32::NEG_INFINITY);
+        assert!(dec.neg_inf.is_infinite() && dec.neg_inf.is_sign_negative());
+        assert_eq!(dec.finite, 1.5);
+        assert_eq!(dec.s, "NaN");
+        assert!(dec.list[0].unwrap().is_nan());
+        assert_eq!(dec.list[1..], [None, Some(2.0)]);
+    }
+
+    #[test]
+    fn test_nan_safe_json_codec_integer_keys() {
+        let map = BTreeMap::from([(1u32, f64::NAN), (2, 1.0)]);
+        let enc = NanSafeJsonSerdeCodec::encode(&map).unwrap();
+        assert_eq!(enc, r#"{"1":"NaN","2":1.0}"#);
+
+        let dec: BTreeMap<u32, f64> = NanSafeJsonSerdeCodec::decode(&enc).unwrap();
+        assert!(dec[&1].is_nan());
+        assert_eq!(dec[&2], 1.0);
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{Decoder, Encoder};
+use serde::{Deserialize, Serialize};
+
+/// Like [`JsonSerdeCodec`](crate::string::JsonSerdeCodec) but encodes pretty-printed JSON with
+/// newlines and indentation. Useful for human-readable and diff-friendly files like configs.
+/// Decoding is the same as with `JsonSerdeCodec`.
+///
+/// Only available with the **`json_serde` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::string::JsonSerdePrettyCodec;
+/// # use serde::{Deserialize, Serialize};
+/// #
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Config {
+///     name: String,
+///     verbose: bool,
+/// }
+///
+/// let config = Config { name: "app".to_owned(), verbose: true };
+///
+/// let encoded = JsonSerdePrettyCodec::encode(&config).unwrap();
+/// assert_eq!(encoded, "{\n  \"name\": \"app\",\n  \"verbose\": true\n}");
+///
+/// let decoded: Config = JsonSerdePrettyCodec::decode(&encoded).unwrap();
+/// assert_eq!(decoded, config);
+/// ```
+pub struct JsonSerdePrettyCodec;
+
+impl<T: Serialize> Encoder<T> for JsonSerdePrettyCodec {
+    type Error = serde_json::Error;
+    type Encoded = String;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        serde_json::to_string_pretty(val)
+    }
+}
+
+impl<T> Decoder<T> for JsonSerdePrettyCodec
+where
+    for<'de> T: Deserialize<'de>,
+{
+    type Error = serde_json::Error;
+    type Encoded = str;
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        serde_json::from_str(val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_pretty_codec() {
+        #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+        struct Test {
+            s: String,
+            i: i32,
+        }
+        let t = Test {
+            s: String::from("party time 🎉"),
+            i: 42,
+        };
+        let enc = JsonSerdePrettyCodec::encode(&t).unwrap();
+        assert!(enc.contains('\n'));
+        assert!(enc.contains("\n  \"i\": 42"));
+
+        let dec: Test = JsonSerdePrettyCodec::decode(&enc).unwrap();
+        assert_eq!(dec, t);
+    }
+}
@@ -0,0 +1,125 @@
+use crate::string::json_serde_stable_map::sorted_object;
+use crate::{Decoder, Encoder};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Number, Value};
+
+/// A codec for encoding JSON messages that relies on [`serde_json`] and rounds every float to
+/// `PLACES` decimal places when encoding.
+///
+/// This reduces the size of the encoded data and normalizes noisy measurements. Please note that
+/// the rounding is **lossy**: decoding returns the rounded values. Integers are never changed.
+/// The keys of encoded objects are sorted alphabetically.
+///
+/// Only available with the **`json_serde` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::string::RoundedFloatJsonCodec;
+/// # use serde::{Deserialize, Serialize};
+/// #
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Position {
+///     lat: f64,
+///     lon: f64,
+/// }
+///
+/// let position = Position { lat: 52.520006599, lon: 13.404954 };
+///
+/// let encoded = RoundedFloatJsonCodec::<3>::encode(&position).unwrap();
+/// assert_eq!(encoded, r#"{"lat":52.52,"lon":13.405}"#);
+///
+/// let decoded: Position = RoundedFloatJsonCodec::<3>::decode(&encoded).unwrap();
+/// assert_eq!(decoded, Position { lat: 52.52, lon: 13.405 });
+/// ```
+pub struct RoundedFloatJsonCodec<const PLACES: u32>;
+
+impl<T: Serialize, const PLACES: u32> Encoder<T> for RoundedFloatJsonCodec<PLACES> {
+    type Error = serde_json::Error;
+    type Encoded = String;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        let value = serde_json::to_value(val)?;
+        serde_json::to_string(&round_floats(value, 10_f64.powi(PLACES as i32)))
+    }
+}
+
+impl<T: DeserializeOwned, const PLACES: u32> Decoder<T> for RoundedFloatJsonCodec<PLACES> {
+    type Error = serde_json::Error;
+    type Encoded = str;
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        serde_json::from_str(val)
+    }
+}
+
+fn round_floats(value: Value, factor: f64) -> Value {
+    match value {
+        Value::Number(number) if number.is_f64() => {
+            let float = number.as_f64().unwrap_or_default();
+            let rounded = (float * factor).round() / factor;
+
+            // very large values or factors would overflow
+            match Number::from_f64(rounded) {
+                Some(rounded) if rounded.as_f64().is_some_and(f64::is_finite) => {
+                    Value::Number(rounded)
+                }
+                _ => Value::Number(number),
+            }
+        }
+        Value::Object(map) => sorted_object(
+            map.into_iter()
+                .map(|(key, value)| (key, round_floats(value, factor))),
+        ),
+        Value::Array(values) => Value::Array(
+            values
+                .into_iter()
+                .map(|value| round_floats(value, factor))
+                .collect(),
+        ),
+        value => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    struct Test {
+        value: f64,
+        list: Vec<f32>,
+        count: u64,
+    }
+
+    #[test]
+    fn test_rounded_float_json_codec() {
+        let t = Test {
+            value: std::f64::consts::E,
+            list: vec![0.123_456, -2.5],
+            count: 123_456_789,
+        };
+
+        let enc = RoundedFloatJsonCodec::<2>::encode(&t).unwrap();
+        assert_eq!(
+            enc,
+            r#"{"count":123456789,"list":[0.12,-2.5],"value":2.72}"#
+        );
+
+        let dec: Test = RoundedFloatJsonCodec::<2>::decode(&enc).unwrap();
+        assert_eq!(dec.value, 2.72);
+        assert_eq!(dec.list, vec![0.12, -2.5]);
+    }
+
+    #[test]
+    fn test_rounded_float_json_codec_zero_places() {
+        let enc = RoundedFloatJsonCodec::<0>::encode(&vec![1.4, 1.5, -0.4]).unwrap();
+        assert_eq!(enc, "[1.0,2.0,-0.0]");
+
+        // multiplying by the factor would overflow
+        let enc = RoundedFloatJsonCodec::<10>::encode(&f64::MAX).unwrap();
+        assert_eq!(enc, serde_json::to_string(&f64::MAX).unwrap());
+    }
+}
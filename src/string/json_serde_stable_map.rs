@@ -0,0 +1,97 @@
+use crate::{Decoder, Encoder};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{BuildHasher, Hash};
+
+/// A JSON codec for `HashMap`s that always produces the same output for the same map.
+///
+/// `serde_json` serializes a `HashMap` in its arbitrary iteration order. This codec collects the
+/// entries into a `BTreeMap` first so the keys are always sorted. This makes the output suitable
+/// for things like deterministic cache keys. Decoding produces a `HashMap` again.
+///
+/// Please note that only the keys of the top level map are sorted. `HashMap`s nested in the
+/// values are serialized as usual.
+///
+/// Only available with the **`json_serde` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::string::StableMapJsonCodec;
+/// # use std::collections::HashMap;
+/// #
+/// let map = HashMap::from([("b", 2), ("c", 3), ("a", 1)]);
+///
+/// let encoded = StableMapJsonCodec::encode(&map).unwrap();
+/// assert_eq!(encoded, r#"{"a":1,"b":2,"c":3}"#);
+///
+/// let decoded: HashMap<String, i32> = StableMapJsonCodec::decode(&encoded).unwrap();
+/// assert_eq!(decoded.len(), 3);
+/// ```
+pub struct StableMapJsonCodec;
+
+impl<K, V, S> Encoder<HashMap<K, V, S>> for StableMapJsonCodec
+where
+    K: Serialize + Ord + 'static,
+    V: Serialize + 'static,
+    S: 'static,
+{
+    type Error = serde_json::Error;
+    type Encoded = String;
+
+    fn encode(val: &HashMap<K, V, S>) -> Result<Self::Encoded, Self::Error> {
+        serde_json::to_string(&val.iter().collect::<BTreeMap<_, _>>())
+    }
+}
+
+impl<K, V, S> Decoder<HashMap<K, V, S>> for StableMapJsonCodec
+where
+    K: DeserializeOwned + Eq + Hash + 'static,
+    V: DeserializeOwned + 'static,
+    S: BuildHasher + Default + 'static,
+{
+    type Error = serde_json::Error;
+    type Encoded = str;
+
+    fn decode(val: &Self::Encoded) -> Result<HashMap<K, V, S>, Self::Error> {
+        serde_json::from_str(val)
+    }
+}
+
+/// Builds a JSON object from `entries` with its keys sorted. Other JSON codecs use this to
+/// sort the keys of objects they build. Sorting explicitly is necessary because `Map` keeps
+/// the insertion order instead if serde_json's `preserve_order` feature is enabled anywhere
+/// in the dependency graph.
+pub(crate) fn sorted_object(entries: impl IntoIterator<Item = (String, Value)>) -> Value {
+    let mut entries: Vec<_> = entries.into_iter().collect();
+    entries.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+    Value::Object(entries.into_iter().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stable_map_json_codec() {
+        let entries = (0..100).map(|i| (format!("key {i}"), vec![i; 3]));
+
+        let map: HashMap<_, _> = entries.clone().collect();
+        let other_map: HashMap<_, _> = entries.rev().collect();
+
+        let enc = StableMapJsonCodec::encode(&map).unwrap();
+        assert_eq!(enc, StableMapJsonCodec::encode(&other_map).unwrap());
+        assert!(enc.starts_with(r#"{"key 0":[0,0,0],"key 1":[1,1,1],"key 10":"#));
+
+        for _ in 0..10 {
+            let map: HashMap<_, _> = map.clone().into_iter().collect();
+            assert_eq!(StableMapJsonCodec::encode(&map).unwrap(), enc);
+        }
+
+        let dec: HashMap<String, Vec<i32>> = StableMapJsonCodec::decode(&enc).unwrap();
+        assert_eq!(dec, map);
+    }
+}
@@ -0,0 +1,261 @@
+use crate::string::JsonSerdeCodec;
+use crate::{Decoder, Encoder};
+use jsonschema::Validator;
+use serde::{Deserialize, Serialize};
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::sync::{Mutex, OnceLock};
+use thiserror::Error;
+
+/// Provides the [JSON Schema](https://json-schema.org/) that [`ValidatedJsonCodec`] validates
+/// against.
+pub trait JsonSchemaProvider: 'static {
+    /// Returns the schema. It is only called once and the compiled validator (or the error if the
+    /// schema is invalid) is cached.
+    fn schema() -> serde_json::Value;
+}
+
+/// Like [`JsonSerdeCodec`] but decoding validates the JSON against the schema provided by `S`
+/// before deserializing it into `T`.
+///
+/// This is useful if you can't trust the stored data, for example because it was written by
+/// an older version of your app. The schema is compiled on first use and cached for the
+/// lifetime of the program. Encoding doesn't validate anything.
+///
+/// Only available with the **`jsonschema` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::string::{JsonSchemaProvider, ValidatedJsonCodec, ValidatedJsonDecodeError};
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// #
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct Settings {
+///     #[serde(default)]
+///     volume: u8,
+/// }
+///
+/// struct SettingsSchema;
+///
+/// impl JsonSchemaProvider for SettingsSchema {
+///     fn schema() -> serde_json::Value {
+///         json!({
+///             "type": "object",
+///             "properties": { "volume": { "type": "integer", "maximum": 100 } },
+///             "required": ["volume"]
+///         })
+///     }
+/// }
+///
+/// type Codec = ValidatedJsonCodec<SettingsSchema>;
+///
+/// let decoded: Settings = Codec::decode(r#"{"volume":42}"#).unwrap();
+/// assert_eq!(decoded, Settings { volume: 42 });
+///
+/// let decoded: Result<Settings, _> = Codec::decode(r#"{"volume":142}"#);
+/// assert!(matches!(decoded, Err(ValidatedJsonDecodeError::Invalid(_))));
+/// ```
+pub struct ValidatedJsonCodec<S>(S);
+
+/// A single violation of the schema found by [`ValidatedJsonCodec`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaViolation {
+    /// JSON pointer to the invalid value, for example `/items/0/name`. Empty for the root.
+    pub instance_path: String,
+    /// Human-readable description of the violation.
+    pub message: String,
+}
+
+impl Display for SchemaViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at \"{}\"", self.message, self.instance_path)
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum ValidatedJsonDecodeError {
+    #[error("failed to (de)serialize JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("invalid JSON schema: {0}")]
+    InvalidSchema(String),
+    #[error("JSON doesn't match the schema: {}", display_violations(.0))]
+    Invalid(Vec<SchemaViolation>),
+}
+
+fn display_violations(violations: &[SchemaViolation]) -> String {
+    violations
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+impl ValidatedJsonDecodeError {
+    /// Returns the instance paths of all schema violations. Empty for other errors.
+    pub fn instance_paths(&self) -> Vec<&str> {
+        match self {
+            Self::Invalid(violations) => violations
+                .iter()
+                .map(|violation| violation.instance_path.as_str())
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Returns the compiled validator of `S`. Compiled validators are leaked so they can be shared
+/// as `'static` references. If the schema is invalid, the error is cached instead.
+fn validator<S: JsonSchemaProvider>() -> Result<&'static Validator, ValidatedJsonDecodeError> {
+    static VALIDATORS: OnceLock<Mutex<HashMap<TypeId, Result<&'static Validator, String>>>> =
+        OnceLock::new();
+
+    let mut validators = VALIDATORS
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|err| err.into_inner());
+
+    validators
+        .entry(TypeId::of::<S>())
+        .or_insert_with(|| {
+            jsonschema::validator_for(&S::schema())
+                .map(|validator| &*Box::leak(Box::new(validator)))
+                .map_err(|err| err.to_string())
+        })
+        .clone()
+        .map_err(ValidatedJsonDecodeError::InvalidSchema)
+}
+
+impl<T: Serialize, S: JsonSchemaProvider> Encoder<T> for ValidatedJsonCodec<S> {
+    type Error = serde_json::Error;
+    type Encoded = String;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        JsonSerdeCodec::encode(val)
+    }
+}
+
+impl<T, S> Decoder<T> for ValidatedJsonCodec<S>
+where
+    for<'de> T: Deserialize<'de>,
+    S: JsonSchemaProvider,
+{
+    type Error = ValidatedJsonDecodeError;
+    type Encoded = str;
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        let value: serde_json::Value = serde_json::from_str(val)?;
+
+        let violations = validator::<S>()?
+            .iter_errors(&value)
+            .map(|err| SchemaViolation {
+                instance_path: err.instance_path.as_str().to_owned(),
+                message: err.to_string(),
+            })
+            .collect::<Vec<_>>();
+
+        if !violations.is_empty() {
+            return Err(ValidatedJsonDecodeError::Invalid(violations));
+        }
+
+        Ok(serde_json::from_value(value)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    struct Test {
+        s: String,
+        #[serde(default)]
+        i: i32,
+    }
+
+    struct TestSchema;
+
+    impl JsonSchemaProvider for TestSchema {
+        fn schema() -> serde_json::Value {
+            json!({
+                "type": "object",
+                "properties": {
+                    "s": { "type": "string", "minLength": 1 },
+                    "i": { "type": "integer" }
+                },
+                "required": ["s", "i"]
+            })
+        }
+    }
+
+    type Codec = ValidatedJsonCodec<TestSchema>;
+
+    #[test]
+    fn test_validated_json_codec() {
+        let t = Test {
+            s: String::from("party time 🎉"),
+            i: 42,
+        };
+
+        let enc = Codec::encode(&t).unwrap();
+        let dec: Test = Codec::decode(&enc).unwrap();
+        assert_eq!(dec, t);
+    }
+
+    #[test]
+    fn test_validated_json_codec_missing_required_field() {
+        // would deserialize fine because of `serde(default)`
+        let dec: Result<Test, _> = Codec::decode(r#"{"s":"a"}"#);
+        let Err(err) = dec else {
+            panic!("expected a validation error");
+        };
+        assert!(matches!(err, ValidatedJsonDecodeError::Invalid(_)));
+        assert_eq!(err.instance_paths(), vec![""]);
+        assert!(err.to_string().contains("\"i\" is a required property"));
+    }
+
+    #[test]
+    fn test_validated_json_codec_instance_paths() {
+        let dec: Result<Test, _> = Codec::decode(r#"{"s":"","i":"1"}"#);
+        let mut paths = dec
+            .unwrap_err()
+            .instance_paths()
+            .into_iter()
+            .map(str::to_owned)
+            .collect::<Vec<_>>();
+        paths.sort();
+        assert_eq!(paths, vec!["/i", "/s"]);
+    }
+
+    #[test]
+    fn test_validated_json_codec_invalid_schema() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static SCHEMA_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        struct BrokenSchema;
+
+        impl JsonSchemaProvider for BrokenSchema {
+            fn schema() -> serde_json::Value {
+                SCHEMA_CALLS.fetch_add(1, Ordering::Relaxed);
+                json!({ "type": 42 })
+            }
+        }
+
+        for _ in 0..2 {
+            let dec: Result<Test, _> =
+                ValidatedJsonCodec::<BrokenSchema>::decode(r#"{"s":"a","i":1}"#);
+            assert!(matches!(
+                dec,
+                Err(ValidatedJsonDecodeError::InvalidSchema(_))
+            ));
+        }
+
+        // the error is cached as well
+        assert_eq!(SCHEMA_CALLS.load(Ordering::Relaxed), 1);
+    }
+}
@@ -0,0 +1,64 @@
+use crate::string::JsonSerdeCodec;
+use crate::{Decoder, Encoder};
+use core::convert::Infallible;
+use serde_json::Value;
+
+/// A codec for untyped [`serde_json::Value`]s.
+///
+/// [`JsonSerdeCodec`] handles `Value` as well but this codec makes it explicit that encoding
+/// can't fail: its encode error is [`Infallible`].
+///
+/// Only available with the **`json_serde` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::string::JsonValueCodec;
+/// # use serde_json::json;
+/// #
+/// let original_value = json!({ "hello": "World", "count": 3 });
+///
+/// let Ok(encoded) = JsonValueCodec::encode(&original_value);
+/// let decoded = JsonValueCodec::decode(&encoded).unwrap();
+///
+/// assert_eq!(decoded, original_value);
+/// ```
+pub struct JsonValueCodec;
+
+impl Encoder<Value> for JsonValueCodec {
+    type Error = Infallible;
+    type Encoded = String;
+
+    fn encode(val: &Value) -> Result<Self::Encoded, Self::Error> {
+        Ok(JsonSerdeCodec::encode_value(val))
+    }
+}
+
+impl Decoder<Value> for JsonValueCodec {
+    type Error = serde_json::Error;
+    type Encoded = str;
+
+    fn decode(val: &Self::Encoded) -> Result<Value, Self::Error> {
+        JsonSerdeCodec::decode_value(val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_json_value_codec() {
+        let value = json!({
+            "s": "party time 🎉",
+            "nested": { "list": [1, -2, 3.5, true, null] }
+        });
+
+        let Ok(enc) = JsonValueCodec::encode(&value);
+        assert_eq!(JsonValueCodec::decode(&enc).unwrap(), value);
+
+        assert!(JsonValueCodec::decode("[1, 2").is_err());
+    }
+}
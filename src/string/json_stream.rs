@@ -0,0 +1,179 @@
+use serde::de::DeserializeOwned;
+use std::marker::PhantomData;
+use thiserror::Error;
+
+/// Reassembles JSON values that arrive split into arbitrary byte chunks, for example fragmented
+/// websocket messages, and decodes them with [`serde_json`] as soon as they are complete.
+///
+/// The top-level values have to be objects or arrays. They can follow each other directly or be
+/// separated by whitespace. Braces and brackets are counted to detect the end of a value while
+/// skipping over strings and escape sequences inside them.
+///
+/// Only available with the **`json_serde` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::string::JsonStreamAccumulator;
+/// # use serde::Deserialize;
+/// #
+/// #[derive(Deserialize, PartialEq, Debug)]
+/// struct Message {
+///     text: String,
+/// }
+///
+/// let mut accumulator = JsonStreamAccumulator::<Message>::new();
+///
+/// assert!(accumulator.push(br#"{"text": "hel"#).is_empty());
+///
+/// let messages = accumulator.push(br#"lo"}"#);
+/// assert_eq!(messages.len(), 1);
+/// assert_eq!(messages[0].as_ref().unwrap().text, "hello");
+/// ```
+pub struct JsonStreamAccumulator<T> {
+    buf: Vec<u8>,
+    /// Index in `buf` up to which the bytes have been scanned
+    pos: usize,
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+    _marker: PhantomData<T>,
+}
+
+#[derive(Error, Debug)]
+pub enum JsonStreamError {
+    #[error("unexpected byte 0x{0:02x} outside of a JSON object or array")]
+    UnexpectedByte(u8),
+    #[error("failed to decode: {0}")]
+    Decoder(#[from] serde_json::Error),
+}
+
+impl<T: DeserializeOwned> JsonStreamAccumulator<T> {
+    pub fn new() -> Self {
+        Self {
+            buf: Vec::new(),
+            pos: 0,
+            depth: 0,
+            in_string: false,
+            escaped: false,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Appends `chunk` and returns all values that are complete now. Bytes of an incomplete value
+    /// are kept until the next call.
+    pub fn push(&mut self, chunk: &[u8]) -> Vec<Result<T, JsonStreamError>> {
+        self.buf.extend_from_slice(chunk);
+
+        let mut values = Vec::new();
+        let mut start = 0;
+
+        while self.pos < self.buf.len() {
+            let byte = self.buf[self.pos];
+            self.pos += 1;
+
+            if self.depth == 0 {
+                match byte {
+                    b'{' | b'[' => {
+                        start = self.pos - 1;
+                        self.depth = 1;
+                    }
+                    b' ' | b'\t' | b'\n' | b'\r' => {}
+                    _ => values.push(Err(JsonStreamError::UnexpectedByte(byte))),
+                }
+                continue;
+            }
+
+            if self.in_string {
+                match byte {
+                    _ if self.escaped => self.escaped = false,
+                    b'\\' => self.escaped = true,
+                    b'"' => self.in_string = false,
+                    _ => {}
+                }
+                continue;
+            }
+
+            match byte {
+                b'"' => self.in_string = true,
+                b'{' | b'[' => self.depth += 1,
+                b'}' | b']' => {
+                    self.depth -= 1;
+                    if self.depth == 0 {
+                        values.push(
+                            serde_json::from_slice(&self.buf[start..self.pos])
+                                .map_err(JsonStreamError::Decoder),
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // keep only the value that is still incomplete
+        let consumed = if self.depth == 0 { self.pos } else { start };
+        self.buf.drain(..consumed);
+        self.pos -= consumed;
+
+        values
+    }
+}
+
+impl<T: DeserializeOwned> Default for JsonStreamAccumulator<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Test {
+        s: String,
+        list: Vec<i32>,
+    }
+
+    #[test]
+    fn test_json_stream_accumulator() {
+        let json = r#"{"s": "party {time} \"🎉\" \\", "list": [1, 2]}"#.as_bytes();
+        let expected = Test {
+            s: String::from(r#"party {time} "🎉" \"#),
+            list: vec![1, 2],
+        };
+
+        let mut accumulator = JsonStreamAccumulator::<Test>::new();
+
+        // split inside the string, inside the emoji and inside the array
+        assert!(accumulator.push(&json[..14]).is_empty());
+        assert!(accumulator.push(&json[14..23]).is_empty());
+        assert!(accumulator.push(&json[23..json.len() - 3]).is_empty());
+
+        let values = accumulator.push(&json[json.len() - 3..]);
+        assert_eq!(values.len(), 1);
+        assert_eq!(values[0].as_ref().unwrap(), &expected);
+        assert!(accumulator.buf.is_empty());
+    }
+
+    #[test]
+    fn test_json_stream_accumulator_several_values() {
+        let mut accumulator = JsonStreamAccumulator::<Vec<i32>>::new();
+
+        let values = accumulator.push(b"[1]\n[2, 3] [4");
+        assert_eq!(values.len(), 2);
+        assert_eq!(values[0].as_ref().unwrap(), &vec![1]);
+        assert_eq!(values[1].as_ref().unwrap(), &vec![2, 3]);
+
+        let values = accumulator.push(b"]x");
+        assert_eq!(values.len(), 2);
+        assert_eq!(values[0].as_ref().unwrap(), &vec![4]);
+        assert!(matches!(
+            values[1],
+            Err(JsonStreamError::UnexpectedByte(b'x'))
+        ));
+
+        let values = accumulator.push(br#"{"a": 1}"#);
+        assert!(matches!(values[..], [Err(JsonStreamError::Decoder(_))]));
+    }
+}
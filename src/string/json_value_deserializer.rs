@@ -0,0 +1,212 @@
+use core::marker::PhantomData;
+use serde::de::value::{MapAccessDeserializer, MapDeserializer, SeqDeserializer};
+use serde::de::{IntoDeserializer, Visitor};
+use serde::{forward_to_deserialize_any, Deserializer};
+use serde_json::{Map, Value};
+
+/// Customizes how [`HookedValue`] deserializes certain types. Every method falls back to the
+/// behavior of `serde_json` by default.
+pub(crate) trait ValueHook: Sized + 'static {
+    /// Called wherever a `bool` is expected.
+    fn deserialize_bool<'de, V: Visitor<'de>>(
+        value: Value,
+        visitor: V,
+    ) -> Result<V::Value, serde_json::Error> {
+        HookedValue::<Self>::new(value).deserialize_any(visitor)
+    }
+
+    /// Called wherever an `f32` or `f64` is expected.
+    fn deserialize_float<'de, V: Visitor<'de>>(
+        value: Value,
+        visitor: V,
+    ) -> Result<V::Value, serde_json::Error> {
+        HookedValue::<Self>::new(value).deserialize_any(visitor)
+    }
+
+    /// Maps the variant name (or the key of a variant with data) of an externally tagged enum
+    /// before it is matched against `variants`.
+    fn enum_variant(variant: String, _variants: &'static [&'static str]) -> String {
+        variant
+    }
+}
+
+/// Deserializes from a parsed JSON [`Value`] like `serde_json` does but lets the [`ValueHook`]
+/// `H` handle some types. Nested values are deserialized with the same hook.
+pub(crate) struct HookedValue<H>(Value, PhantomData<H>);
+
+impl<H: ValueHook> HookedValue<H> {
+    pub(crate) fn new(value: Value) -> Self {
+        Self(value, PhantomData)
+    }
+
+    fn map_deserializer(
+        obj: Map<String, Value>,
+    ) -> MapDeserializer<'static, impl Iterator<Item = (MapKey<H>, Self)>, serde_json::Error> {
+        MapDeserializer::new(
+            obj.into_iter()
+                .map(|(k, v)| (MapKey(k, PhantomData), Self::new(v))),
+        )
+    }
+}
+
+impl<'de, H: ValueHook> IntoDeserializer<'de, serde_json::Error> for HookedValue<H> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+impl<'de, H: ValueHook> Deserializer<'de> for HookedValue<H> {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Value::Null => visitor.visit_unit(),
+            Value::Bool(b) => visitor.visit_bool(b),
+            Value::Number(n) => n.deserialize_any(visitor),
+            Value::String(s) => visitor.visit_string(s),
+            Value::Array(arr) => {
+                let mut seq = SeqDeserializer::new(arr.into_iter().map(Self::new));
+                let value = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(value)
+            }
+            Value::Object(obj) => {
+                let mut map = Self::map_deserializer(obj);
+                let value = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(value)
+            }
+        }
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        H::deserialize_bool(self.0, visitor)
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        H::deserialize_float(self.0, visitor)
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        H::deserialize_float(self.0, visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Value::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Value::String(variant) => {
+                visitor.visit_enum(H::enum_variant(variant, variants).into_deserializer())
+            }
+            Value::Object(obj) => {
+                let obj = obj
+                    .into_iter()
+                    .map(|(k, v)| (H::enum_variant(k, variants), v))
+                    .collect();
+                visitor.visit_enum(MapAccessDeserializer::new(Self::map_deserializer(obj)))
+            }
+            other => Self::new(other).deserialize_any(visitor),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 char str string
+        bytes byte_buf unit unit_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+/// Deserializes an object key. JSON keys are always strings so, like `serde_json`, numbers and
+/// bools are parsed from the key when they are expected.
+struct MapKey<H>(String, PhantomData<H>);
+
+macro_rules! deserialize_parsed_key {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                match self.0.parse() {
+                    Ok(val) => visitor.$visit(val),
+                    Err(_) => visitor.visit_string(self.0),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de, H: ValueHook> IntoDeserializer<'de, serde_json::Error> for MapKey<H> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+impl<'de, H: ValueHook> Deserializer<'de> for MapKey<H> {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_string(self.0)
+    }
+
+    deserialize_parsed_key! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_enum(H::enum_variant(self.0, variants).into_deserializer())
+    }
+
+    forward_to_deserialize_any! {
+        char str string bytes byte_buf unit unit_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
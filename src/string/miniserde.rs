@@ -1,5 +1,6 @@
 use crate::{Decoder, Encoder};
 use miniserde::{json, Deserialize, Serialize};
+use std::convert::Infallible;
 
 /// A codec that relies on `miniserde` to encode data in the json format.
 ///
@@ -7,7 +8,7 @@ use miniserde::{json, Deserialize, Serialize};
 pub struct MiniserdeCodec;
 
 impl<T: Serialize> Encoder<T> for MiniserdeCodec {
-    type Error = ();
+    type Error = Infallible;
     type Encoded = String;
 
     fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
@@ -39,7 +40,8 @@ mod tests {
             s: String::from("party time 🎉"),
             i: 42,
         };
-        let enc = MiniserdeCodec::encode(&t).unwrap();
+        let enc: Result<String, Infallible> = MiniserdeCodec::encode(&t);
+        let enc = enc.unwrap();
         let dec: Test = MiniserdeCodec::decode(&enc).unwrap();
         assert_eq!(dec, t);
     }
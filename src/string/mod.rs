@@ -1,21 +1,123 @@
+mod ascii85;
+#[cfg(feature = "base16384")]
+mod base16384;
 #[cfg(feature = "base64")]
 mod base64;
+#[cfg(feature = "deflate")]
+mod deflated_base64;
+mod enum_string;
 mod from_to_string;
+#[cfg(all(feature = "json_serde", feature = "gzip", feature = "base64"))]
+mod json_gzip_base64;
+#[cfg(feature = "json_serde")]
+mod json_lines;
 #[cfg(feature = "json_serde")]
 mod json_serde;
+#[cfg(feature = "json_serde")]
+mod json_serde_camel_case;
+#[cfg(feature = "json_serde")]
+mod json_serde_case_insensitive_enum;
+#[cfg(feature = "json_serde")]
+mod json_serde_lenient_bool;
+#[cfg(feature = "json_serde")]
+mod json_serde_nan_safe;
+#[cfg(feature = "json_serde")]
+mod json_serde_pretty;
+#[cfg(feature = "json_serde")]
+mod json_serde_rounded;
+#[cfg(feature = "json_serde")]
+mod json_serde_stable_map;
+#[cfg(feature = "jsonschema")]
+mod json_serde_validated;
+#[cfg(feature = "json_serde")]
+mod json_serde_value;
 #[cfg(feature = "json_serde_wasm")]
 mod json_serde_wasm;
+#[cfg(feature = "json_serde")]
+mod json_stream;
+#[cfg(feature = "json_serde")]
+mod json_value_deserializer;
 #[cfg(feature = "miniserde")]
 mod miniserde;
+mod multi_field;
+#[cfg(feature = "nanoserde")]
+mod nanoserde;
+mod non_zero;
 mod option;
+#[cfg(feature = "percent")]
+mod percent_encoded;
+mod range;
+mod result;
+#[cfg(feature = "datetime")]
+mod rfc3339;
+mod separated;
+#[cfg(feature = "simd_json")]
+mod simd_json;
+mod structured_or_string;
+#[cfg(feature = "uuid")]
+mod uuid;
+#[cfg(feature = "xml")]
+mod xml;
+#[cfg(feature = "z85")]
+mod z85;
 
+pub use ascii85::*;
+#[cfg(feature = "base16384")]
+pub use base16384::*;
 #[cfg(feature = "base64")]
 pub use base64::*;
+#[cfg(feature = "deflate")]
+pub use deflated_base64::*;
+pub use enum_string::*;
 pub use from_to_string::*;
+#[cfg(all(feature = "json_serde", feature = "gzip", feature = "base64"))]
+pub use json_gzip_base64::*;
+#[cfg(feature = "json_serde")]
+pub use json_lines::*;
 #[cfg(feature = "json_serde")]
 pub use json_serde::*;
+#[cfg(feature = "json_serde")]
+pub use json_serde_camel_case::*;
+#[cfg(feature = "json_serde")]
+pub use json_serde_case_insensitive_enum::*;
+#[cfg(feature = "json_serde")]
+pub use json_serde_lenient_bool::*;
+#[cfg(feature = "json_serde")]
+pub use json_serde_nan_safe::*;
+#[cfg(feature = "json_serde")]
+pub use json_serde_pretty::*;
+#[cfg(feature = "json_serde")]
+pub use json_serde_rounded::*;
+#[cfg(feature = "json_serde")]
+pub use json_serde_stable_map::*;
+#[cfg(feature = "jsonschema")]
+pub use json_serde_validated::*;
+#[cfg(feature = "json_serde")]
+pub use json_serde_value::*;
 #[cfg(feature = "json_serde_wasm")]
 pub use json_serde_wasm::*;
+#[cfg(feature = "json_serde")]
+pub use json_stream::*;
 #[cfg(feature = "miniserde")]
 pub use miniserde::*;
+pub use multi_field::*;
+#[cfg(feature = "nanoserde")]
+pub use nanoserde::*;
+pub use non_zero::*;
 pub use option::*;
+#[cfg(feature = "percent")]
+pub use percent_encoded::*;
+pub use range::*;
+pub use result::*;
+#[cfg(feature = "datetime")]
+pub use rfc3339::*;
+pub use separated::*;
+#[cfg(feature = "simd_json")]
+pub use simd_json::*;
+pub use structured_or_string::*;
+#[cfg(feature = "uuid")]
+pub use uuid::*;
+#[cfg(feature = "xml")]
+pub use xml::*;
+#[cfg(feature = "z85")]
+pub use z85::*;
@@ -0,0 +1,247 @@
+use crate::dyn_codec::dyn_codec_error;
+use crate::{Decoder, DynCodecError, Encoder};
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use core::convert::Infallible;
+use core::fmt::Debug;
+use thiserror::Error;
+
+/// Separately encoded fields of a type that is encoded with [`MultiFieldCodec`].
+pub type Fields = BTreeMap<&'static str, String>;
+
+/// Implement this for types that are encoded with [`MultiFieldCodec`]. Every field is encoded
+/// with its own string codec under its own name.
+pub trait MultiField {
+    /// Encodes the fields of `self` by calling [`FieldsEncoder::field`] for every field.
+    fn encode_fields(&self, fields: &mut FieldsEncoder) -> Result<(), MultiFieldEncodeError>;
+
+    /// Creates `Self` from the fields by calling [`FieldsDecoder::field`] for every field.
+    fn decode_fields(fields: &FieldsDecoder) -> Self;
+}
+
+/// A codec that splits a type into several separately encoded string fields.
+///
+/// This makes it easy to avoid versioning entirely as described in the
+/// [versioning section](crate#versioning): every field is stored as its own string, so a field
+/// that is missing or can't be decoded falls back to its default value without affecting the
+/// other fields. New fields can be added and old ones removed at any time.
+///
+/// The fields are defined by implementing [`MultiField`].
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::string::{
+/// #     FieldsDecoder, FieldsEncoder, FromToStringCodec, MultiField, MultiFieldCodec,
+/// #     MultiFieldEncodeError,
+/// # };
+/// #
+/// #[derive(Default, PartialEq, Debug)]
+/// struct Settings {
+///     timezone: String,
+///     thousand_separator: bool,
+/// }
+///
+/// impl MultiField for Settings {
+///     fn encode_fields(&self, fields: &mut FieldsEncoder) -> Result<(), MultiFieldEncodeError> {
+///         fields.field::<FromToStringCodec, _>("timezone", &self.timezone)?;
+///         fields.field::<FromToStringCodec, _>("thousand_separator", &self.thousand_separator)?;
+///         Ok(())
+///     }
+///
+///     fn decode_fields(fields: &FieldsDecoder) -> Self {
+///         Self {
+///             timezone: fields.field::<FromToStringCodec, _>("timezone"),
+///             thousand_separator: fields.field::<FromToStringCodec, _>("thousand_separator"),
+///         }
+///     }
+/// }
+///
+/// let settings = Settings {
+///     timezone: "Europe/Berlin".to_owned(),
+///     thousand_separator: true,
+/// };
+///
+/// let mut encoded = MultiFieldCodec::encode(&settings).unwrap();
+/// assert_eq!(encoded["timezone"], "Europe/Berlin");
+/// assert_eq!(encoded["thousand_separator"], "true");
+///
+/// encoded.remove("thousand_separator");
+/// let decoded: Settings = MultiFieldCodec::decode(&encoded).unwrap();
+///
+/// assert_eq!(decoded.timezone, "Europe/Berlin");
+/// assert!(!decoded.thousand_separator);
+/// ```
+pub struct MultiFieldCodec;
+
+#[derive(Error, Debug)]
+#[error("failed to encode field `{field}`: {error}")]
+pub struct MultiFieldEncodeError {
+    /// The name of the field that failed to encode
+    pub field: &'static str,
+    /// The error of the field's codec
+    #[source]
+    pub error: DynCodecError,
+}
+
+/// Collects the encoded fields in [`MultiField::encode_fields`].
+pub struct FieldsEncoder {
+    fields: Fields,
+}
+
+impl FieldsEncoder {
+    /// Encodes `val` with the string codec `C` and stores it under `name`.
+    pub fn field<C, V>(&mut self, name: &'static str, val: &V) -> Result<(), MultiFieldEncodeError>
+    where
+        C: Encoder<V, Encoded = String>,
+        C::Error: Debug + 'static,
+    {
+        let encoded = C::encode(val).map_err(|err| MultiFieldEncodeError {
+            field: name,
+            error: dyn_codec_error(err),
+        })?;
+        self.fields.insert(name, encoded);
+
+        Ok(())
+    }
+}
+
+/// Provides the encoded fields in [`MultiField::decode_fields`].
+pub struct FieldsDecoder<'a> {
+    fields: &'a Fields,
+}
+
+impl FieldsDecoder<'_> {
+    /// Decodes the field `name` with the string codec `C`. Returns the default value if the
+    /// field is missing or can't be decoded.
+    pub fn field<C, V>(&self, name: &str) -> V
+    where
+        C: Decoder<V, Encoded = str>,
+        V: Default,
+    {
+        self.field_or::<C, V>(name, V::default())
+    }
+
+    /// Like [`FieldsDecoder::field`] but returns `default` if the field is missing or can't be
+    /// decoded.
+    pub fn field_or<C, V>(&self, name: &str, default: V) -> V
+    where
+        C: Decoder<V, Encoded = str>,
+    {
+        self.fields
+            .get(name)
+            .and_then(|encoded| C::decode(encoded).ok())
+            .unwrap_or(default)
+    }
+}
+
+impl<T: MultiField> Encoder<T> for MultiFieldCodec {
+    type Error = MultiFieldEncodeError;
+    type Encoded = Fields;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        let mut encoder = FieldsEncoder {
+            fields: Fields::new(),
+        };
+        val.encode_fields(&mut encoder)?;
+
+        Ok(encoder.fields)
+    }
+}
+
+impl<T: MultiField> Decoder<T> for MultiFieldCodec {
+    type Error = Infallible;
+    type Encoded = Fields;
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        Ok(T::decode_fields(&FieldsDecoder { fields: val }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::string::FromToStringCodec;
+
+    #[derive(Debug, PartialEq)]
+    struct Settings {
+        timezone: String,
+        thousand_separator: bool,
+        volume: u8,
+    }
+
+    impl Default for Settings {
+        fn default() -> Self {
+            Self {
+                timezone: "UTC".to_owned(),
+                thousand_separator: false,
+                volume: 50,
+            }
+        }
+    }
+
+    impl MultiField for Settings {
+        fn encode_fields(&self, fields: &mut FieldsEncoder) -> Result<(), MultiFieldEncodeError> {
+            fields.field::<FromToStringCodec, _>("timezone", &self.timezone)?;
+            fields.field::<FromToStringCodec, _>("thousand_separator", &self.thousand_separator)?;
+            fields.field::<FromToStringCodec, _>("volume", &self.volume)?;
+            Ok(())
+        }
+
+        fn decode_fields(fields: &FieldsDecoder) -> Self {
+            let default = Self::default();
+
+            Self {
+                timezone: fields.field::<FromToStringCodec, _>("timezone"),
+                thousand_separator: fields.field::<FromToStringCodec, _>("thousand_separator"),
+                volume: fields.field_or::<FromToStringCodec, _>("volume", default.volume),
+            }
+        }
+    }
+
+    #[test]
+    fn test_multi_field_codec() {
+        let settings = Settings {
+            timezone: "Europe/Berlin".to_owned(),
+            thousand_separator: true,
+            volume: 11,
+        };
+
+        let enc = MultiFieldCodec::encode(&settings).unwrap();
+        assert_eq!(enc.len(), 3);
+        assert_eq!(enc["volume"], "11");
+
+        let dec: Settings = MultiFieldCodec::decode(&enc).unwrap();
+        assert_eq!(dec, settings);
+    }
+
+    #[test]
+    fn test_multi_field_codec_missing_and_invalid_fields() {
+        let settings = Settings {
+            timezone: "Europe/Berlin".to_owned(),
+            thousand_separator: true,
+            volume: 11,
+        };
+
+        let mut enc = MultiFieldCodec::encode(&settings).unwrap();
+        enc.remove("thousand_separator");
+
+        let dec: Settings = MultiFieldCodec::decode(&enc).unwrap();
+        assert_eq!(
+            dec,
+            Settings {
+                timezone: "Europe/Berlin".to_owned(),
+                thousand_separator: false,
+                volume: 11,
+            }
+        );
+
+        enc.insert("volume", "loud".to_owned());
+        enc.insert("unknown", "ignored".to_owned());
+
+        let dec: Settings = MultiFieldCodec::decode(&enc).unwrap();
+        assert_eq!(dec.volume, 50);
+        assert_eq!(dec.timezone, "Europe/Berlin");
+    }
+}
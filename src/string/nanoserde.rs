@@ -0,0 +1,80 @@
+use crate::{Decoder, Encoder};
+use nanoserde::{DeJson, DeJsonErr, SerJson};
+use std::convert::Infallible;
+
+/// A codec that relies on `nanoserde` to encode data in the json format.
+///
+/// nanoserde has no dependencies besides its derive macros and compiles a lot faster than serde,
+/// which makes it a lightweight alternative to [`JsonSerdeCodec`](crate::string::JsonSerdeCodec)
+/// for projects where build times matter, e.g. WASM apps.
+///
+/// This is only available with the **`nanoserde` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::string::NanoJsonCodec;
+/// # use nanoserde::{DeJson, SerJson};
+/// #
+/// #[derive(DeJson, SerJson, PartialEq, Debug)]
+/// struct MyState {
+///     chicken_count: u32,
+///     farm_name: String,
+/// }
+///
+/// let original_value = MyState {
+///     chicken_count: 10,
+///     farm_name: "My Farm".to_owned(),
+/// };
+///
+/// let encoded = NanoJsonCodec::encode(&original_value).unwrap();
+/// let decoded: MyState = NanoJsonCodec::decode(&encoded).unwrap();
+///
+/// assert_eq!(decoded, original_value);
+/// ```
+pub struct NanoJsonCodec;
+
+impl<T: SerJson> Encoder<T> for NanoJsonCodec {
+    type Error = Infallible;
+    type Encoded = String;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        Ok(val.serialize_json())
+    }
+}
+
+impl<T: DeJson> Decoder<T> for NanoJsonCodec {
+    type Error = DeJsonErr;
+    type Encoded = str;
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        T::deserialize_json(val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nano_json_codec() {
+        #[derive(Clone, Debug, PartialEq, SerJson, DeJson)]
+        struct Test {
+            s: String,
+            i: i32,
+            list: Vec<f64>,
+        }
+        let t = Test {
+            s: String::from("party time 🎉"),
+            i: 42,
+            list: vec![0.5, -1.25],
+        };
+        let enc = NanoJsonCodec::encode(&t).unwrap();
+        let dec: Test = NanoJsonCodec::decode(&enc).unwrap();
+        assert_eq!(dec, t);
+
+        let dec: Result<Test, _> = NanoJsonCodec::decode("{\"s\": 42}");
+        assert!(dec.is_err());
+    }
+}
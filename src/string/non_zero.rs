@@ -0,0 +1,109 @@
+use crate::{Decoder, Encoder};
+use alloc::string::{String, ToString};
+use core::convert::Infallible;
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroU128, NonZeroU16,
+    NonZeroU32, NonZeroU64, NonZeroU8, ParseIntError,
+};
+use thiserror::Error;
+
+/// A string codec for the `std::num::NonZero*` integer types like [`NonZeroU32`].
+///
+/// Encodes the number as decimal string just like
+/// [`FromToStringCodec`](crate::string::FromToStringCodec) but decoding a zero returns the
+/// dedicated error [`NonZeroDecodeError::WasZero`].
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::string::{NonZeroCodec, NonZeroDecodeError};
+/// # use std::num::NonZeroU32;
+/// #
+/// let counter = NonZeroU32::new(42).unwrap();
+///
+/// let encoded = NonZeroCodec::encode(&counter).unwrap();
+/// assert_eq!(encoded, "42");
+///
+/// let decoded: NonZeroU32 = NonZeroCodec::decode(encoded.as_str()).unwrap();
+/// assert_eq!(decoded, counter);
+///
+/// let decoded: Result<NonZeroU32, _> = NonZeroCodec::decode("0");
+/// assert_eq!(decoded, Err(NonZeroDecodeError::WasZero));
+/// ```
+pub struct NonZeroCodec;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum NonZeroDecodeError {
+    #[error("value was zero")]
+    WasZero,
+    #[error("failed to parse integer: {0}")]
+    Parse(#[from] ParseIntError),
+}
+
+macro_rules! impl_non_zero_codec {
+    ($($non_zero:ty => $int:ty),* $(,)?) => {
+        $(
+            impl Encoder<$non_zero> for NonZeroCodec {
+                type Error = Infallible;
+                type Encoded = String;
+
+                fn encode(val: &$non_zero) -> Result<Self::Encoded, Self::Error> {
+                    Ok(val.to_string())
+                }
+            }
+
+            impl Decoder<$non_zero> for NonZeroCodec {
+                type Error = NonZeroDecodeError;
+                type Encoded = str;
+
+                fn decode(val: &Self::Encoded) -> Result<$non_zero, Self::Error> {
+                    let val: $int = val.parse()?;
+                    <$non_zero>::new(val).ok_or(NonZeroDecodeError::WasZero)
+                }
+            }
+        )*
+    };
+}
+
+impl_non_zero_codec!(
+    NonZeroU8 => u8,
+    NonZeroU16 => u16,
+    NonZeroU32 => u32,
+    NonZeroU64 => u64,
+    NonZeroU128 => u128,
+    NonZeroI8 => i8,
+    NonZeroI16 => i16,
+    NonZeroI32 => i32,
+    NonZeroI64 => i64,
+    NonZeroI128 => i128,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_non_zero_codec() {
+        let v = NonZeroU64::new(u64::MAX).unwrap();
+        let enc = NonZeroCodec::encode(&v).unwrap();
+        assert_eq!(NonZeroCodec::decode(enc.as_str()), Ok(v));
+
+        let v = NonZeroI8::new(-5).unwrap();
+        let enc = NonZeroCodec::encode(&v).unwrap();
+        assert_eq!(enc, "-5");
+        assert_eq!(NonZeroCodec::decode(enc.as_str()), Ok(v));
+    }
+
+    #[test]
+    fn test_non_zero_codec_zero() {
+        let dec: Result<NonZeroU32, _> = NonZeroCodec::decode("0");
+        assert_eq!(dec, Err(NonZeroDecodeError::WasZero));
+
+        let dec: Result<NonZeroI64, _> = NonZeroCodec::decode("-0");
+        assert_eq!(dec, Err(NonZeroDecodeError::WasZero));
+
+        let dec: Result<NonZeroU8, _> = NonZeroCodec::decode("256");
+        assert!(matches!(dec, Err(NonZeroDecodeError::Parse(_))));
+    }
+}
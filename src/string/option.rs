@@ -1,4 +1,7 @@
 use crate::{Decoder, Encoder};
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::String;
 
 /// Wraps a string codec that encodes `T` to create a codec that encodes `Option<T>`.
 ///
@@ -0,0 +1,165 @@
+use crate::{Decoder, Encoder};
+use alloc::string::{String, ToString};
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use thiserror::Error;
+
+/// Characters that are never escaped: the unreserved characters of
+/// [RFC 3986](https://datatracker.ietf.org/doc/html/rfc3986#section-2.3).
+const UNRESERVED: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Selects which characters [`PercentEncoded`] escapes.
+pub trait PercentEncodeSet: 'static {
+    const SET: &'static AsciiSet;
+}
+
+/// Encode set for a single URL path segment. Escapes everything except the unreserved
+/// characters and the sub-delimiters as well as `:` and `@`. Most importantly it escapes `/`.
+pub struct PathSegment;
+
+impl PercentEncodeSet for PathSegment {
+    const SET: &'static AsciiSet = &UNRESERVED
+        .remove(b'!')
+        .remove(b'$')
+        .remove(b'&')
+        .remove(b'\'')
+        .remove(b'(')
+        .remove(b')')
+        .remove(b'*')
+        .remove(b'+')
+        .remove(b',')
+        .remove(b';')
+        .remove(b'=')
+        .remove(b':')
+        .remove(b'@');
+}
+
+/// Encode set for a query parameter key or value. Escapes everything except the unreserved
+/// characters and `!$'()*,;:@/?`. Most importantly it escapes `&`, `=` and `+`.
+pub struct QueryComponent;
+
+impl PercentEncodeSet for QueryComponent {
+    const SET: &'static AsciiSet = &UNRESERVED
+        .remove(b'!')
+        .remove(b'$')
+        .remove(b'\'')
+        .remove(b'(')
+        .remove(b')')
+        .remove(b'*')
+        .remove(b',')
+        .remove(b';')
+        .remove(b':')
+        .remove(b'@')
+        .remove(b'/')
+        .remove(b'?');
+}
+
+/// Wraps a string codec and percent-encodes its output so it can be put into a URL.
+///
+/// The encode set `S` selects which characters are escaped. It defaults to [`PathSegment`].
+/// Use [`QueryComponent`] for query parameters.
+///
+/// Only available with the **`percent` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::string::{FromToStringCodec, PercentEncoded, QueryComponent};
+/// #
+/// let value = "a/b c".to_owned();
+///
+/// let encoded = PercentEncoded::<FromToStringCodec>::encode(&value).unwrap();
+/// assert_eq!(encoded, "a%2Fb%20c");
+///
+/// let decoded: String = PercentEncoded::<FromToStringCodec>::decode(&encoded).unwrap();
+/// assert_eq!(decoded, value);
+///
+/// let encoded = PercentEncoded::<FromToStringCodec, QueryComponent>::encode(&value).unwrap();
+/// assert_eq!(encoded, "a/b%20c");
+/// ```
+pub struct PercentEncoded<C, S = PathSegment>(C, S);
+
+#[derive(Error, Debug, PartialEq)]
+pub enum PercentEncodedDecodeError<Err> {
+    #[error("percent-decoded string is not valid UTF-8: {0}")]
+    InvalidUtf8(#[from] core::str::Utf8Error),
+    #[error("failed to decode: {0}")]
+    Decoder(Err),
+}
+
+impl<T, E, S> Encoder<T> for PercentEncoded<E, S>
+where
+    E: Encoder<T, Encoded = String>,
+    S: PercentEncodeSet,
+{
+    type Error = E::Error;
+    type Encoded = String;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        let encoded = E::encode(val)?;
+        Ok(utf8_percent_encode(&encoded, S::SET).to_string())
+    }
+}
+
+impl<T, D, S> Decoder<T> for PercentEncoded<D, S>
+where
+    D: Decoder<T, Encoded = str>,
+    S: PercentEncodeSet,
+{
+    type Error = PercentEncodedDecodeError<D::Error>;
+    type Encoded = str;
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        let decoded = percent_decode_str(val).decode_utf8()?;
+        D::decode(&decoded).map_err(PercentEncodedDecodeError::Decoder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::string::FromToStringCodec;
+
+    #[test]
+    fn test_percent_encoded_path_segment() {
+        type Codec = PercentEncoded<FromToStringCodec, PathSegment>;
+
+        let s = String::from("party time/🎉?a=1&b=2%");
+
+        let enc = Codec::encode(&s).unwrap();
+        assert_eq!(enc, "party%20time%2F%F0%9F%8E%89%3Fa=1&b=2%25");
+        let dec: String = Codec::decode(&enc).unwrap();
+        assert_eq!(dec, s);
+    }
+
+    #[test]
+    fn test_percent_encoded_query_component() {
+        type Codec = PercentEncoded<FromToStringCodec, QueryComponent>;
+
+        let s = String::from("party time/🎉?a=1&b=2+3");
+
+        let enc = Codec::encode(&s).unwrap();
+        assert_eq!(enc, "party%20time/%F0%9F%8E%89?a%3D1%26b%3D2%2B3");
+        let dec: String = Codec::decode(&enc).unwrap();
+        assert_eq!(dec, s);
+    }
+
+    #[test]
+    fn test_percent_encoded_errors() {
+        type Codec = PercentEncoded<FromToStringCodec>;
+
+        let dec: Result<String, _> = Codec::decode("%FF");
+        assert!(matches!(
+            dec,
+            Err(PercentEncodedDecodeError::InvalidUtf8(_))
+        ));
+
+        let dec: Result<i32, _> = Codec::decode("4%202");
+        assert!(matches!(dec, Err(PercentEncodedDecodeError::Decoder(_))));
+        assert_eq!(Codec::decode("-42"), Ok(-42));
+    }
+}
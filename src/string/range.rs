@@ -0,0 +1,116 @@
+use crate::{Decoder, Encoder};
+use alloc::format;
+use alloc::string::String;
+use core::ops::Range;
+use thiserror::Error;
+
+/// Wraps a string codec that encodes `T` to create a codec that encodes `Range<T>` as
+/// `start..end`.
+///
+/// Decoding splits the input at the first `..`, so the wrapped codec must not produce `..`
+/// for the start bound. Negative numbers like `-5..-1` are no problem.
+///
+/// By default, decoding rejects inverted ranges where `start > end`. Set the const parameter
+/// `ALLOW_INVERTED` to `true` to accept them.
+///
+/// Example:
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::string::{RangeCodec, FromToStringCodec};
+/// #
+/// let original_value = 3..10;
+/// let encoded = RangeCodec::<FromToStringCodec>::encode(&original_value).unwrap();
+/// let decoded = RangeCodec::<FromToStringCodec>::decode(&encoded).unwrap();
+///
+/// assert_eq!(encoded, "3..10");
+/// assert_eq!(decoded, original_value);
+///
+/// // Inverted ranges are only accepted if explicitly allowed
+/// let inverted: Result<std::ops::Range<i32>, _> = RangeCodec::<FromToStringCodec>::decode("10..3");
+/// assert!(inverted.is_err());
+///
+/// let inverted: std::ops::Range<i32> = RangeCodec::<FromToStringCodec, true>::decode("10..3").unwrap();
+/// assert_eq!((inverted.start, inverted.end), (10, 3));
+/// ```
+pub struct RangeCodec<C, const ALLOW_INVERTED: bool = false>(C);
+
+#[derive(Error, Debug, PartialEq)]
+pub enum RangeDecodeError<Err> {
+    #[error("missing `..` separator")]
+    MissingSeparator,
+    #[error("start of range is greater than its end")]
+    Inverted,
+    #[error("failed to decode: {0}")]
+    Decoder(Err),
+}
+
+impl<T, E, const ALLOW_INVERTED: bool> Encoder<Range<T>> for RangeCodec<E, ALLOW_INVERTED>
+where
+    E: Encoder<T, Encoded = String>,
+{
+    type Error = E::Error;
+    type Encoded = String;
+
+    fn encode(val: &Range<T>) -> Result<String, Self::Error> {
+        Ok(format!(
+            "{}..{}",
+            E::encode(&val.start)?,
+            E::encode(&val.end)?
+        ))
+    }
+}
+
+impl<T, D, const ALLOW_INVERTED: bool> Decoder<Range<T>> for RangeCodec<D, ALLOW_INVERTED>
+where
+    T: PartialOrd,
+    D: Decoder<T, Encoded = str>,
+{
+    type Error = RangeDecodeError<D::Error>;
+    type Encoded = str;
+
+    fn decode(val: &Self::Encoded) -> Result<Range<T>, Self::Error> {
+        let (start, end) = val
+            .split_once("..")
+            .ok_or(RangeDecodeError::MissingSeparator)?;
+
+        let start = D::decode(start).map_err(RangeDecodeError::Decoder)?;
+        let end = D::decode(end).map_err(RangeDecodeError::Decoder)?;
+
+        if !ALLOW_INVERTED && start > end {
+            return Err(RangeDecodeError::Inverted);
+        }
+
+        Ok(start..end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::string::FromToStringCodec;
+
+    #[test]
+    fn test_range_codec() {
+        let r = 3..10;
+        let enc = RangeCodec::<FromToStringCodec>::encode(&r).unwrap();
+        assert_eq!(enc, "3..10");
+        assert_eq!(RangeCodec::<FromToStringCodec>::decode(&enc), Ok(r));
+
+        let r = -5..-1;
+        let enc = RangeCodec::<FromToStringCodec>::encode(&r).unwrap();
+        assert_eq!(enc, "-5..-1");
+        assert_eq!(RangeCodec::<FromToStringCodec>::decode(&enc), Ok(r));
+    }
+
+    #[test]
+    fn test_range_codec_inverted() {
+        assert_eq!(
+            RangeCodec::<FromToStringCodec>::decode("10..3") as Result<Range<i32>, _>,
+            Err(RangeDecodeError::Inverted)
+        );
+
+        let dec: Range<i32> = RangeCodec::<FromToStringCodec, true>::decode("10..3").unwrap();
+        assert_eq!((dec.start, dec.end), (10, 3));
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{Decoder, Encoder};
+use alloc::format;
+use alloc::string::String;
+use thiserror::Error;
+
+/// Wraps two string codecs that encode `T` and `E` to create a codec that encodes
+/// `Result<T, E>`.
+///
+/// The encoded value is prefixed with a tag so that the right codec is picked on decode even if
+/// `T` and `E` are the same type.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::string::{ResultCodec, FromToStringCodec};
+/// #
+/// type Codec = ResultCodec<FromToStringCodec, FromToStringCodec>;
+///
+/// let original_value: Result<u32, String> = Err("not found".to_owned());
+/// let encoded = Codec::encode(&original_value).unwrap();
+/// let decoded: Result<u32, String> = Codec::decode(&encoded).unwrap();
+///
+/// assert_eq!(decoded, original_value);
+/// ```
+pub struct ResultCodec<OkC, ErrC>(OkC, ErrC);
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ResultCodecError<OkErr, ErrErr> {
+    #[error("missing Ok or Err tag")]
+    MissingTag,
+    #[error("failed to encode or decode the Ok value: {0}")]
+    Ok(OkErr),
+    #[error("failed to encode or decode the Err value: {0}")]
+    Err(ErrErr),
+}
+
+const OK_TAG: &str = "~<|Ok|>~";
+const ERR_TAG: &str = "~<|Err|>~";
+
+impl<T, E, OkC, ErrC> Encoder<Result<T, E>> for ResultCodec<OkC, ErrC>
+where
+    OkC: Encoder<T, Encoded = String>,
+    ErrC: Encoder<E, Encoded = String>,
+{
+    type Error = ResultCodecError<OkC::Error, ErrC::Error>;
+    type Encoded = String;
+
+    fn encode(val: &Result<T, E>) -> Result<String, Self::Error> {
+        match val {
+            Ok(val) => Ok(format!(
+                "{OK_TAG}{}",
+                OkC::encode(val).map_err(ResultCodecError::Ok)?
+            )),
+            Err(val) => Ok(format!(
+                "{ERR_TAG}{}",
+                ErrC::encode(val).map_err(ResultCodecError::Err)?
+            )),
+        }
+    }
+}
+
+impl<T, E, OkC, ErrC> Decoder<Result<T, E>> for ResultCodec<OkC, ErrC>
+where
+    OkC: Decoder<T, Encoded = str>,
+    ErrC: Decoder<E, Encoded = str>,
+{
+    type Error = ResultCodecError<OkC::Error, ErrC::Error>;
+    type Encoded = str;
+
+    fn decode(str: &Self::Encoded) -> Result<Result<T, E>, Self::Error> {
+        if let Some(val) = str.strip_prefix(OK_TAG) {
+            OkC::decode(val).map(Ok).map_err(ResultCodecError::Ok)
+        } else if let Some(val) = str.strip_prefix(ERR_TAG) {
+            ErrC::decode(val).map(Err).map_err(ResultCodecError::Err)
+        } else {
+            Err(ResultCodecError::MissingTag)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::string::FromToStringCodec;
+
+    type Codec = ResultCodec<FromToStringCodec, FromToStringCodec>;
+
+    #[test]
+    fn test_result_codec() {
+        let ok: Result<i32, String> = Ok(42);
+        let enc = Codec::encode(&ok).unwrap();
+        assert_eq!(Codec::decode(&enc), Ok(ok));
+
+        let err: Result<i32, String> = Err(String::from("party time 🎉"));
+        let enc = Codec::encode(&err).unwrap();
+        assert_eq!(Codec::decode(&enc), Ok(err));
+    }
+
+    #[test]
+    fn test_result_codec_same_type() {
+        let ok: Result<String, String> = Ok(String::from("same"));
+        let err: Result<String, String> = Err(String::from("same"));
+
+        let enc_ok = Codec::encode(&ok).unwrap();
+        let enc_err = Codec::encode(&err).unwrap();
+        assert_ne!(enc_ok, enc_err);
+
+        assert_eq!(Codec::decode(enc_ok.as_str()), Ok(ok));
+        assert_eq!(Codec::decode(enc_err.as_str()), Ok(err));
+    }
+
+    #[test]
+    fn test_result_codec_invalid() {
+        let dec: Result<Result<i32, String>, _> = Codec::decode("42");
+        assert_eq!(dec, Err(ResultCodecError::MissingTag));
+
+        let dec: Result<Result<i32, String>, _> = Codec::decode("~<|Ok|>~x");
+        assert!(matches!(dec, Err(ResultCodecError::Ok(_))));
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{Decoder, Encoder};
+use alloc::string::String;
+use chrono::{DateTime, FixedOffset, SecondsFormat, Utc};
+
+/// A codec that encodes [`chrono`] date times as [RFC 3339](https://datatracker.ietf.org/doc/html/rfc3339)
+/// strings like `2024-08-23T12:34:56.789Z`.
+///
+/// Fractional seconds are only written if they are not zero. `DateTime<Utc>` is written with a
+/// `Z` suffix while `DateTime<FixedOffset>` keeps its offset. Decoding accepts any offset and
+/// converts to the target time zone.
+///
+/// Only available with the **`datetime` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::string::Rfc3339Codec;
+/// # use chrono::{DateTime, TimeZone, Utc};
+/// #
+/// let original_value = Utc.with_ymd_and_hms(2024, 8, 23, 12, 34, 56).unwrap();
+///
+/// let encoded = Rfc3339Codec::encode(&original_value).unwrap();
+/// assert_eq!(encoded, "2024-08-23T12:34:56Z");
+///
+/// let decoded: DateTime<Utc> = Rfc3339Codec::decode("2024-08-23T14:34:56+02:00").unwrap();
+/// assert_eq!(decoded, original_value);
+/// ```
+pub struct Rfc3339Codec;
+
+impl Encoder<DateTime<Utc>> for Rfc3339Codec {
+    type Error = ();
+    type Encoded = String;
+
+    fn encode(val: &DateTime<Utc>) -> Result<Self::Encoded, Self::Error> {
+        Ok(val.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+    }
+}
+
+impl Decoder<DateTime<Utc>> for Rfc3339Codec {
+    type Error = chrono::ParseError;
+    type Encoded = str;
+
+    fn decode(val: &Self::Encoded) -> Result<DateTime<Utc>, Self::Error> {
+        DateTime::parse_from_rfc3339(val).map(|dt| dt.with_timezone(&Utc))
+    }
+}
+
+impl Encoder<DateTime<FixedOffset>> for Rfc3339Codec {
+    type Error = ();
+    type Encoded = String;
+
+    fn encode(val: &DateTime<FixedOffset>) -> Result<Self::Encoded, Self::Error> {
+        Ok(val.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+    }
+}
+
+impl Decoder<DateTime<FixedOffset>> for Rfc3339Codec {
+    type Error = chrono::ParseError;
+    type Encoded = str;
+
+    fn decode(val: &Self::Encoded) -> Result<DateTime<FixedOffset>, Self::Error> {
+        DateTime::parse_from_rfc3339(val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, TimeZone, Timelike};
+
+    #[test]
+    fn test_rfc3339_codec_utc() {
+        let dt = NaiveDate::from_ymd_opt(2024, 8, 23)
+            .unwrap()
+            .and_hms_nano_opt(12, 34, 56, 789_000_000)
+            .unwrap()
+            .and_utc();
+
+        let enc = Rfc3339Codec::encode(&dt).unwrap();
+        assert_eq!(enc, "2024-08-23T12:34:56.789Z");
+
+        let dec: DateTime<Utc> = Rfc3339Codec::decode(enc.as_str()).unwrap();
+        assert_eq!(dec, dt);
+
+        let dec: DateTime<Utc> = Rfc3339Codec::decode("2024-08-23T07:04:56.789-05:30").unwrap();
+        assert_eq!(dec, dt);
+    }
+
+    #[test]
+    fn test_rfc3339_codec_fixed_offset() {
+        let offset = FixedOffset::east_opt(2 * 3600).unwrap();
+        let dt = offset
+            .with_ymd_and_hms(2024, 8, 23, 14, 34, 56)
+            .unwrap()
+            .with_nanosecond(123_456)
+            .unwrap();
+
+        let enc = Rfc3339Codec::encode(&dt).unwrap();
+        assert_eq!(enc, "2024-08-23T14:34:56.000123456+02:00");
+
+        let dec: DateTime<FixedOffset> = Rfc3339Codec::decode(enc.as_str()).unwrap();
+        assert_eq!(dec, dt);
+        assert_eq!(dec.offset(), &offset);
+    }
+
+    #[test]
+    fn test_rfc3339_codec_invalid() {
+        let dec: Result<DateTime<Utc>, _> = Rfc3339Codec::decode("2024-08-23 12:34:56");
+        assert!(dec.is_err());
+
+        let dec: Result<DateTime<Utc>, _> = Rfc3339Codec::decode("2024-13-01T00:00:00Z");
+        assert!(dec.is_err());
+    }
+}
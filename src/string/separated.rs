@@ -0,0 +1,122 @@
+use crate::{Decoder, Encoder};
+use alloc::string::String;
+use alloc::vec::Vec;
+use thiserror::Error;
+
+/// Wraps a string codec that encodes `T` to create a codec that encodes `Vec<T>` by joining the
+/// encoded elements with the separator `SEP`.
+///
+/// Encoding fails if an encoded element contains the separator because it couldn't be decoded
+/// again. Please note that an empty string decodes to an empty `Vec`, so a `Vec` with a single
+/// element that encodes to an empty string doesn't round-trip.
+///
+/// Example:
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::string::{SeparatedCodec, FromToStringCodec};
+/// #
+/// let original_value: Vec<i32> = vec![1, 2, 3];
+/// let encoded = SeparatedCodec::<FromToStringCodec, ';'>::encode(&original_value).unwrap();
+/// let decoded: Vec<i32> = SeparatedCodec::<FromToStringCodec, ';'>::decode(&encoded).unwrap();
+///
+/// assert_eq!(encoded, "1;2;3");
+/// assert_eq!(decoded, original_value);
+/// ```
+pub struct SeparatedCodec<C, const SEP: char>(C);
+
+#[derive(Error, Debug, PartialEq)]
+pub enum SeparatedEncodeError<Err> {
+    #[error("encoded element at index {0} contains the separator")]
+    ContainsSeparator(usize),
+    #[error("failed to encode: {0}")]
+    Encoder(Err),
+}
+
+impl<T, E, const SEP: char> Encoder<Vec<T>> for SeparatedCodec<E, SEP>
+where
+    E: Encoder<T, Encoded = String>,
+{
+    type Error = SeparatedEncodeError<E::Error>;
+    type Encoded = String;
+
+    fn encode(val: &Vec<T>) -> Result<String, Self::Error> {
+        let mut encoded = String::new();
+
+        for (index, item) in val.iter().enumerate() {
+            let item = E::encode(item).map_err(SeparatedEncodeError::Encoder)?;
+            if item.contains(SEP) {
+                return Err(SeparatedEncodeError::ContainsSeparator(index));
+            }
+
+            if index > 0 {
+                encoded.push(SEP);
+            }
+            encoded.push_str(&item);
+        }
+
+        Ok(encoded)
+    }
+}
+
+impl<T, D, const SEP: char> Decoder<Vec<T>> for SeparatedCodec<D, SEP>
+where
+    D: Decoder<T, Encoded = str>,
+{
+    type Error = D::Error;
+    type Encoded = str;
+
+    fn decode(val: &Self::Encoded) -> Result<Vec<T>, Self::Error> {
+        if val.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        val.split(SEP).map(D::decode).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::string::FromToStringCodec;
+
+    #[test]
+    fn test_separated_codec() {
+        let v = vec![-1, 2, 3];
+
+        let enc = SeparatedCodec::<FromToStringCodec, ','>::encode(&v).unwrap();
+        assert_eq!(enc, "-1,2,3");
+        assert_eq!(
+            SeparatedCodec::<FromToStringCodec, ','>::decode(&enc),
+            Ok(v.clone())
+        );
+
+        let enc = SeparatedCodec::<FromToStringCodec, '|'>::encode(&v).unwrap();
+        assert_eq!(enc, "-1|2|3");
+        assert_eq!(
+            SeparatedCodec::<FromToStringCodec, '|'>::decode(&enc),
+            Ok(v)
+        );
+
+        let empty: Vec<i32> = vec![];
+        let enc = SeparatedCodec::<FromToStringCodec, '|'>::encode(&empty).unwrap();
+        assert_eq!(
+            SeparatedCodec::<FromToStringCodec, '|'>::decode(&enc),
+            Ok(empty)
+        );
+    }
+
+    #[test]
+    fn test_separated_codec_contains_separator() {
+        let v = vec!["a".to_owned(), "b;c".to_owned()];
+
+        assert_eq!(
+            SeparatedCodec::<FromToStringCodec, ';'>::encode(&v),
+            Err(SeparatedEncodeError::ContainsSeparator(1))
+        );
+        assert_eq!(
+            SeparatedCodec::<FromToStringCodec, ','>::encode(&v),
+            Ok("a,b;c".to_owned())
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{Decoder, Encoder};
+use serde::{de::DeserializeOwned, Serialize};
+
+/// A codec for encoding JSON messages that relies on [`simd_json`] which parses large payloads
+/// considerably faster than `serde_json`.
+///
+/// `simd_json` parses in place from a mutable buffer. That's why decoding has to copy the input
+/// `str` into an owned buffer first. For small payloads this extra copy can outweigh the faster
+/// parsing so [`JsonSerdeCodec`](super::JsonSerdeCodec) might be the better choice there.
+///
+/// Only available with the **`simd_json` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::string::SimdJsonCodec;
+/// # use serde::{Deserialize, Serialize};
+/// #
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct MyState {
+///     hello: String,
+/// }
+///
+/// let state = MyState { hello: "world".to_owned() };
+///
+/// let encoded = SimdJsonCodec::encode(&state).unwrap();
+/// assert_eq!(encoded, r#"{"hello":"world"}"#);
+///
+/// let decoded: MyState = SimdJsonCodec::decode(&encoded).unwrap();
+/// assert_eq!(decoded, state);
+/// ```
+pub struct SimdJsonCodec;
+
+impl<T: Serialize> Encoder<T> for SimdJsonCodec {
+    type Error = simd_json::Error;
+    type Encoded = String;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        simd_json::to_string(val)
+    }
+}
+
+impl<T: DeserializeOwned> Decoder<T> for SimdJsonCodec {
+    type Error = simd_json::Error;
+    type Encoded = str;
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        let mut buf = val.as_bytes().to_vec();
+        simd_json::from_slice(&mut buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[test]
+    fn test_simd_json_codec() {
+        #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+        struct Test {
+            s: String,
+            i: i32,
+            list: Vec<f64>,
+        }
+        let t = Test {
+            s: String::from("party time 🎉"),
+            i: 42,
+            list: vec![1.5, -2.0],
+        };
+        let enc = SimdJsonCodec::encode(&t).unwrap();
+        let dec: Test = SimdJsonCodec::decode(&enc).unwrap();
+        assert_eq!(dec, t);
+
+        let dec: Result<Test, _> = SimdJsonCodec::decode(&enc[1..]);
+        assert!(dec.is_err());
+    }
+}
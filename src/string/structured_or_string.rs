@@ -0,0 +1,113 @@
+use crate::{Decoder, Encoder};
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use core::convert::Infallible;
+
+/// Result of decoding with [`StructuredOrStringCodec`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Decoded<T> {
+    /// The inner codec successfully decoded the input.
+    Structured(T),
+    /// The inner codec failed to decode the input so it's returned as is.
+    Raw(String),
+}
+
+/// Wraps a string codec `C` that encodes `T` to create a codec for fields that usually contain
+/// structured data but sometimes only a plain string (for example an error message).
+///
+/// Decoding first tries the inner codec. If that fails the input is returned as
+/// [`Decoded::Raw`] so decoding never fails. Encoding a [`Decoded::Raw`] writes the string as is.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::string::{Decoded, StructuredOrStringCodec, JsonSerdeCodec};
+/// # use serde::{Deserialize, Serialize};
+/// #
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Status {
+///     code: u16,
+/// }
+///
+/// type Codec = StructuredOrStringCodec<JsonSerdeCodec>;
+///
+/// let decoded: Decoded<Status> = Codec::decode(r#"{"code":200}"#).unwrap();
+/// assert_eq!(decoded, Decoded::Structured(Status { code: 200 }));
+///
+/// let decoded: Decoded<Status> = Codec::decode("service unavailable").unwrap();
+/// assert_eq!(decoded, Decoded::Raw("service unavailable".to_owned()));
+///
+/// let encoded = Codec::encode(&Decoded::Structured(Status { code: 404 })).unwrap();
+/// assert_eq!(encoded, r#"{"code":404}"#);
+/// ```
+pub struct StructuredOrStringCodec<C>(C);
+
+impl<T, E> Encoder<Decoded<T>> for StructuredOrStringCodec<E>
+where
+    E: Encoder<T, Encoded = String>,
+{
+    type Error = E::Error;
+    type Encoded = String;
+
+    fn encode(val: &Decoded<T>) -> Result<String, Self::Error> {
+        match val {
+            Decoded::Structured(val) => E::encode(val),
+            Decoded::Raw(val) => Ok(val.clone()),
+        }
+    }
+}
+
+impl<T, D> Decoder<Decoded<T>> for StructuredOrStringCodec<D>
+where
+    D: Decoder<T, Encoded = str>,
+{
+    type Error = Infallible;
+    type Encoded = str;
+
+    fn decode(val: &Self::Encoded) -> Result<Decoded<T>, Self::Error> {
+        Ok(match D::decode(val) {
+            Ok(val) => Decoded::Structured(val),
+            Err(_) => Decoded::Raw(val.to_owned()),
+        })
+    }
+}
+
+#[cfg(all(test, feature = "json_serde"))]
+mod tests {
+    use super::*;
+    use crate::string::JsonSerdeCodec;
+
+    type Codec = StructuredOrStringCodec<JsonSerdeCodec>;
+
+    #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Test {
+        s: String,
+        i: i32,
+    }
+
+    #[test]
+    fn test_structured_input() {
+        let t = Test {
+            s: String::from("party time 🎉"),
+            i: 42,
+        };
+
+        let enc = Codec::encode(&Decoded::Structured(t.clone())).unwrap();
+        let dec: Decoded<Test> = Codec::decode(&enc).unwrap();
+        assert_eq!(dec, Decoded::Structured(t));
+    }
+
+    #[test]
+    fn test_raw_string_input() {
+        let dec: Decoded<Test> = Codec::decode("internal server error").unwrap();
+        assert_eq!(dec, Decoded::Raw("internal server error".to_owned()));
+
+        let enc = Codec::encode(&dec).unwrap();
+        assert_eq!(enc, "internal server error");
+
+        // valid JSON that doesn't match the structure is raw as well
+        let dec: Decoded<Test> = Codec::decode(r#"{"s":"hi"}"#).unwrap();
+        assert_eq!(dec, Decoded::Raw(r#"{"s":"hi"}"#.to_owned()));
+    }
+}
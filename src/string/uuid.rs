@@ -0,0 +1,75 @@
+use crate::{Decoder, Encoder};
+use alloc::string::{String, ToString};
+use uuid::Uuid;
+
+/// A codec that encodes a [`Uuid`] as its lowercase hyphenated string form like
+/// `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+///
+/// Decoding uses [`Uuid::parse_str`] and thus also accepts the simple, braced and urn forms.
+///
+/// Only available with the **`uuid` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::string::UuidCodec;
+/// # use uuid::Uuid;
+/// #
+/// let original_value = Uuid::from_u128(0x67e5504410b1426f9247bb680e5fe0c8);
+///
+/// let encoded = UuidCodec::encode(&original_value).unwrap();
+/// assert_eq!(encoded, "67e55044-10b1-426f-9247-bb680e5fe0c8");
+///
+/// let decoded = UuidCodec::decode(&encoded).unwrap();
+/// assert_eq!(decoded, original_value);
+/// ```
+pub struct UuidCodec;
+
+impl Encoder<Uuid> for UuidCodec {
+    type Error = ();
+    type Encoded = String;
+
+    fn encode(val: &Uuid) -> Result<Self::Encoded, Self::Error> {
+        Ok(val.hyphenated().to_string())
+    }
+}
+
+impl Decoder<Uuid> for UuidCodec {
+    type Error = uuid::Error;
+    type Encoded = str;
+
+    fn decode(val: &Self::Encoded) -> Result<Uuid, Self::Error> {
+        Uuid::parse_str(val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uuid_codec() {
+        let id = Uuid::from_u128(0x67e5504410b1426f9247bb680e5fe0c8);
+
+        let enc = UuidCodec::encode(&id).unwrap();
+        assert_eq!(enc, "67e55044-10b1-426f-9247-bb680e5fe0c8");
+        assert_eq!(UuidCodec::decode(&enc), Ok(id));
+
+        assert_eq!(
+            UuidCodec::decode("67E55044-10B1-426F-9247-BB680E5FE0C8"),
+            Ok(id)
+        );
+        assert_eq!(
+            UuidCodec::decode("67e5504410b1426f9247bb680e5fe0c8"),
+            Ok(id)
+        );
+    }
+
+    #[test]
+    fn test_uuid_codec_malformed() {
+        assert!(UuidCodec::decode("67e55044-10b1-426f-9247-bb680e5fe0c").is_err());
+        assert!(UuidCodec::decode("67e55044-10b1-426f-9247-bb680e5fe0cg").is_err());
+        assert!(UuidCodec::decode("").is_err());
+    }
+}
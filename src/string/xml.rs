@@ -0,0 +1,138 @@
+use crate::{Decoder, Encoder};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// A codec for encoding XML documents that relies on [`quick_xml`]'s serde support.
+///
+/// XML documents need a root element. Its name is taken from the name of the serialized type,
+/// so encoding works best with structs and enums. Scalar top-level values like numbers or
+/// strings aren't well supported. Struct fields whose names start with `@` are written as
+/// attributes, see the [`quick_xml::de`] docs for all the mapping rules.
+///
+/// Only available with the **`xml` feature** enabled.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::string::XmlCodec;
+/// # use serde::{Deserialize, Serialize};
+/// #
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct Farm {
+///     #[serde(rename = "@name")]
+///     name: String,
+///     chicken_count: u32,
+/// }
+///
+/// let original_value = Farm {
+///     name: "My Farm".to_owned(),
+///     chicken_count: 10,
+/// };
+///
+/// let encoded = XmlCodec::encode(&original_value).unwrap();
+/// assert_eq!(encoded, r#"<Farm name="My Farm"><chicken_count>10</chicken_count></Farm>"#);
+///
+/// let decoded: Farm = XmlCodec::decode(&encoded).unwrap();
+/// assert_eq!(decoded, original_value);
+/// ```
+pub struct XmlCodec;
+
+#[derive(Error, Debug)]
+pub enum XmlCodecError {
+    #[error("failed to serialize XML: {0}")]
+    Serialize(#[from] quick_xml::SeError),
+    #[error("failed to deserialize XML: {0}")]
+    Deserialize(#[from] quick_xml::DeError),
+}
+
+impl<T: Serialize> Encoder<T> for XmlCodec {
+    type Error = XmlCodecError;
+    type Encoded = String;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        Ok(quick_xml::se::to_string(val)?)
+    }
+}
+
+impl<T> Decoder<T> for XmlCodec
+where
+    for<'de> T: Deserialize<'de>,
+{
+    type Error = XmlCodecError;
+    type Encoded = str;
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        Ok(quick_xml::de::from_str(val)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    struct Envelope {
+        #[serde(rename = "@version")]
+        version: String,
+        header: Header,
+        #[serde(rename = "item")]
+        items: Vec<Item>,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    struct Header {
+        sender: String,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    struct Item {
+        #[serde(rename = "@id")]
+        id: u32,
+        name: String,
+    }
+
+    #[test]
+    fn test_xml_codec() {
+        let envelope = Envelope {
+            version: "1.1".to_owned(),
+            header: Header {
+                sender: "party time 🎉 <&>".to_owned(),
+            },
+            items: vec![
+                Item {
+                    id: 1,
+                    name: "a".to_owned(),
+                },
+                Item {
+                    id: 2,
+                    name: "b".to_owned(),
+                },
+            ],
+        };
+
+        let enc = XmlCodec::encode(&envelope).unwrap();
+        assert_eq!(
+            enc,
+            concat!(
+                r#"<Envelope version="1.1">"#,
+                "<header><sender>party time 🎉 &lt;&amp;&gt;</sender></header>",
+                r#"<item id="1"><name>a</name></item>"#,
+                r#"<item id="2"><name>b</name></item>"#,
+                "</Envelope>"
+            )
+        );
+
+        let dec: Envelope = XmlCodec::decode(&enc).unwrap();
+        assert_eq!(dec, envelope);
+    }
+
+    #[test]
+    fn test_xml_codec_errors() {
+        let dec: Result<Header, _> = XmlCodec::decode("<Header><sender>a</Header>");
+        assert!(matches!(dec, Err(XmlCodecError::Deserialize(_))));
+
+        let enc = XmlCodec::encode(&vec![1, 2]);
+        assert!(matches!(enc, Err(XmlCodecError::Serialize(_))));
+    }
+}
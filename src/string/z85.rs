@@ -0,0 +1,116 @@
+use crate::{Decoder, Encoder};
+use thiserror::Error;
+
+/// Wraps a binary codec and make it a string codec by representing the binary data as a
+/// [Z85](https://rfc.zeromq.org/spec/32/) string. Z85 is more compact than base64.
+///
+/// Z85 can only encode data whose length is a multiple of 4. Other data is transparently padded
+/// with zeros. The number of padding bytes is stored as the first character of the encoded string.
+///
+/// Only available with the **`z85` feature** enabled.
+///
+/// Example:
+///
+/// ```
+/// # use codee::{Encoder, Decoder};
+/// # use codee::string::Z85;
+/// # use codee::binary::MsgpackSerdeCodec;
+/// # use serde::{Serialize, Deserialize};
+/// #
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct MyState {
+///     chicken_count: u32,
+///     egg_count: u32,
+///     farm_name: String,
+/// }
+///
+/// let original_value = MyState {
+///     chicken_count: 10,
+///     egg_count: 20,
+///     farm_name: "My Farm".to_owned(),
+/// };
+///
+/// let encoded: String = Z85::<MsgpackSerdeCodec>::encode(&original_value).unwrap();
+/// let decoded: MyState = Z85::<MsgpackSerdeCodec>::decode(&encoded).unwrap();
+///
+/// assert_eq!(decoded, original_value);
+/// ```
+pub struct Z85<C>(C);
+
+#[derive(Error, Debug, PartialEq)]
+pub enum Z85DecodeError<Err> {
+    #[error("invalid padding information")]
+    InvalidPadding,
+    #[error("failed to decode z85: {0}")]
+    DecodeZ85(#[from] z85::DecodeError),
+    #[error("failed to decode: {0}")]
+    Decoder(Err),
+}
+
+impl<T, E> Encoder<T> for Z85<E>
+where
+    E: Encoder<T, Encoded = Vec<u8>>,
+{
+    type Error = E::Error;
+    type Encoded = String;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        let mut buf = E::encode(val)?;
+
+        let padding = (4 - buf.len() % 4) % 4;
+        buf.resize(buf.len() + padding, 0);
+
+        Ok(format!("{padding}{}", z85::encode(buf)))
+    }
+}
+
+impl<T, D> Decoder<T> for Z85<D>
+where
+    D: Decoder<T, Encoded = [u8]>,
+{
+    type Error = Z85DecodeError<D::Error>;
+    type Encoded = str;
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        let (padding, data) = match val.as_bytes().split_first() {
+            Some((padding @ b'0'..=b'3', data)) => ((padding - b'0') as usize, data),
+            _ => return Err(Z85DecodeError::InvalidPadding),
+        };
+
+        let mut buf = z85::decode(data)?;
+        if buf.len() < padding {
+            return Err(Z85DecodeError::InvalidPadding);
+        }
+        buf.truncate(buf.len() - padding);
+
+        D::decode(&buf).map_err(Z85DecodeError::Decoder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary::FromToBytesCodec;
+
+    #[test]
+    fn test_z85_codec() {
+        for len in 0..=9 {
+            let s = "abcdefghi"[..len].to_owned();
+
+            let enc = Z85::<FromToBytesCodec>::encode(&s).unwrap();
+            assert_eq!(enc.len(), 1 + len.div_ceil(4) * 5);
+
+            let dec: String = Z85::<FromToBytesCodec>::decode(&enc).unwrap();
+            assert_eq!(dec, s);
+        }
+    }
+
+    #[test]
+    fn test_z85_codec_invalid_padding() {
+        let dec: Result<String, _> = Z85::<FromToBytesCodec>::decode("4");
+        assert!(matches!(dec, Err(Z85DecodeError::InvalidPadding)));
+
+        let dec: Result<String, _> = Z85::<FromToBytesCodec>::decode("1");
+        assert!(matches!(dec, Err(Z85DecodeError::InvalidPadding)));
+    }
+}
@@ -1,9 +1,21 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
 /// Trait every encoder must implement.
-pub trait Encoder<T>: 'static {
+///
+/// `T` may be unsized so that codecs can encode slices like `[T]` or `str` directly without
+/// having to collect them into an owned value first.
+pub trait Encoder<T: ?Sized>: 'static {
     type Error;
     type Encoded;
 
     fn encode(val: &T) -> Result<Self::Encoded, Self::Error>;
+
+    /// Returns the size of the encoded `val` if it can be determined cheaply.
+    /// This can be used to reserve capacity in a buffer before encoding into it.
+    fn size_hint(_val: &T) -> Option<usize> {
+        None
+    }
 }
 
 /// Trait every decoder must implement.
@@ -13,3 +25,147 @@ pub trait Decoder<T>: 'static {
 
     fn decode(val: &Self::Encoded) -> Result<T, Self::Error>;
 }
+
+/// Extension methods for every [`Decoder`]. It is implemented automatically.
+pub trait DecoderExt<T>: Decoder<T> {
+    /// Decodes `val` and falls back to `T::default()` if decoding fails. This is useful for
+    /// settings that should simply reset when the stored data is corrupt or has an old format.
+    fn decode_or_default(val: &Self::Encoded) -> T
+    where
+        T: Default,
+    {
+        Self::decode(val).unwrap_or_default()
+    }
+}
+
+impl<T, D> DecoderExt<T> for D where D: Decoder<T> {}
+
+/// Trait for decoders that can return values which borrow from the encoded input, for example
+/// structs with `&str` fields. This avoids allocations for string-heavy payloads.
+///
+/// Unlike [`Decoder`] the decoded `T` can't outlive the encoded value `'de`.
+pub trait BorrowDecoder<'de, T>: 'static {
+    type Error;
+    type Encoded: ?Sized;
+
+    fn decode_borrowed(val: &'de Self::Encoded) -> Result<T, Self::Error>;
+}
+
+/// Trait alias for types that implement both [`Encoder`] and [`Decoder`] for `T`.
+/// It is implemented automatically.
+pub trait Codec<T>: Encoder<T> + Decoder<T> {}
+
+impl<T, C> Codec<T> for C where C: Encoder<T> + Decoder<T> {}
+
+/// Trait alias for string codecs, i.e. codecs that encode `T` as `String` and decode it from
+/// `str`. It is implemented automatically.
+pub trait StringCodec<T>: Encoder<T, Encoded = String> + Decoder<T, Encoded = str> {}
+
+impl<T, C> StringCodec<T> for C where C: Encoder<T, Encoded = String> + Decoder<T, Encoded = str> {}
+
+/// Trait alias for binary codecs, i.e. codecs that encode `T` as `Vec<u8>` and decode it from
+/// `[u8]`. It is implemented automatically.
+pub trait BinaryCodec<T>: Encoder<T, Encoded = Vec<u8>> + Decoder<T, Encoded = [u8]> {}
+
+impl<T, C> BinaryCodec<T> for C where C: Encoder<T, Encoded = Vec<u8>> + Decoder<T, Encoded = [u8]> {}
+
+/// Trait for encoders that can append their output to an existing buffer instead of allocating
+/// a new one. This way a buffer can be cleared and reused for many values.
+pub trait EncoderInto<T: ?Sized>: Encoder<T> {
+    /// Appends the encoded `val` to `buf`. Existing content of `buf` is left untouched.
+    fn encode_into(val: &T, buf: &mut Self::Encoded) -> Result<(), Self::Error>;
+}
+
+/// Trait for binary encoders that can encode into a [`SmallVec`](smallvec::SmallVec) instead of a
+/// `Vec<u8>`. Encodings that fit into the `N` inline bytes don't allocate on the heap at all,
+/// which is useful for high-frequency small messages.
+///
+/// Only available with the **`smallvec` feature** enabled.
+#[cfg(feature = "smallvec")]
+pub trait SmallVecEncoder<T: ?Sized>: Encoder<T> {
+    /// Encodes `val` into exactly the same bytes as [`Encoder::encode`] would.
+    ///
+    /// Pick `N` around the size of your typical encoded values so they stay inline. Larger
+    /// values still work but spill onto the heap, while a larger `N` makes every `SmallVec`
+    /// bigger, also when it's mostly empty.
+    fn encode_smallvec<const N: usize>(val: &T)
+        -> Result<smallvec::SmallVec<[u8; N]>, Self::Error>;
+}
+
+/// Trait for binary decoders of self-delimiting formats that know how many bytes a decoded value
+/// occupies. This makes it possible to decode several values that have been concatenated into one
+/// buffer.
+pub trait DecoderWithRemainder<T>: Decoder<T, Encoded = [u8]> {
+    /// Decodes the value at the front of `val` and returns it together with the bytes that
+    /// haven't been consumed.
+    fn decode_with_remainder(val: &[u8]) -> Result<(T, &[u8]), Self::Error>;
+}
+
+/// Trait for encoders that can write their output directly into an [`std::io::Write`] without
+/// materializing the whole encoded value in memory first.
+///
+/// Only available with the **`std` feature** enabled.
+#[cfg(feature = "std")]
+pub trait StreamEncoder<T: ?Sized>: Encoder<T> {
+    fn encode_to_writer<W: std::io::Write>(val: &T, writer: &mut W) -> Result<(), Self::Error>;
+}
+
+/// Trait for decoders that can read their input directly from an [`std::io::Read`] without
+/// reading everything into memory first.
+///
+/// Please note that formats which don't encode their own length might consume the rest of the
+/// reader and not only the bytes of the decoded value.
+///
+/// Only available with the **`std` feature** enabled.
+#[cfg(feature = "std")]
+pub trait StreamDecoder<T>: Decoder<T> {
+    fn decode_from_reader<R: std::io::Read>(reader: &mut R) -> Result<T, Self::Error>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary::FromToBytesCodec;
+    use crate::string::FromToStringCodec;
+
+    fn string_round_trip<T, C: StringCodec<T>>(val: &T) -> T {
+        let encoded: String = C::encode(val).ok().unwrap();
+        C::decode(&encoded).ok().unwrap()
+    }
+
+    fn binary_round_trip<T, C: BinaryCodec<T>>(val: &T) -> T {
+        let encoded: Vec<u8> = C::encode(val).ok().unwrap();
+        C::decode(&encoded).ok().unwrap()
+    }
+
+    fn round_trip<T, C>(val: &T) -> T
+    where
+        C: Codec<T>,
+        <C as Encoder<T>>::Encoded: core::borrow::Borrow<<C as Decoder<T>>::Encoded>,
+    {
+        use core::borrow::Borrow;
+
+        let encoded = C::encode(val).ok().unwrap();
+        C::decode(encoded.borrow()).ok().unwrap()
+    }
+
+    #[test]
+    fn test_decode_or_default() {
+        let decoded: i32 = FromToStringCodec::decode_or_default("42");
+        assert_eq!(decoded, 42);
+
+        let decoded: i32 = FromToStringCodec::decode_or_default("corrupt");
+        assert_eq!(decoded, 0);
+
+        let decoded: String = FromToBytesCodec::decode_or_default(&[0xff, 0xfe][..]);
+        assert_eq!(decoded, "");
+    }
+
+    #[test]
+    fn test_codec_aliases() {
+        assert_eq!(string_round_trip::<i32, FromToStringCodec>(&42), 42);
+        assert_eq!(binary_round_trip::<i32, FromToBytesCodec>(&42), 42);
+        assert_eq!(round_trip::<i32, FromToStringCodec>(&42), 42);
+        assert_eq!(round_trip::<i32, FromToBytesCodec>(&42), 42);
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{Decoder, Encoder};
+use thiserror::Error;
+
+/// Wraps a codec `C` that works on the type `Dto` to create a codec for any `T` that can be
+/// converted to and from `Dto`.
+///
+/// Encoding clones the value, converts it with [`Into<Dto>`] and encodes the result with `C`.
+/// Decoding decodes a `Dto` with `C` and converts it with [`TryFrom<Dto>`]. This is useful if
+/// your in-memory type can't be serialized directly but has a cheap conversion to a type that
+/// can.
+///
+/// ## Example
+///
+/// ```
+/// # use codee::{Encoder, Decoder, Via};
+/// # use codee::string::FromToStringCodec;
+/// #
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Percent(u8);
+///
+/// impl From<Percent> for u8 {
+///     fn from(val: Percent) -> Self {
+///         val.0
+///     }
+/// }
+///
+/// impl TryFrom<u8> for Percent {
+///     type Error = String;
+///
+///     fn try_from(val: u8) -> Result<Self, Self::Error> {
+///         if val <= 100 {
+///             Ok(Percent(val))
+///         } else {
+///             Err(format!("{val} is more than 100%"))
+///         }
+///     }
+/// }
+///
+/// let encoded = Via::<u8, FromToStringCodec>::encode(&Percent(42)).unwrap();
+/// assert_eq!(encoded, "42");
+///
+/// let decoded: Percent = Via::<u8, FromToStringCodec>::decode(&encoded).unwrap();
+/// assert_eq!(decoded, Percent(42));
+///
+/// let decoded: Result<Percent, _> = Via::<u8, FromToStringCodec>::decode("142");
+/// assert!(decoded.is_err());
+/// ```
+pub struct Via<Dto, C>(Dto, C);
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ViaDecodeError<Err, ConvErr> {
+    #[error("failed to decode: {0}")]
+    Decoder(Err),
+    #[error("failed to convert: {0}")]
+    Conversion(ConvErr),
+}
+
+impl<T, Dto, E> Encoder<T> for Via<Dto, E>
+where
+    T: Clone + Into<Dto>,
+    Dto: 'static,
+    E: Encoder<Dto>,
+{
+    type Error = E::Error;
+    type Encoded = E::Encoded;
+
+    fn encode(val: &T) -> Result<Self::Encoded, Self::Error> {
+        E::encode(&val.clone().into())
+    }
+}
+
+impl<T, Dto, D> Decoder<T> for Via<Dto, D>
+where
+    T: TryFrom<Dto>,
+    Dto: 'static,
+    D: Decoder<Dto>,
+{
+    type Error = ViaDecodeError<D::Error, T::Error>;
+    type Encoded = D::Encoded;
+
+    fn decode(val: &Self::Encoded) -> Result<T, Self::Error> {
+        let dto = D::decode(val).map_err(ViaDecodeError::Decoder)?;
+        T::try_from(dto).map_err(ViaDecodeError::Conversion)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary::FromToBytesCodec;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct UserId(u32);
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct UserIdDto {
+        id: u32,
+    }
+
+    impl From<UserId> for UserIdDto {
+        fn from(val: UserId) -> Self {
+            UserIdDto { id: val.0 }
+        }
+    }
+
+    impl TryFrom<UserIdDto> for UserId {
+        type Error = &'static str;
+
+        fn try_from(val: UserIdDto) -> Result<Self, Self::Error> {
+            if val.id == 0 {
+                Err("user id 0 is reserved")
+            } else {
+                Ok(UserId(val.id))
+            }
+        }
+    }
+
+    /// Stand-in for a serializable DTO codec
+    struct UserIdDtoCodec;
+
+    impl Encoder<UserIdDto> for UserIdDtoCodec {
+        type Error = ();
+        type Encoded = Vec<u8>;
+
+        fn encode(val: &UserIdDto) -> Result<Self::Encoded, Self::Error> {
+            FromToBytesCodec::encode(&val.id)
+        }
+    }
+
+    impl Decoder<UserIdDto> for UserIdDtoCodec {
+        type Error = crate::binary::FromToBytesCodecError;
+        type Encoded = [u8];
+
+        fn decode(val: &Self::Encoded) -> Result<UserIdDto, Self::Error> {
+            FromToBytesCodec::decode(val).map(|id| UserIdDto { id })
+        }
+    }
+
+    type Codec = Via<UserIdDto, UserIdDtoCodec>;
+
+    #[test]
+    fn test_via_codec() {
+        let enc = Codec::encode(&UserId(42)).unwrap();
+        assert_eq!(enc, vec![0, 0, 0, 42]);
+
+        let dec: UserId = Codec::decode(&enc).unwrap();
+        assert_eq!(dec, UserId(42));
+    }
+
+    #[test]
+    fn test_via_codec_errors() {
+        let dec: Result<UserId, _> = Codec::decode(&[0, 0, 0, 0]);
+        assert!(matches!(
+            dec,
+            Err(ViaDecodeError::Conversion("user id 0 is reserved"))
+        ));
+
+        let dec: Result<UserId, _> = Codec::decode(&[0]);
+        assert!(matches!(dec, Err(ViaDecodeError::Decoder(_))));
+    }
+}
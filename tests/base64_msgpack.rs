@@ -0,0 +1,77 @@
+//! Makes sure `Base64<MsgpackSerdeCodec>` works end-to-end as a string codec, including when the
+//! encoded string is embedded in a JSON document.
+
+#![cfg(all(feature = "base64", feature = "msgpack_serde", feature = "json_serde"))]
+
+use codee::binary::MsgpackSerdeCodec;
+use codee::string::{Base64, Base64DecodeError, JsonSerdeCodec, MsgpackBase64Codec};
+use codee::{Decoder, Encoder, StringCodec};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Payload {
+    id: u64,
+    tags: Vec<String>,
+    ratio: f64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct LegacyRequest {
+    kind: String,
+    data: String,
+}
+
+fn payload() -> Payload {
+    Payload {
+        id: 42,
+        tags: vec!["party".to_owned(), "time 🎉".to_owned()],
+        ratio: 0.5,
+    }
+}
+
+fn round_trip<T, C: StringCodec<T>>(val: &T) -> T
+where
+    <C as Encoder<T>>::Error: core::fmt::Debug,
+    <C as Decoder<T>>::Error: core::fmt::Debug,
+{
+    C::decode(&C::encode(val).unwrap()).unwrap()
+}
+
+#[test]
+fn test_base64_msgpack_round_trip() {
+    let payload = payload();
+
+    let encoded = Base64::<MsgpackSerdeCodec>::encode(&payload).unwrap();
+    let decoded: Payload = Base64::<MsgpackSerdeCodec>::decode(&encoded).unwrap();
+    assert_eq!(decoded, payload);
+
+    assert_eq!(MsgpackBase64Codec::encode(&payload).unwrap(), encoded);
+    assert_eq!(round_trip::<_, MsgpackBase64Codec>(&payload), payload);
+}
+
+#[test]
+fn test_base64_msgpack_in_json_string_field() {
+    let payload = payload();
+
+    let request = LegacyRequest {
+        kind: "payload".to_owned(),
+        data: MsgpackBase64Codec::encode(&payload).unwrap(),
+    };
+    let json = JsonSerdeCodec::encode(&request).unwrap();
+
+    let request: LegacyRequest = JsonSerdeCodec::decode(&json).unwrap();
+    let decoded: Payload = MsgpackBase64Codec::decode(&request.data).unwrap();
+    assert_eq!(decoded, payload);
+
+    // more compact than the plain JSON encoding of the payload
+    assert!(request.data.len() < JsonSerdeCodec::encode(&payload).unwrap().len());
+}
+
+#[test]
+fn test_base64_msgpack_invalid_input() {
+    let decoded: Result<Payload, _> = MsgpackBase64Codec::decode("not base64!");
+    assert!(matches!(decoded, Err(Base64DecodeError::DecodeBase64(_))));
+
+    let decoded: Result<Payload, _> = MsgpackBase64Codec::decode("kwECAw==");
+    assert!(matches!(decoded, Err(Base64DecodeError::Decoder(_))));
+}
@@ -0,0 +1,58 @@
+//! Makes sure the core traits and the alloc-only codecs can be used from a `#![no_std]` crate.
+//!
+//! To check that the library itself builds without `std` run
+//! `cargo build --no-default-features --features postcard_serde`.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use codee::binary::FromToBytesCodec;
+use codee::string::FromToStringCodec;
+use codee::{Codec, Decoder, Encoder};
+
+fn round_trip<T, C>(val: &T) -> T
+where
+    C: Codec<T>,
+    <C as Encoder<T>>::Encoded: core::borrow::Borrow<<C as Decoder<T>>::Encoded>,
+    <C as Encoder<T>>::Error: core::fmt::Debug,
+    <C as Decoder<T>>::Error: core::fmt::Debug,
+{
+    use core::borrow::Borrow;
+
+    let encoded = C::encode(val).unwrap();
+    C::decode(encoded.borrow()).unwrap()
+}
+
+#[test]
+fn test_no_std_from_to_bytes() {
+    assert_eq!(round_trip::<u32, FromToBytesCodec>(&42), 42);
+
+    let encoded: Vec<u8> = FromToBytesCodec::encode(&String::from("no_std")).unwrap();
+    assert_eq!(encoded, b"no_std");
+}
+
+#[test]
+fn test_no_std_from_to_string() {
+    assert_eq!(round_trip::<i64, FromToStringCodec>(&-7), -7);
+}
+
+#[cfg(feature = "postcard_serde")]
+#[test]
+fn test_no_std_postcard() {
+    use codee::binary::PostcardSerdeCodec;
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Test {
+        s: String,
+        list: Vec<u16>,
+    }
+
+    let t = Test {
+        s: String::from("party time 🎉"),
+        list: alloc::vec![1, 2, 3],
+    };
+    assert_eq!(round_trip::<Test, PostcardSerdeCodec>(&t), t);
+}